@@ -0,0 +1,104 @@
+//! Interactive prompt which prints the segments of the text typed or pasted in, with their spans
+//! and kinds, the abbreviations which were recognized, and the rules which changed the text at
+//! each stage of the pipeline. It makes a precise reproduction easy when reporting a wrong
+//! segmentation:
+//!
+//! ```bash
+//! cargo run --example repl
+//! ```
+
+use std::error::Error;
+use std::io::{self, BufRead, Write};
+
+use pragmatic_segmenter::Segmenter;
+
+const HELP: &str = "\
+Type or paste text, then an empty line to segment it.
+    :lang CODE      Segment with the rules of the language
+    :help           Print this message
+    :quit           Exit
+";
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut segmenter = Segmenter::new()?;
+    print!("{}", HELP);
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    let mut text = String::new();
+    loop {
+        print!("{}", if text.is_empty() { "> " } else { ". " });
+        io::stdout().flush()?;
+        let line = match lines.next() {
+            Some(line) => line?,
+            None => break,
+        };
+
+        if text.is_empty() {
+            let mut words = line.split_whitespace();
+            match (words.next(), words.next()) {
+                (Some(":quit"), None) | (Some(":q"), None) => break,
+                (Some(":help"), None) => {
+                    print!("{}", HELP);
+                    continue;
+                }
+                (Some(":lang"), Some(code)) => {
+                    match Segmenter::builder().language(code) {
+                        Ok(builder) => segmenter = builder.build()?,
+                        Err(err) => println!("{}", err),
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        if !line.is_empty() {
+            text.push_str(&line);
+            text.push('\n');
+            continue;
+        }
+        if text.is_empty() {
+            continue;
+        }
+        // 붙여넣은 마지막 줄의 줄바꿈은 입력의 일부가 아니다.
+        text.pop();
+        explain(&segmenter, &text);
+        text.clear();
+    }
+    Ok(())
+}
+
+fn explain(segmenter: &Segmenter, text: &str) {
+    println!("segments:");
+    for segment in segmenter.segments(text) {
+        let end = segment.start + segment.text.len();
+        println!(
+            "  {}..{} {:?} {:?}",
+            segment.start, end, segment.kind, segment.text
+        );
+    }
+
+    let abbreviations = segmenter.abbreviation_occurrences(text);
+    if !abbreviations.is_empty() {
+        println!("abbreviations:");
+        for abbreviation in abbreviations {
+            let category = match abbreviation.category {
+                Some(category) => format!("{:?}", category),
+                None => "possible boundary".to_string(),
+            };
+            println!(
+                "  {} {:?} {:?} abbreviation: {}",
+                abbreviation.start, abbreviation.text, abbreviation.kind, category
+            );
+        }
+    }
+
+    let steps = segmenter.explain(text);
+    if !steps.is_empty() {
+        println!("rules applied:");
+        for step in steps {
+            println!("  {:?} {} {:?}", step.stage, step.rule, step.text);
+        }
+    }
+    println!();
+}
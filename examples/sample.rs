@@ -0,0 +1,201 @@
+//! Print the segmentation of random documents of a corpus, with every segment in an alternating
+//! color, for a quick review by eye when tuning rules or languages:
+//!
+//! ```bash
+//! cargo run --example sample -- -n 5 corpus.txt
+//! ```
+
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use pragmatic_segmenter::Segmenter;
+
+const USAGE: &str = "\
+Usage:
+    sample [-n COUNT] [--seed SEED] [--lines] [-l CODE] [--plain] FILE
+
+Pick COUNT random documents of FILE, and print each of them with its segments in alternating
+colors. Documents are separated by blank lines, or are single lines with --lines.
+
+Options:
+    -n COUNT                Number of documents to print (default: 10)
+    --seed SEED             Seed of the random choice (default: the current time)
+    --lines                 Treat every line as a document
+    -l, --language CODE     Segment with the rules of the language (default: en)
+    --plain                 Mark the boundaries with '|' instead of colors
+    -h, --help              Print this message
+";
+
+const COLORS: &[&str] = &["\x1b[36m", "\x1b[33m"];
+const RESET: &str = "\x1b[0m";
+
+#[derive(Debug)]
+struct Args {
+    file: String,
+    count: usize,
+    seed: u64,
+    lines: bool,
+    language: Option<String>,
+    plain: bool,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut file = None;
+        let mut count = 10;
+        let mut seed = None;
+        let mut lines = false;
+        let mut language = None;
+        let mut plain = false;
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .ok_or_else(|| format!("missing value for {}", name))
+            };
+            match arg.as_str() {
+                "-h" | "--help" => {
+                    print!("{}", USAGE);
+                    process::exit(0);
+                }
+                "-n" => {
+                    let n = value(&arg)?;
+                    count = n.parse().map_err(|_| format!("invalid count: {}", n))?;
+                }
+                "--seed" => {
+                    let s = value(&arg)?;
+                    seed = Some(s.parse().map_err(|_| format!("invalid seed: {}", s))?);
+                }
+                "--lines" => lines = true,
+                "-l" | "--language" => language = Some(value(&arg)?),
+                "--plain" => plain = true,
+                _ if arg.starts_with('-') => return Err(format!("unknown option: {}", arg)),
+                _ if file.is_none() => file = Some(arg),
+                _ => return Err(format!("unexpected argument: {}", arg)),
+            }
+        }
+        Ok(Args {
+            file: file.ok_or("missing FILE")?,
+            count,
+            seed: seed.unwrap_or_else(|| {
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+            }),
+            lines,
+            language,
+            plain,
+        })
+    }
+}
+
+/// xorshift64*, which is good enough to pick documents without a dependency.
+struct Random(u64);
+
+impl Random {
+    fn new(seed: u64) -> Self {
+        // 0은 xorshift의 고정점이므로 피한다.
+        Random(seed ^ 0x9e37_79b9_7f4a_7c15)
+    }
+
+    /// Random number in `0..n`.
+    fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        (self.0.wrapping_mul(0x2545_f491_4f6c_dd1d) % n as u64) as usize
+    }
+}
+
+fn main() {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(msg) => {
+            eprint!("error: {}\n\n{}", msg, USAGE);
+            process::exit(2);
+        }
+    };
+    if let Err(err) = run(&args) {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+fn run(args: &Args) -> Result<(), Box<dyn Error>> {
+    let mut builder = Segmenter::builder();
+    if let Some(code) = &args.language {
+        builder = builder.language(code)?;
+    }
+    let segmenter = builder.build()?;
+
+    let file = File::open(&args.file).map_err(|err| format!("{}: {}", args.file, err))?;
+    let mut samples = sample(BufReader::new(file), args)?;
+    samples.sort_unstable_by_key(|&(line, _)| line);
+
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    for (line, document) in samples {
+        writeln!(out, "== {}:{} ==", args.file, line)?;
+        for (idx, segment) in segmenter.segment(&document).enumerate() {
+            if args.plain {
+                let separator = if idx == 0 { "" } else { "|" };
+                write!(out, "{}{}", separator, segment)?;
+            } else {
+                write!(out, "{}{}{}", COLORS[idx % COLORS.len()], segment, RESET)?;
+            }
+        }
+        writeln!(out, "\n")?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+/// Pick `args.count` documents of `reader` with the same probability, by reservoir sampling, so
+/// that the corpus never has to be in memory at once. Each document comes with its first line
+/// number.
+fn sample(reader: impl BufRead, args: &Args) -> io::Result<Vec<(usize, String)>> {
+    let mut random = Random::new(args.seed);
+    let mut samples = Vec::with_capacity(args.count);
+    let mut seen = 0;
+    let mut offer = |line: usize, document: String| {
+        seen += 1;
+        if samples.len() < args.count {
+            samples.push((line, document));
+        } else {
+            let idx = random.below(seen);
+            if idx < args.count {
+                samples[idx] = (line, document);
+            }
+        }
+    };
+
+    let mut document = String::new();
+    let mut first_line = 0;
+    for (idx, line) in reader.lines().enumerate() {
+        let line = line?;
+        if args.lines {
+            if !line.trim().is_empty() {
+                offer(idx + 1, line);
+            }
+            continue;
+        }
+        if line.trim().is_empty() {
+            if !document.is_empty() {
+                offer(first_line, std::mem::take(&mut document));
+            }
+            continue;
+        }
+        if document.is_empty() {
+            first_line = idx + 1;
+        } else {
+            document.push('\n');
+        }
+        document.push_str(&line);
+    }
+    if !document.is_empty() {
+        offer(first_line, document);
+    }
+    Ok(samples)
+}
@@ -0,0 +1,378 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+
+/// How an abbreviation affects the period which follows it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AbbreviationKind {
+    /// Ordinary abbreviation like "etc." or "approx.". The period is a sentence boundary only
+    /// when it looks like one from the context.
+    Plain,
+    /// Abbreviation which always comes before a name, like "Dr." or "Sra.". The period never ends
+    /// a sentence.
+    Prepositive,
+    /// Abbreviation which comes before a number, like "No. 5" or "pp. 12".
+    Number,
+}
+
+/// A named set of abbreviations which can be added to a [`Segmenter`](crate::Segmenter) with
+/// [`SegmenterBuilder::abbreviations()`](crate::SegmenterBuilder::abbreviations).
+///
+/// ```rust
+/// use pragmatic_segmenter::{AbbreviationKind, AbbreviationPack, Segmenter};
+///
+/// let pack = AbbreviationPack::new("my-titles")
+///     .with("dott", AbbreviationKind::Prepositive)
+///     .with("approx", AbbreviationKind::Plain);
+/// let segmenter = Segmenter::builder().abbreviations(pack).build()?;
+/// let result: Vec<_> = segmenter.segment("Ho visto il Dott. Rossi ieri.").collect();
+/// assert_eq!(result, vec!["Ho visto il Dott. Rossi ieri."]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbbreviationPack {
+    name: Cow<'static, str>,
+    entries: Vec<(Cow<'static, str>, AbbreviationKind)>,
+}
+
+impl AbbreviationPack {
+    /// Create an empty pack.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        AbbreviationPack {
+            name: name.into(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Add an abbreviation, written without its final period ("dr", "e.g", "sig.ra"). Matching
+    /// is case insensitive.
+    pub fn with(
+        mut self,
+        abbreviation: impl Into<Cow<'static, str>>,
+        kind: AbbreviationKind,
+    ) -> Self {
+        self.entries.push((abbreviation.into(), kind));
+        self
+    }
+
+    /// Create a pack from groups of abbreviations which share a kind, in order.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::{AbbreviationKind, AbbreviationPack};
+    ///
+    /// let pack = AbbreviationPack::from_groups(
+    ///     "my-pack",
+    ///     &[
+    ///         (&["fig", "eq"], AbbreviationKind::Number),
+    ///         (&["approx"], AbbreviationKind::Plain),
+    ///     ],
+    /// );
+    /// let expected = AbbreviationPack::new("my-pack")
+    ///     .with("fig", AbbreviationKind::Number)
+    ///     .with("eq", AbbreviationKind::Number)
+    ///     .with("approx", AbbreviationKind::Plain);
+    /// assert_eq!(pack, expected);
+    /// ```
+    pub fn from_groups(
+        name: impl Into<Cow<'static, str>>,
+        groups: &[(&[&'static str], AbbreviationKind)],
+    ) -> Self {
+        groups
+            .iter()
+            .flat_map(|&(words, kind)| words.iter().map(move |&word| (word, kind)))
+            .fold(AbbreviationPack::new(name), |pack, (word, kind)| {
+                pack.with(word, kind)
+            })
+    }
+
+    /// Honorifics and titles used before names in the given language. Every entry is
+    /// [`AbbreviationKind::Prepositive`]. Supported languages are `"ar"`, `"de"`, `"es"`, `"fr"`,
+    /// `"hi"`, `"it"`, `"kk"`, `"mr"`, `"pl"`, `"pt"` and `"ru"`.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::{AbbreviationPack, Segmenter};
+    ///
+    /// let segmenter = Segmenter::builder()
+    ///     .abbreviations(AbbreviationPack::honorifics("es").unwrap())
+    ///     .build()?;
+    /// let result: Vec<_> = segmenter.segment("La Sra. Pérez llegó.").collect();
+    /// assert_eq!(result, vec!["La Sra. Pérez llegó."]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn honorifics(language: &str) -> Option<Self> {
+        let (name, words) = match language {
+            "ar" => ("honorifics-ar", HONORIFICS_AR),
+            "de" => ("honorifics-de", HONORIFICS_DE),
+            "es" => ("honorifics-es", HONORIFICS_ES),
+            "fr" => ("honorifics-fr", HONORIFICS_FR),
+            "hi" => ("honorifics-hi", HONORIFICS_HI),
+            "kk" => ("honorifics-kk", HONORIFICS_KK),
+            "it" => ("honorifics-it", HONORIFICS_IT),
+            "mr" => ("honorifics-mr", HONORIFICS_MR),
+            "pl" => ("honorifics-pl", HONORIFICS_PL),
+            "pt" => ("honorifics-pt", HONORIFICS_PT),
+            "ru" => ("honorifics-ru", HONORIFICS_RU),
+            _ => return None,
+        };
+        Some(AbbreviationPack::from_groups(
+            name,
+            &[(words, AbbreviationKind::Prepositive)],
+        ))
+    }
+
+    /// Abbreviations of biomedical literature: references to figures, tables and equations like
+    /// "Figs. 2", taxonomic ranks like "spp." and "subsp.", and dosing schedules and routes like
+    /// "b.i.d." and "i.v.". [`Preset::Biomedical`](crate::Preset::Biomedical) adds this pack along
+    /// with a rule which ends a sentence after a dosing abbreviation followed by an uppercase
+    /// letter.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::{AbbreviationPack, Segmenter};
+    ///
+    /// let segmenter = Segmenter::builder()
+    ///     .abbreviations(AbbreviationPack::biomedical())
+    ///     .build()?;
+    /// let result: Vec<_> = segmenter
+    ///     .segment("Some Streptococcus spp. were found (Figs. 2 and 3). See Suppl. Table 1.")
+    ///     .collect();
+    /// assert_eq!(
+    ///     result,
+    ///     vec!["Some Streptococcus spp. were found (Figs. 2 and 3). ", "See Suppl. Table 1."]
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn biomedical() -> Self {
+        AbbreviationPack::from_groups(
+            "biomedical",
+            &[
+                (BIOMEDICAL_NUMBER, AbbreviationKind::Number),
+                (BIOMEDICAL_PREPOSITIVE, AbbreviationKind::Prepositive),
+                (BIOMEDICAL_PLAIN, AbbreviationKind::Plain),
+                (DOSING, AbbreviationKind::Plain),
+            ],
+        )
+    }
+
+    /// Name of this pack.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Abbreviations of this pack, in insertion order.
+    pub fn entries(&self) -> impl Iterator<Item = (&str, AbbreviationKind)> {
+        self.entries
+            .iter()
+            .map(|(abbr, kind)| (abbr.as_ref(), *kind))
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), InvalidAbbreviation> {
+        // NOTE: 약어는 regex 안에 escape 없이 들어간다. abbreviation_replacer.rs의 ABBREVIATIONS
+        // 주석 참고. 데바나가리의 virama처럼 글자에 붙는 결합 문자도 허용한다.
+        let valid = regex::Regex::new(r"\A[\p{L}\p{M}\p{N}.\-]+\z").unwrap(); // Must succeed
+        for (abbr, _) in &self.entries {
+            if !valid.is_match(abbr) {
+                return Err(InvalidAbbreviation(abbr.to_string()));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Abbreviation which is given different kinds by the packs added to a builder, reported by
+/// [`SegmenterBuilder::abbreviation_conflicts()`](crate::SegmenterBuilder::abbreviation_conflicts).
+/// Only the kind of the pack of the highest priority is used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AbbreviationConflict {
+    /// Abbreviation, in lowercase.
+    pub abbreviation: String,
+    /// Name of the pack whose kind is used.
+    pub pack: String,
+    /// Kind which is used.
+    pub kind: AbbreviationKind,
+    /// Names of the packs which give the abbreviation another kind, with the kind, from the
+    /// highest priority.
+    pub overridden: Vec<(String, AbbreviationKind)>,
+}
+
+impl fmt::Display for AbbreviationConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} is {:?} in {:?}, overriding",
+            self.abbreviation, self.kind, self.pack
+        )?;
+        for (idx, (pack, kind)) in self.overridden.iter().enumerate() {
+            let separator = if idx == 0 { " " } else { ", " };
+            write!(f, "{}{:?} in {:?}", separator, kind, pack)?;
+        }
+        Ok(())
+    }
+}
+
+/// Merge `packs` into one list of abbreviations in lowercase, each with a single kind, along with
+/// the abbreviations whose kinds conflict.
+///
+/// Packs named in `priority` come first in that order, then the others in the order they were
+/// added. The first kind of an abbreviation in this order wins.
+pub(crate) fn merge(
+    packs: &[AbbreviationPack],
+    priority: &[String],
+) -> (Vec<(String, AbbreviationKind)>, Vec<AbbreviationConflict>) {
+    let mut ordered: Vec<_> = packs.iter().collect();
+    // NOTE: 안정 정렬이므로 우선순위가 없는 팩끼리는 추가된 순서가 유지된다.
+    ordered.sort_by_key(|pack| {
+        priority
+            .iter()
+            .position(|name| *name == pack.name)
+            .unwrap_or(priority.len())
+    });
+
+    let mut merged: Vec<(String, AbbreviationKind)> = Vec::new();
+    let mut winners: Vec<&str> = Vec::new();
+    let mut conflicts: Vec<AbbreviationConflict> = Vec::new();
+    for pack in ordered {
+        for (abbr, kind) in pack.entries() {
+            let abbr = abbr.to_lowercase();
+            let idx = match merged.iter().position(|(other, _)| *other == abbr) {
+                Some(idx) => idx,
+                None => {
+                    merged.push((abbr, kind));
+                    winners.push(pack.name());
+                    continue;
+                }
+            };
+            if merged[idx].1 == kind {
+                continue;
+            }
+            match conflicts.iter_mut().find(|c| c.abbreviation == abbr) {
+                Some(conflict) => conflict.overridden.push((pack.name().into(), kind)),
+                None => conflicts.push(AbbreviationConflict {
+                    abbreviation: abbr,
+                    pack: winners[idx].into(),
+                    kind: merged[idx].1,
+                    overridden: vec![(pack.name().into(), kind)],
+                }),
+            }
+        }
+    }
+    (merged, conflicts)
+}
+
+/// Error returned when an [`AbbreviationPack`] contains characters other than letters, combining
+/// marks, digits, `.` and `-`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidAbbreviation(pub String);
+
+impl fmt::Display for InvalidAbbreviation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid abbreviation: {:?}", self.0)
+    }
+}
+
+impl Error for InvalidAbbreviation {}
+
+/// "د." is "Dr." and "أ." is "Prof." or "Mr.", spelled with or without the hamza.
+const HONORIFICS_AR: &[&str] = &["د", "أ", "ا", "أ.د", "ا.د"];
+
+const HONORIFICS_DE: &[&str] = &[
+    "hr", "hrn", "fr", "frl", "dr", "prof", "dipl", "ing", "mag", "st",
+];
+
+#[rustfmt::skip]
+const HONORIFICS_ES: &[&str] = &[
+    "sr", "sra", "srta", "sres", "sras", "d", "dña", "dr", "dra", "lic", "ing", "prof", "ud",
+    "uds", "excmo", "excma", "ilmo", "ilma", "sto", "sta",
+];
+
+const HONORIFICS_FR: &[&str] = &[
+    "m", "mm", "mme", "mmes", "mlle", "mlles", "mgr", "me", "pr", "dr", "st", "ste",
+];
+
+/// "डॉ." is "Dr.", "स्व." is "the late", "कु." is "Miss" and "पं." is "Pandit".
+const HONORIFICS_HI: &[&str] = &["डॉ", "प्रो", "स्व", "कु", "सु", "पं", "मो"];
+
+#[rustfmt::skip]
+const HONORIFICS_IT: &[&str] = &[
+    "sig", "sigg", "sig.ra", "sig.na", "dott", "dott.ssa", "ing", "avv", "prof", "arch", "geom",
+    "rag", "on", "egr", "gent", "mons",
+];
+
+/// "проф." is "Prof.", "акад." is "Academician", and "т.ғ.д." and "ф.-м.ғ.д." are doctors of
+/// sciences.
+const HONORIFICS_KK: &[&str] = &["проф", "акад", "доц", "ген", "т.ғ.д", "ф.-м.ғ.д", "т.ғ.к"];
+
+/// "प्रा." is "Prof.", "सौ." is "Mrs.", "कै." is "the late" and "मा." is "the Honourable". A bride
+/// is "चि. सौ. कां.".
+const HONORIFICS_MR: &[&str] = &["डॉ", "प्रा", "सौ", "कु", "चि", "कां", "कै", "स्व", "मा"];
+
+#[rustfmt::skip]
+const HONORIFICS_PL: &[&str] = &[
+    "p", "pp", "prof", "dr", "hab", "inż", "mgr", "lic", "lek", "doc", "ks", "bp", "abp", "św",
+    "gen", "płk", "ppłk", "mjr", "kpt", "kmdr",
+];
+
+const HONORIFICS_PT: &[&str] = &[
+    "sr", "sra", "srs", "sras", "srta", "d", "dr", "dra", "prof", "profa", "eng", "exmo", "exma",
+];
+
+const HONORIFICS_RU: &[&str] = &["тов", "проф", "акад", "доц", "св", "ген", "полк", "кап"];
+
+const BIOMEDICAL_NUMBER: &[&str] = &["figs", "tab", "tabs", "eq", "eqs", "vol", "refs"];
+
+const BIOMEDICAL_PREPOSITIVE: &[&str] = &["suppl", "cv"];
+
+#[rustfmt::skip]
+const BIOMEDICAL_PLAIN: &[&str] = &[
+    "ca", "approx", "sp", "spp", "ssp", "subsp", "nov", "var", "resp", "conc", "comb", "n.s",
+];
+
+/// Dosing schedules and routes of administration.
+#[rustfmt::skip]
+pub(crate) const DOSING: &[&str] = &[
+    "b.i.d", "t.i.d", "q.i.d", "q.o.d", "q.d", "q.h", "p.r.n", "p.o", "i.v", "i.m", "i.p", "s.c",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge() {
+        let legal = AbbreviationPack::new("legal")
+            .with("cf", AbbreviationKind::Prepositive)
+            .with("no", AbbreviationKind::Number);
+        let medical = AbbreviationPack::new("medical")
+            .with("CF", AbbreviationKind::Plain)
+            .with("no", AbbreviationKind::Number)
+            .with("ca", AbbreviationKind::Plain);
+        let user = AbbreviationPack::new("user").with("cf", AbbreviationKind::Number);
+        let packs = [legal, medical, user];
+
+        let (merged, conflicts) = merge(&packs, &[]);
+        assert_eq!(
+            merged,
+            vec![
+                ("cf".to_string(), AbbreviationKind::Prepositive),
+                ("no".to_string(), AbbreviationKind::Number),
+                ("ca".to_string(), AbbreviationKind::Plain),
+            ]
+        );
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(
+            conflicts[0].to_string(),
+            r#""cf" is Prepositive in "legal", overriding Plain in "medical", Number in "user""#
+        );
+
+        let (merged, conflicts) = merge(&packs, &["user".into(), "medical".into()]);
+        assert_eq!(merged[0], ("cf".to_string(), AbbreviationKind::Number));
+        assert_eq!(conflicts[0].pack, "user");
+        assert_eq!(
+            conflicts[0].overridden,
+            vec![
+                ("medical".to_string(), AbbreviationKind::Plain),
+                ("legal".to_string(), AbbreviationKind::Prepositive),
+            ]
+        );
+    }
+}
@@ -1,29 +1,64 @@
 use std::borrow::Cow;
 use std::collections::{BTreeSet, HashSet};
+use std::io::{self, BufRead};
 use std::iter::Iterator;
+use std::ops::Range;
 
-use aho_corasick::{AhoCorasick, AhoCorasickBuilder, AhoCorasickKind, FindIter, MatchKind};
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder};
 use onig::{Captures, Error, Regex};
-use unic_ucd_case::is_cased;
 
-use crate::rule::Rule;
+use crate::abbreviation_pack::{merge, AbbreviationKind};
+use crate::builder::{AcronymBoundary, SegmenterBuilder};
+use crate::compat::python::{isupper, SplitLines};
+use crate::rule::{Rule, RuleSet};
+use crate::trace::{Stage, Tracer};
 use crate::util::{re, re_i};
 
+/// The abbreviation rules of a [`Segmenter`](crate::Segmenter), which replace the periods of
+/// abbreviations, initials and acronyms with [`PLACEHOLDER`](AbbreviationReplacer::PLACEHOLDER).
+/// A tokenizer can run on the result to keep abbreviations in one piece, without a full
+/// segmentation. Get one with [`Segmenter::abbreviation_replacer()`](
+/// crate::Segmenter::abbreviation_replacer).
+///
+/// ```rust
+/// use pragmatic_segmenter::{AbbreviationReplacer, Segmenter};
+///
+/// let segmenter = Segmenter::new()?;
+/// let replacer = segmenter.abbreviation_replacer();
+/// let text = replacer.replace("Dr. Kim met Mr. Lee at 5 p.m. today. Bye.");
+/// assert_eq!(text, "Dr∯ Kim met Mr∯ Lee at 5 p∯m∯ today. Bye.");
+///
+/// let tokens: Vec<_> = text
+///     .split_whitespace()
+///     .map(|token| token.replace(AbbreviationReplacer::PLACEHOLDER, "."))
+///     .collect();
+/// assert_eq!(tokens[..3], ["Dr.", "Kim", "met"]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 pub struct AbbreviationReplacer {
     possessive_abbreviation_rule: Rule,
     kommanditgesellschaft_rule: Rule,
-    single_letter_abbreviation_rules: [Rule; 2],
-    am_pm_rules: [Rule; 4],
-
-    python_splitlines_keepends: PythonSplitLines,
-
-    abbreviations: Vec<(&'static str, Regex, Regex)>,
-    prepositive_abbreviations: HashSet<&'static str>,
-    number_abbreviations: HashSet<&'static str>,
+    single_letter_abbreviation_rules: RuleSet,
+    initials_regex: Option<Regex>,
+    am_pm_rules: RuleSet,
+
+    python_splitlines_keepends: SplitLines,
+
+    abbreviations: Vec<(Cow<'static, str>, Regex, Regex)>,
+    /// Every abbreviation followed by a period, in the cases which ASCII case insensitivity
+    /// doesn't cover
+    abbreviation_scanner: AhoCorasick,
+    /// Index in `abbreviations` of each pattern of `abbreviation_scanner`
+    scanned_abbreviations: Vec<usize>,
+    prepositive_abbreviations: HashSet<Cow<'static, str>>,
+    number_abbreviations: HashSet<Cow<'static, str>>,
+    /// Regex of a lowercase letter, which keeps the period of an abbreviation before it
+    lower: String,
 
     multi_period_abbreviation_regex: Regex,
 
     replace_abbreviation_as_sentence_boundary: Rule,
+    acronym_as_sentence_boundary: Option<Rule>,
 }
 
 // NOTE: 이 글자들은 regex 안에 들어간다. ABBREVIATIONS를 고칠경우 특수문자를 사용하지 않도록
@@ -55,44 +90,155 @@ const PREPOSITIVE_ABBREVIATIONS: &[&str] = &[
 
 const NUMBER_ABBREVIATIONS: &[&str] = &["art", "ext", "no", "nos", "p", "pp"];
 
+// AbbreviationReplacer.SENTENCE_STARTERS
+#[rustfmt::skip]
+const SENTENCE_STARTERS: &[&str] = &[
+    "A", "Being", "Did", "For", "He", "How", "However", "I", "In", "It", "Millions", "More", "She",
+    "That", "The", "There", "They", "We", "What", "When", "Where", "Who", "Why",
+];
+
 impl AbbreviationReplacer {
-    pub fn new() -> Result<Self, Error> {
+    /// Replacement of the periods of abbreviations. Every other character is kept as it is, so
+    /// the result has the same number of characters as the input.
+    pub const PLACEHOLDER: char = '∯';
+
+    pub(crate) fn new(builder: &SegmenterBuilder) -> Result<Self, Box<dyn std::error::Error>> {
+        let upper = builder.uppercase_pattern();
+        let letter = builder.letter_class();
+
+        let mut abbreviations: Vec<Cow<'static, str>> = ABBREVIATIONS
+            .iter()
+            .map(|&abbr| Cow::Borrowed(abbr))
+            .collect();
+        let mut prepositive_abbreviations: HashSet<_> = PREPOSITIVE_ABBREVIATIONS
+            .iter()
+            .map(|&abbr| Cow::Borrowed(abbr))
+            .collect();
+        let mut number_abbreviations: HashSet<_> = NUMBER_ABBREVIATIONS
+            .iter()
+            .map(|&abbr| Cow::Borrowed(abbr))
+            .collect();
+        let (merged, _) = merge(&builder.abbreviation_packs, &builder.abbreviation_priority);
+        for (abbr, kind) in merged {
+            let abbr: Cow<'static, str> = Cow::Owned(abbr);
+            if !abbreviations.contains(&abbr) {
+                abbreviations.push(abbr.clone());
+            }
+            match kind {
+                AbbreviationKind::Plain => {}
+                AbbreviationKind::Prepositive => {
+                    prepositive_abbreviations.insert(abbr);
+                }
+                AbbreviationKind::Number => {
+                    number_abbreviations.insert(abbr);
+                }
+            }
+        }
+
+        // NOTE: Aho-Corasick은 ASCII 글자만 대소문자를 무시하므로, "Ул." 같은 약어를 찾기 위해
+        // 첫 글자만 대문자인 것과 모두 대문자인 것을 따로 넣어둔다.
+        let mut scanned_abbreviations = Vec::new();
+        let mut scanned_patterns = Vec::new();
+        for (idx, abbr) in abbreviations.iter().enumerate() {
+            let mut chars = abbr.chars();
+            let capitalized: String = chars
+                .next()
+                .into_iter()
+                .flat_map(char::to_uppercase)
+                .collect();
+            let variants = [
+                abbr.to_string(),
+                capitalized + chars.as_str(),
+                abbr.to_uppercase(),
+            ];
+            for (i, variant) in variants.iter().enumerate() {
+                if variants[..i]
+                    .iter()
+                    .all(|other| !other.eq_ignore_ascii_case(variant))
+                {
+                    scanned_abbreviations.push(idx);
+                    scanned_patterns.push(format!("{}.", variant));
+                }
+            }
+        }
+        let abbreviation_scanner = AhoCorasickBuilder::new()
+            .ascii_case_insensitive(true)
+            .build(&scanned_patterns)?;
+
         Ok(AbbreviationReplacer {
             // Example: https://rubular.com/r/yqa4Rit8EY
-            possessive_abbreviation_rule: Rule::new(r"\.(?='s\s)|\.(?='s$)|\.(?='s\Z)", "∯")?,
+            possessive_abbreviation_rule: Rule::new(
+                "PossessiveAbbreviationRule",
+                r"\.(?='s\s)|\.(?='s$)|\.(?='s\Z)",
+                "∯",
+            )?,
 
             // Example: https://rubular.com/r/NEv265G2X2
-            kommanditgesellschaft_rule: Rule::new(r"(?<=Co)\.(?=\sKG)", "∯")?,
+            kommanditgesellschaft_rule: Rule::new(
+                "KommanditgesellschaftRule",
+                r"(?<=Co)\.(?=\sKG)",
+                "∯",
+            )?,
 
-            single_letter_abbreviation_rules: [
-                // SingleUpperCaseLetterAtStartOfLineRule
+            single_letter_abbreviation_rules: vec![
                 // Example: https://rubular.com/r/e3H6kwnr6H
-                Rule::new(r"(?<=^[A-Z])\.(?=\s)", "∯")?,
-                // SingleUpperCaseLetterRule
+                Rule::new(
+                    "SingleUpperCaseLetterAtStartOfLineRule",
+                    &format!(r"(?<=^{upper})\.(?=\s)", upper = upper),
+                    "∯",
+                )?,
                 // Example: https://rubular.com/r/gitvf0YWH4
-                Rule::new(r"(?<=\s[A-Z])\.(?=,?\s)", "∯")?,
-            ],
-
-            am_pm_rules: [
-                // UpperCasePmRule
+                Rule::new(
+                    "SingleUpperCaseLetterRule",
+                    &format!(r"(?<=\s{upper})\.(?=,?\s)", upper = upper),
+                    "∯",
+                )?,
+            ]
+            .into(),
+
+            // Example: "J. R. R. Tolkien", "(W.E.B. Du Bois)", "—J. Doe"
+            initials_regex: if builder.name_initials {
+                Some(re(&format!(
+                    r#"(?:\A|(?<=[\s(\[{{"'“‘«—–-]))(?:{upper}\.\s?)+(?=\s*{upper})"#,
+                    upper = upper
+                ))?)
+            } else {
+                None
+            },
+
+            am_pm_rules: vec![
                 // Example: https://rubular.com/r/Vnx3m4Spc8
-                Rule::new(r"(?<= P∯M)∯(?=\s[A-Z])", ".")?,
-                // UpperCaseAmRule
+                Rule::new(
+                    "UpperCasePmRule",
+                    &format!(r"(?<= P∯M)∯(?=\s{upper})", upper = upper),
+                    ".",
+                )?,
                 // Example: https://rubular.com/r/AJMCotJVbW
-                Rule::new(r"(?<=A∯M)∯(?=\s[A-Z])", ".")?,
-                // LowerCasePmRule
+                Rule::new(
+                    "UpperCaseAmRule",
+                    &format!(r"(?<=A∯M)∯(?=\s{upper})", upper = upper),
+                    ".",
+                )?,
                 // Example: https://rubular.com/r/13q7SnOhgA
-                Rule::new(r"(?<=p∯m)∯(?=\s[A-Z])", ".")?,
-                // LowerCaseAmRule
+                Rule::new(
+                    "LowerCasePmRule",
+                    &format!(r"(?<=p∯m)∯(?=\s{upper})", upper = upper),
+                    ".",
+                )?,
                 // Example: https://rubular.com/r/DgUDq4mLz5
-                Rule::new(r"(?<=a∯m)∯(?=\s[A-Z])", ".")?,
-            ],
-
-            python_splitlines_keepends: PythonSplitLines::new(),
-
-            abbreviations: ABBREVIATIONS
-                .iter()
-                .map(|&abbr| -> Result<_, Error> {
+                Rule::new(
+                    "LowerCaseAmRule",
+                    &format!(r"(?<=a∯m)∯(?=\s{upper})", upper = upper),
+                    ".",
+                )?,
+            ]
+            .into(),
+
+            python_splitlines_keepends: SplitLines::new(),
+
+            abbreviations: abbreviations
+                .into_iter()
+                .map(|abbr| -> Result<_, Error> {
                     // NOTE: 여기에서도 escaped이 된 abbr을 써야하지만, pySBD와 동작을 유지하기위해
                     // 의도적으로 abbr를 바로 사용한다
                     //
@@ -109,52 +255,271 @@ impl AbbreviationReplacer {
                     Ok((abbr, abbr_match, next_word_start))
                 })
                 .collect::<Result<_, _>>()?,
+            abbreviation_scanner,
+            scanned_abbreviations,
 
-            prepositive_abbreviations: PREPOSITIVE_ABBREVIATIONS.iter().copied().collect(),
-            number_abbreviations: NUMBER_ABBREVIATIONS.iter().copied().collect(),
+            prepositive_abbreviations,
+            number_abbreviations,
+            lower: builder.lowercase_pattern().to_string(),
 
             // Example: https://rubular.com/r/xDkpFZ0EgH
-            multi_period_abbreviation_regex: re_i(r"\b[a-z](?:\.[a-z])+[.]")?,
+            multi_period_abbreviation_regex: re_i(&format!(
+                r"\b[{letter}](?:\.[{letter}])+[.]",
+                letter = letter
+            ))?,
 
             replace_abbreviation_as_sentence_boundary: Rule::new(
-                r"(U∯S|U\.S|U∯K|E∯U|E\.U|U∯S∯A|U\.S\.A|I|i.v|I.V)∯((?=\sA\s)|(?=\sBeing\s)|(?=\sDid\s)|(?=\sFor\s)|(?=\sHe\s)|(?=\sHow\s)|(?=\sHowever\s)|(?=\sI\s)|(?=\sIn\s)|(?=\sIt\s)|(?=\sMillions\s)|(?=\sMore\s)|(?=\sShe\s)|(?=\sThat\s)|(?=\sThe\s)|(?=\sThere\s)|(?=\sThey\s)|(?=\sWe\s)|(?=\sWhat\s)|(?=\sWhen\s)|(?=\sWhere\s)|(?=\sWho\s)|(?=\sWhy\s))",
+                "ReplaceAbbreviationAsSentenceBoundaryRule",
+                &format!(
+                    r"(U∯S|U\.S|U∯K|E∯U|E\.U|U∯S∯A|U\.S\.A|I|i.v|I.V)∯({starters})",
+                    starters = SENTENCE_STARTERS
+                        .iter()
+                        .map(|word| format!(r"(?=\s{}\s)", word))
+                        .collect::<Vec<_>>()
+                        .join("|")
+                ),
                 r"\1.",
             )?,
+
+            // 여러 마침표로 된 약어는 multi_period_abbreviation_regex가 통째로 ∯로 바꿔두므로,
+            // 마지막 마침표만 되돌린다.
+            acronym_as_sentence_boundary: match &builder.acronym_boundary {
+                AcronymBoundary::Compatible => None,
+                AcronymBoundary::SentenceStarters => Some(
+                    Rule::new(
+                        "AcronymAsSentenceBoundaryRule",
+                        &format!(
+                            r"(\b[{letter}](?:∯[{letter}])+)∯(?=\s(?:{starters})\s)",
+                            letter = letter,
+                            starters = SENTENCE_STARTERS.join("|")
+                        ),
+                        r"\1.",
+                    )?
+                    .with_example("in D∯C∯ He left", "in D∯C. He left")
+                    .with_example("in D∯C∯ Hector left", "in D∯C∯ Hector left"),
+                ),
+                AcronymBoundary::Uppercase => Some(
+                    Rule::new(
+                        "AcronymAsSentenceBoundaryRule",
+                        &format!(
+                            r"(\b[{letter}](?:∯[{letter}])+)∯(?=\s{upper})",
+                            letter = letter,
+                            upper = upper
+                        ),
+                        r"\1.",
+                    )?
+                    .with_example("the U∯S∯S∯R∯ Hector", "the U∯S∯S∯R. Hector")
+                    .with_example("the U∯S∯S∯R∯ army", "the U∯S∯S∯R∯ army"),
+                ),
+            },
         })
     }
 
+    pub(crate) fn rules(&self) -> impl Iterator<Item = &Rule> {
+        Some(&self.possessive_abbreviation_rule)
+            .into_iter()
+            .chain(Some(&self.kommanditgesellschaft_rule))
+            .chain(&self.single_letter_abbreviation_rules)
+            .chain(&self.am_pm_rules)
+            .chain(Some(&self.replace_abbreviation_as_sentence_boundary))
+            .chain(&self.acronym_as_sentence_boundary)
+    }
+
+    pub(crate) fn rules_mut(&mut self) -> impl Iterator<Item = &mut Rule> {
+        Some(&mut self.possessive_abbreviation_rule)
+            .into_iter()
+            .chain(Some(&mut self.kommanditgesellschaft_rule))
+            .chain(self.single_letter_abbreviation_rules.iter_mut())
+            .chain(self.am_pm_rules.iter_mut())
+            .chain(Some(&mut self.replace_abbreviation_as_sentence_boundary))
+            .chain(&mut self.acronym_as_sentence_boundary)
+    }
+
+    /// Replace the periods of abbreviations in `text` with
+    /// [`PLACEHOLDER`](AbbreviationReplacer::PLACEHOLDER).
     pub fn replace(&self, text: &str) -> String {
-        let text = self.possessive_abbreviation_rule.replace_all(text);
-        let mut text = self.kommanditgesellschaft_rule.replace_all(&text);
-        for rule in &self.single_letter_abbreviation_rules {
-            text = rule.replace_all(&text);
-        }
+        self.replace_traced(text, Tracer::default())
+    }
+
+    pub(crate) fn replace_traced(&self, text: &str, tracer: Tracer<'_>) -> String {
+        STEPS
+            .iter()
+            .fold(text.to_string(), |text, &step| self.apply(step, &text, tracer))
+    }
 
-        let text = {
-            // NOTE: 이 부분 pySBD와 원본 루비 구현체 (pragmatic-segmenter)의
-            // 동작이 전혀 다른데, pySBD를 따라간다.
-            let mut abbr_handled_text = String::new();
-            for line in self.python_splitlines_keepends.splitlines_keepends(&text) {
-                abbr_handled_text += &self.search_for_abbreviations_in_string(line);
+    /// Same as [`replace()`](AbbreviationReplacer::replace) for every line of `input`, one line
+    /// at a time, so that a large input never has to be in memory at once. Each line keeps its
+    /// line break. A period at the end of a line is decided without looking at the next line,
+    /// which may differ from [`replace()`](AbbreviationReplacer::replace) of the whole input.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::new()?;
+    /// let input = "Hi Mr. Kim.\nIt is No. 5.\n";
+    /// let lines = segmenter
+    ///     .abbreviation_replacer()
+    ///     .replace_lines(input.as_bytes())
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(lines, vec!["Hi Mr∯ Kim.\n", "It is No∯ 5.\n"]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn replace_lines<'a>(
+        &'a self,
+        mut input: impl BufRead + 'a,
+    ) -> impl Iterator<Item = io::Result<String>> + 'a {
+        let mut line = String::new();
+        std::iter::from_fn(move || {
+            line.clear();
+            match input.read_line(&mut line) {
+                Ok(0) => None,
+                Ok(_) => Some(Ok(self.replace(&line))),
+                Err(err) => Some(Err(err)),
             }
-            abbr_handled_text
-        };
+        })
+    }
 
-        // replace_multi_period_abbreviations()
-        let mut text = self
-            .multi_period_abbreviation_regex
-            .replace_all(&text, |c: &Captures| {
-                let mat = c.at(0).unwrap(); // Must exists
-                mat.replace('.', "∯")
-            });
+    /// Byte offsets of the periods in `text` which are abbreviation periods, and their
+    /// categories.
+    pub(crate) fn abbreviation_periods(&self, text: &str) -> Vec<(usize, AbbreviationCategory)> {
+        // 각 단계는 '.'과 '∯'를 서로 바꾸기만 하므로, 글자 단위로 원문과 대응시킬 수 있다.
+        let mut categories: Vec<Option<AbbreviationCategory>> = vec![None; text.chars().count()];
+        let mut before = text.to_string();
+        for &step in STEPS {
+            let after = self.apply(step, &before, Tracer::default());
+            debug_assert_eq!(before.chars().count(), after.chars().count());
+            let chars = text.char_indices().zip(before.chars().zip(after.chars()));
+            for (category, ((idx, _), (b, a))) in categories.iter_mut().zip(chars) {
+                match (b, a) {
+                    ('.', '∯') => *category = Some(self.categorize(step, text, idx)),
+                    ('∯', '.') => *category = None,
+                    _ => {}
+                }
+            }
+            before = after;
+        }
+        text.char_indices()
+            .zip(categories)
+            .filter_map(|((idx, c), category)| Some((idx, category.filter(|_| c == '.')?)))
+            .collect()
+    }
+
+    /// Known abbreviations followed by a period in `text`, with their kinds, whether or not the
+    /// period was judged to be the period of an abbreviation. An abbreviation must be a whole
+    /// word, so "p." of "p.m." doesn't count, and the longest one is taken when they overlap.
+    pub(crate) fn occurrences(&self, text: &str) -> Vec<(Range<usize>, AbbreviationKind)> {
+        let mut matches: Vec<_> = self
+            .abbreviation_scanner
+            .find_overlapping_iter(text)
+            .filter(|mat| {
+                !text[..mat.start()]
+                    .chars()
+                    .next_back()
+                    .is_some_and(char::is_alphanumeric)
+                    && !text[mat.end()..]
+                        .chars()
+                        .next()
+                        .is_some_and(char::is_alphanumeric)
+            })
+            .collect();
+        matches.sort_by_key(|mat| (mat.start(), std::cmp::Reverse(mat.end())));
+
+        let mut occurrences: Vec<(Range<usize>, AbbreviationKind)> = Vec::new();
+        for mat in matches {
+            if occurrences
+                .last()
+                .is_some_and(|(last, _)| mat.start() < last.end)
+            {
+                continue;
+            }
+            let abbr = self.abbreviations[self.scanned_abbreviations[mat.pattern().as_usize()]]
+                .0
+                .as_ref();
+            let kind = if self.prepositive_abbreviations.contains(abbr) {
+                AbbreviationKind::Prepositive
+            } else if self.number_abbreviations.contains(abbr) {
+                AbbreviationKind::Number
+            } else {
+                AbbreviationKind::Plain
+            };
+            occurrences.push((mat.range(), kind));
+        }
+        occurrences
+    }
 
-        for rule in &self.am_pm_rules {
-            text = rule.replace_all(&text);
+    fn categorize(&self, step: Step, text: &str, period: usize) -> AbbreviationCategory {
+        match step {
+            Step::Possessive => AbbreviationCategory::Possessive,
+            Step::SingleLetter => AbbreviationCategory::Initial,
+            Step::MultiPeriod => AbbreviationCategory::MultiPeriod,
+            Step::Abbreviations => {
+                // search_for_abbreviations_in_string()에서 마침표 바로 앞에 공백으로 시작하는
+                // 약어가 있을때만 마침표를 바꾸므로, 공백 뒤의 단어가 곧 약어다.
+                let start = text[..period].rfind(char::is_whitespace).map_or(0, |idx| {
+                    idx + text[idx..].chars().next().unwrap().len_utf8()
+                });
+                let abbr = text[start..period].to_lowercase();
+                if self.prepositive_abbreviations.contains(abbr.as_str()) {
+                    AbbreviationCategory::Prepositive
+                } else if self.number_abbreviations.contains(abbr.as_str()) {
+                    AbbreviationCategory::Number
+                } else {
+                    AbbreviationCategory::Plain
+                }
+            }
+            Step::Kommanditgesellschaft | Step::AmPm | Step::SentenceBoundary => {
+                AbbreviationCategory::Plain
+            }
         }
+    }
 
-        // replace_abbreviation_as_sentence_boundary()
-        self.replace_abbreviation_as_sentence_boundary
-            .replace_all(&text)
+    fn apply(&self, step: Step, text: &str, tracer: Tracer<'_>) -> String {
+        let apply = |rule: &Rule, text: &str| tracer.apply(Stage::Abbreviations, rule, text);
+        let apply_all = |rules: &RuleSet, text: &str| {
+            rules
+                .iter()
+                .fold(text.to_string(), |text, rule| apply(rule, &text))
+        };
+        match step {
+            Step::Possessive => apply(&self.possessive_abbreviation_rule, text),
+            Step::Kommanditgesellschaft => apply(&self.kommanditgesellschaft_rule, text),
+            Step::SingleLetter => {
+                let mut text = apply_all(&self.single_letter_abbreviation_rules, text);
+                if let Some(initials_regex) = &self.initials_regex {
+                    text = initials_regex.replace_all(&text, |c: &Captures| {
+                        let mat = c.at(0).unwrap(); // Must exists
+                        mat.replace('.', "∯")
+                    });
+                }
+                text
+            }
+            Step::Abbreviations => {
+                // NOTE: 이 부분 pySBD와 원본 루비 구현체 (pragmatic-segmenter)의
+                // 동작이 전혀 다른데, pySBD를 따라간다.
+                let mut abbr_handled_text = String::new();
+                for line in self.python_splitlines_keepends.splitlines_keepends(text) {
+                    abbr_handled_text += &self.search_for_abbreviations_in_string(line);
+                }
+                abbr_handled_text
+            }
+            // replace_multi_period_abbreviations()
+            Step::MultiPeriod => {
+                self.multi_period_abbreviation_regex
+                    .replace_all(text, |c: &Captures| {
+                        let mat = c.at(0).unwrap(); // Must exists
+                        mat.replace('.', "∯")
+                    })
+            }
+            Step::AmPm => apply_all(&self.am_pm_rules, text),
+            // replace_abbreviation_as_sentence_boundary()
+            Step::SentenceBoundary => {
+                let text = apply(&self.replace_abbreviation_as_sentence_boundary, text);
+                match &self.acronym_as_sentence_boundary {
+                    Some(rule) => apply(rule, &text),
+                    None => text,
+                }
+            }
+        }
     }
 
     fn search_for_abbreviations_in_string<'a>(&self, text: &'a str) -> Cow<'a, str> {
@@ -162,7 +527,7 @@ impl AbbreviationReplacer {
 
         let mut text = Cow::Borrowed(text);
         for (abbr, abbr_match_regex, next_word_start_regex) in &self.abbreviations {
-            if !lowered.contains(abbr) {
+            if !lowered.contains(abbr.as_ref()) {
                 continue;
             }
             let abbrev_match: Vec<_> = abbr_match_regex.find_iter(&text).collect();
@@ -186,7 +551,7 @@ impl AbbreviationReplacer {
                 // References:
                 //   https://github.com/nipunsadvilkar/pySBD/blob/90699972/pysbd/abbreviation_replacer.py#L104
                 //   https://github.com/diasks2/pragmatic_segmenter/blob/1ade491c/lib/pragmatic_segmenter/abbreviation_replacer.rb#L51
-                let upper = python_isupper(ch);
+                let upper = isupper(ch);
                 let abbr_lower = abbr.to_lowercase();
                 let abbr_lower = abbr_lower.as_str();
                 let is_prepositive = self.prepositive_abbreviations.contains(abbr_lower);
@@ -202,8 +567,9 @@ impl AbbreviationReplacer {
                     } else {
                         // replace_period_of_abbr()
                         format!(
-                            r"(?<=\s{abbr})\.(?=((\.|\:|-|\?|,)|(\s([a-z]|I\s|I'm|I'll|\d|\())))",
-                            abbr = abbr
+                            r"(?<=\s{abbr})\.(?=((\.|\:|-|\?|,)|(\s({lower}|I\s|I'm|I'll|\d|\())))",
+                            abbr = abbr,
+                            lower = self.lower,
                         )
                     };
 
@@ -237,152 +603,81 @@ impl AbbreviationReplacer {
     }
 }
 
-/// Rust implementation of Python's [`str.splitlines(keepends=True)`][ref].
-///
-/// [ref]: https://docs.python.org/3/library/stdtypes.html#str.splitlines
-struct PythonSplitLines(AhoCorasick);
-
-impl PythonSplitLines {
-    fn new() -> Self {
-        let newlines = &[
-            "\r\n",     // Carriage Return + Line Feed
-            "\n",       // Line Feed
-            "\r",       // Carriage Return
-            "\x0b",     // Line Tabulation
-            "\x0c",     // Form Feed
-            "\x1c",     // File Separator
-            "\x1d",     // Group Separator
-            "\x1e",     // Record Separator
-            "\u{85}",   // Next Line (C1 Control Code)
-            "\u{2028}", // Line Separator
-            "\u{2029}", // Paragraph Separator
-        ];
-
-        Self(
-            AhoCorasickBuilder::new()
-                .match_kind(MatchKind::LeftmostFirst)
-                .kind(Some(AhoCorasickKind::DFA))
-                .build(newlines)
-                .unwrap(), // NOTE: It does not fails with our small input
-        )
-    }
-
-    fn splitlines_keepends<'a>(&self, input: &'a str) -> PythonSplitLinesKeepEnds<'_, 'a> {
-        PythonSplitLinesKeepEnds {
-            input,
-            last_index: 0,
-            searcher: self.0.find_iter(input),
-        }
-    }
-}
-
-struct PythonSplitLinesKeepEnds<'ac, 'input> {
-    input: &'input str,
-    last_index: usize,
-    searcher: FindIter<'ac, 'input>,
-}
-
-impl<'ac, 'input> Iterator for PythonSplitLinesKeepEnds<'ac, 'input> {
-    type Item = &'input str;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        match self.searcher.next() {
-            Some(mat) => {
-                let begin = self.last_index;
-                let end = mat.end();
-                self.last_index = end;
-                Some(&self.input[begin..end])
-            }
-            None => {
-                let last = self.last_index;
-                let len = self.input.len();
-                if last < len {
-                    self.last_index = len;
-                    Some(&self.input[last..len])
-                } else {
-                    None
-                }
-            }
-        }
-    }
+#[derive(Debug, Clone, Copy)]
+enum Step {
+    Possessive,
+    Kommanditgesellschaft,
+    SingleLetter,
+    Abbreviations,
+    MultiPeriod,
+    AmPm,
+    SentenceBoundary,
 }
 
-#[test]
-fn test_python_splitlines_keepends() {
-    let splitter = PythonSplitLines::new();
-
-    let input = "x\nx\rx\r\nx\x0bx\x0cx\x1cx\x1dx\x1ex\u{85}x\u{2028}x\u{2029}";
-    let output = [
-        "x\n",
-        "x\r",
-        "x\r\n",
-        "x\x0b",
-        "x\x0c",
-        "x\x1c",
-        "x\x1d",
-        "x\x1e",
-        "x\u{85}",
-        "x\u{2028}",
-        "x\u{2029}",
-    ];
-    assert_eq!(
-        splitter.splitlines_keepends(input).collect::<Vec<_>>(),
-        output
-    );
-
-    let input = "\n\na";
-    let output = ["\n", "\n", "a"];
-    assert_eq!(
-        splitter.splitlines_keepends(input).collect::<Vec<_>>(),
-        output
-    );
-}
+/// Steps of [`AbbreviationReplacer::replace()`], in order.
+const STEPS: &[Step] = &[
+    Step::Possessive,
+    Step::Kommanditgesellschaft,
+    Step::SingleLetter,
+    Step::Abbreviations,
+    Step::MultiPeriod,
+    Step::AmPm,
+    Step::SentenceBoundary,
+];
 
-/// Rust implementation of Python's [`str.isupper()`][ref].
-///
-/// [ref]: https://docs.python.org/3/library/stdtypes.html#str.isupper
-///
-/// Reference: https://github.com/RustPython/RustPython/pull/1577
-fn python_isupper(text: &str) -> bool {
-    let mut cased = false;
-    for c in text.chars() {
-        if is_cased(c) && c.is_uppercase() {
-            cased = true
-        } else if is_cased(c) && c.is_lowercase() {
-            return false;
-        }
-    }
-    cased
+/// Why a period was judged to be the period of an abbreviation, returned by
+/// [`Segmenter::abbreviation_periods()`](crate::Segmenter::abbreviation_periods).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AbbreviationCategory {
+    /// A known abbreviation like "etc." or "Inc." followed by a word which doesn't look like
+    /// the start of a sentence. See [`AbbreviationKind::Plain`].
+    Plain,
+    /// A known abbreviation which comes before a name, like "Dr.". See
+    /// [`AbbreviationKind::Prepositive`].
+    Prepositive,
+    /// A known abbreviation which comes before a number, like "No.". See
+    /// [`AbbreviationKind::Number`].
+    Number,
+    /// An abbreviation with multiple periods like "U.S.A." or "a.m.".
+    MultiPeriod,
+    /// A single uppercase letter like the initial of a name.
+    Initial,
+    /// An abbreviation followed by 's, like "Jr.'s".
+    Possessive,
 }
 
-#[test]
-fn test_python_isupper() {
-    assert!(!python_isupper("abc"));
-    assert!(!python_isupper("123"));
-    assert!(python_isupper("A_B"));
-    assert!(!python_isupper("a_b"));
-    assert!(python_isupper("A1"));
-    assert!(python_isupper("1A"));
-    assert!(!python_isupper("a1"));
-    assert!(!python_isupper("1a"));
-    assert!(!python_isupper("가나다a"));
-    assert!(python_isupper("가나다A"));
+/// A known abbreviation found in the input, returned by
+/// [`Segmenter::abbreviation_occurrences()`](crate::Segmenter::abbreviation_occurrences).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AbbreviationOccurrence<'a> {
+    /// Text of the abbreviation including the period. This is a slice of the input.
+    pub text: &'a str,
+    /// Byte offset of [`text`](AbbreviationOccurrence::text) in the input.
+    pub start: usize,
+    /// Kind of the abbreviation in the list of known abbreviations.
+    pub kind: AbbreviationKind,
+    /// Why the period was judged to be the period of an abbreviation, or `None` if the
+    /// abbreviation rules left it as a possible sentence boundary.
+    pub category: Option<AbbreviationCategory>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::AbbreviationPack;
 
-    type TestResult = Result<(), Error>;
+    type TestResult = Result<(), Box<dyn std::error::Error>>;
 
     #[test]
     fn regex_should_be_compiled() {
-        assert!(AbbreviationReplacer::new().is_ok())
+        assert!(AbbreviationReplacer::new(&SegmenterBuilder::new()).is_ok())
     }
 
     #[test]
     fn test_abbr_replace() -> TestResult {
-        let rep = AbbreviationReplacer::new()?;
+        let rep = AbbreviationReplacer::new(&SegmenterBuilder::new())?;
 
         assert_eq!(
             rep.replace("Humana Inc. is including"),
@@ -392,9 +687,92 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_initials() -> TestResult {
+        let rep = AbbreviationReplacer::new(&SegmenterBuilder::new().name_initials(true))?;
+
+        assert_eq!(
+            rep.replace("'J. R. R. Tolkien' and —W.E.B. Du Bois"),
+            "'J∯ R∯ R∯ Tolkien' and —W∯E∯B∯ Du Bois"
+        );
+        assert_eq!(rep.replace("Hello.J. doe"), "Hello.J. doe");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_acronym_boundary() -> TestResult {
+        let text = "In D.C. He met the U.S.S.R. Army, e.t.c. Then left.";
+
+        let rep = AbbreviationReplacer::new(&SegmenterBuilder::new())?;
+        assert_eq!(
+            rep.replace(text),
+            "In D∯C∯ He met the U∯S∯S∯R∯ Army, e∯t∯c∯ Then left."
+        );
+
+        let builder = SegmenterBuilder::new().acronym_boundary(AcronymBoundary::SentenceStarters);
+        let rep = AbbreviationReplacer::new(&builder)?;
+        assert_eq!(
+            rep.replace(text),
+            "In D∯C. He met the U∯S∯S∯R∯ Army, e∯t∯c∯ Then left."
+        );
+
+        let builder = SegmenterBuilder::new().acronym_boundary(AcronymBoundary::Uppercase);
+        let rep = AbbreviationReplacer::new(&builder)?;
+        assert_eq!(
+            rep.replace(text),
+            "In D∯C. He met the U∯S∯S∯R. Army, e∯t∯c. Then left."
+        );
+        rep.rules().try_for_each(Rule::check_examples)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_occurrences() -> TestResult {
+        let pack = AbbreviationPack::new("test").with("Approx", AbbreviationKind::Plain);
+        let rep = AbbreviationReplacer::new(&SegmenterBuilder::new().abbreviations(pack))?;
+        let text = "MR. Kim, e.g. approx. 5 at No. 3, not nr.5 or first. (dr.) i.e.x.";
+        let occurrences: Vec<_> = rep
+            .occurrences(text)
+            .into_iter()
+            .map(|(range, kind)| (&text[range], kind))
+            .collect();
+        assert_eq!(
+            occurrences,
+            vec![
+                ("MR.", AbbreviationKind::Prepositive),
+                ("e.g.", AbbreviationKind::Plain),
+                ("approx.", AbbreviationKind::Plain),
+                ("No.", AbbreviationKind::Number),
+                ("dr.", AbbreviationKind::Prepositive),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_abbreviation_periods() -> TestResult {
+        use AbbreviationCategory::*;
+        let rep = AbbreviationReplacer::new(&SegmenterBuilder::new())?;
+
+        assert_eq!(
+            rep.abbreviation_periods("That is JFK Jr.'s book by John F. Kennedy, Inc. in town."),
+            vec![(14, Possessive), (32, Initial), (46, Plain)]
+        );
+        // The last period of "P.M." is a sentence boundary.
+        assert_eq!(
+            rep.abbreviation_periods("He left at 6 P.M. Mr. Smith then left."),
+            vec![(14, MultiPeriod), (20, Prepositive)]
+        );
+        assert_eq!(rep.abbreviation_periods("Hello. World."), vec![]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_search_for_abbreviations_in_string() -> TestResult {
-        let rep = AbbreviationReplacer::new()?;
+        let rep = AbbreviationReplacer::new(&SegmenterBuilder::new())?;
 
         assert_eq!(
             rep.search_for_abbreviations_in_string("Humana Inc. is including"),
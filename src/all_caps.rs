@@ -0,0 +1,79 @@
+/// Lowercase every line of `text` which is written in capital letters only, like a headline or
+/// "THIS AGREEMENT IS MADE BY AND BETWEEN ACME INC. AND JOHN DOE.", so that the rules which look
+/// at the case of the next word work again. `None` if there's no such line.
+///
+/// Words of a single letter, like "I" or the "A" of "PLAN A.", are kept, since the rules treat
+/// them as initials and list item markers. Letters whose lowercase has a different length in
+/// UTF-8 are kept too, so that every byte offset of the result is also a byte offset of `text`.
+pub(crate) fn fold(text: &str) -> Option<String> {
+    if !text.split('\n').any(is_all_caps) {
+        return None;
+    }
+    let mut folded = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        if is_all_caps(line) {
+            fold_line(line, &mut folded);
+        } else {
+            folded += line;
+        }
+    }
+    Some(folded)
+}
+
+/// Whether `line` has no lowercase letter, and at least two words of two or more letters.
+fn is_all_caps(line: &str) -> bool {
+    !line.chars().any(char::is_lowercase)
+        && line
+            .split(|c: char| !c.is_alphabetic())
+            .filter(|word| word.chars().nth(1).is_some())
+            .nth(1)
+            .is_some()
+}
+
+fn fold_line(line: &str, folded: &mut String) {
+    let mut rest = line;
+    while let Some(start) = rest.find(char::is_alphabetic) {
+        *folded += &rest[..start];
+        rest = &rest[start..];
+        let end = rest
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(rest.len());
+        let word = &rest[..end];
+        if word.chars().nth(1).is_none() {
+            *folded += word;
+        } else {
+            folded.extend(word.chars().map(lowercase));
+        }
+        rest = &rest[end..];
+    }
+    *folded += rest;
+}
+
+fn lowercase(c: char) -> char {
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(l), None) if l.len_utf8() == c.len_utf8() => l,
+        _ => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fold() {
+        assert_eq!(fold("Hi Mr. Kim. OK."), None);
+        assert_eq!(fold("NASA"), None);
+        assert_eq!(
+            fold("BREAKING NEWS\nMR. KIM AND I MET ÉLISE AT 5 P.M.").as_deref(),
+            Some("breaking news\nmr. kim and I met élise at 5 P.M.")
+        );
+        assert_eq!(
+            fold("Title\nTHE U.S. ARMY SAID NO.\nOk then.").as_deref(),
+            Some("Title\nthe U.S. army said no.\nOk then.")
+        );
+        // 소문자의 길이가 다른 글자는 그대로 둔다.
+        assert_eq!(fold("İSTANBUL IS BIG").as_deref(), Some("İstanbul is big"));
+    }
+}
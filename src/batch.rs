@@ -0,0 +1,236 @@
+//! Segmentation of many small documents into a single allocation. The documents are copied one
+//! after another into one string, and their segments are kept as offsets into it in one vector,
+//! which avoids an allocation per document for services which segment millions of short texts
+//! like tweets. A document is found by its index, and [`Batch::clear()`] keeps both allocations
+//! for the next lot of documents.
+//!
+//! ```rust
+//! use pragmatic_segmenter::batch::Batch;
+//! use pragmatic_segmenter::Segmenter;
+//!
+//! let segmenter = Segmenter::new()?;
+//! let mut batch = Batch::new();
+//! for tweet in ["Hi Mr. Kim. Bye.", "lol", "See you at 3 P.M. Ok?"] {
+//!     batch.push(&segmenter, tweet);
+//! }
+//! assert_eq!(batch.len(), 3);
+//! assert_eq!(batch.segments().count(), 5);
+//! let texts: Vec<_> = batch.get(2).unwrap().map(|segment| segment.text).collect();
+//! assert_eq!(texts, vec!["See you at 3 P.M. ", "Ok?"]);
+//!
+//! // The next documents reuse the same allocations, whatever their lifetime.
+//! batch.clear();
+//! let owned = String::from("Next one. Done.");
+//! batch.push(&segmenter, &owned);
+//! assert_eq!(batch.len(), 1);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::ops::Range;
+
+use crate::{Kind, Segment, Segmenter};
+
+/// Segments of many documents in a single allocation. See the [module documentation](self).
+#[derive(Debug, Clone, Default)]
+pub struct Batch {
+    /// Every document, one after another
+    text: String,
+    spans: Vec<Span>,
+    /// End of each document in `text`, and end of its segments in `spans`
+    ends: Vec<(usize, usize)>,
+}
+
+/// A [`Segment`] without the borrow of the document.
+#[derive(Debug, Clone)]
+struct Span {
+    /// Byte range of the segment in the document
+    range: Range<usize>,
+    kind: SpanKind,
+    /// Byte range of the speaker in the document
+    speaker: Option<Range<usize>>,
+    overlong: bool,
+}
+
+/// A [`Kind`] with the length of the marker or the prefix, which starts the segment.
+#[derive(Debug, Clone, Copy)]
+enum SpanKind {
+    Sentence,
+    ListItem(usize),
+    Turn(usize),
+    Dateline,
+    Clause,
+}
+
+impl Batch {
+    /// Create an empty batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty batch with room for `documents` documents of `bytes` bytes and `segments`
+    /// segments in total.
+    pub fn with_capacity(documents: usize, bytes: usize, segments: usize) -> Self {
+        Batch {
+            text: String::with_capacity(bytes),
+            spans: Vec::with_capacity(segments),
+            ends: Vec::with_capacity(documents),
+        }
+    }
+
+    /// Segment `document` with `segmenter` like [`Segmenter::segments()`], and append it. Returns
+    /// the index of the document.
+    pub fn push(&mut self, segmenter: &Segmenter, document: &str) -> usize {
+        // NOTE: 화자는 입력의 슬라이스이므로 주소의 차이가 입력 안에서의 위치다.
+        let offset = |slice: &str| slice.as_ptr() as usize - document.as_ptr() as usize;
+        self.spans
+            .extend(segmenter.segments(document).map(|segment| Span {
+                range: segment.start..segment.start + segment.text.len(),
+                kind: match segment.kind {
+                    Kind::Sentence => SpanKind::Sentence,
+                    Kind::ListItem { marker } => SpanKind::ListItem(marker.len()),
+                    Kind::Turn { prefix } => SpanKind::Turn(prefix.len()),
+                    Kind::Dateline => SpanKind::Dateline,
+                    Kind::Clause => SpanKind::Clause,
+                },
+                speaker: segment
+                    .speaker
+                    .map(|speaker| offset(speaker)..offset(speaker) + speaker.len()),
+                overlong: segment.overlong,
+            }));
+        self.text.push_str(document);
+        self.ends.push((self.text.len(), self.spans.len()));
+        self.ends.len() - 1
+    }
+
+    /// Text of the document at `index`, or `None` if there's no such document.
+    pub fn document(&self, index: usize) -> Option<&str> {
+        let (start, _) = self.start(index);
+        let &(end, _) = self.ends.get(index)?;
+        Some(&self.text[start..end])
+    }
+
+    /// Segments of the document at `index`, or `None` if there's no such document.
+    pub fn get(&self, index: usize) -> Option<impl ExactSizeIterator<Item = Segment<'_>>> {
+        let document = self.document(index)?;
+        let (_, start) = self.start(index);
+        let (_, end) = self.ends[index];
+        Some(self.spans[start..end].iter().map(move |span| {
+            let text = &document[span.range.clone()];
+            Segment {
+                text,
+                start: span.range.start,
+                kind: match span.kind {
+                    SpanKind::Sentence => Kind::Sentence,
+                    SpanKind::ListItem(len) => Kind::ListItem {
+                        marker: &text[..len],
+                    },
+                    SpanKind::Turn(len) => Kind::Turn {
+                        prefix: &text[..len],
+                    },
+                    SpanKind::Dateline => Kind::Dateline,
+                    SpanKind::Clause => Kind::Clause,
+                },
+                speaker: span.speaker.clone().map(|range| &document[range]),
+                overlong: span.overlong,
+            }
+        }))
+    }
+
+    /// Segments of every document, in order.
+    pub fn iter(&self) -> impl Iterator<Item = impl ExactSizeIterator<Item = Segment<'_>>> {
+        (0..self.len()).map(move |index| self.get(index).unwrap()) // Must exist
+    }
+
+    /// Segments of every document one after another.
+    pub fn segments(&self) -> impl Iterator<Item = Segment<'_>> {
+        self.iter().flatten()
+    }
+
+    /// Number of documents.
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Whether there's no document.
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Remove every document, keeping the allocations.
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.spans.clear();
+        self.ends.clear();
+    }
+
+    /// Start of the document at `index` in `text`, and start of its segments in `spans`.
+    fn start(&self, index: usize) -> (usize, usize) {
+        match index {
+            0 => (0, 0),
+            _ => self.ends.get(index - 1).copied().unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Overlong, SegmenterBuilder};
+
+    #[test]
+    fn test_batch() -> Result<(), Box<dyn std::error::Error>> {
+        let segmenter = Segmenter::new()?;
+        let mut batch = Batch::with_capacity(3, 16, 8);
+        assert!(batch.is_empty());
+        assert_eq!(batch.push(&segmenter, "A b. C d."), 0);
+        assert_eq!(batch.push(&segmenter, ""), 1);
+        assert_eq!(batch.push(&segmenter, "E f."), 2);
+
+        let texts: Vec<Vec<_>> = batch
+            .iter()
+            .map(|segments| segments.map(|segment| segment.text).collect())
+            .collect();
+        assert_eq!(texts, vec![vec!["A b. ", "C d."], vec![], vec!["E f."]]);
+        assert_eq!(batch.get(0).unwrap().nth(1).unwrap().start, 5);
+        assert_eq!(batch.document(2), Some("E f."));
+        assert!(batch.get(3).is_none() && batch.document(3).is_none());
+
+        let capacity = batch.text.capacity();
+        batch.clear();
+        assert!(batch.is_empty() && batch.segments().next().is_none());
+        assert_eq!(batch.text.capacity(), capacity);
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_same_segments() -> Result<(), Box<dyn std::error::Error>> {
+        let segmenter = SegmenterBuilder::new().transcript(true).build()?;
+        let documents = ["Hi. 1. One. 2. Two.", "SPEAKER 1: Hello. Bye.\nJohn: Hi."];
+        let mut batch = Batch::new();
+        for document in &documents {
+            batch.push(&segmenter, document);
+        }
+        for (index, document) in documents.iter().enumerate() {
+            let expected: Vec<_> = segmenter.segments(document).collect();
+            assert_eq!(batch.get(index).unwrap().collect::<Vec<_>>(), expected);
+        }
+        let segments: Vec<_> = batch.segments().collect();
+        assert!(matches!(segments[1].kind, Kind::ListItem { marker: "1." }));
+        assert_eq!(segments.last().unwrap().speaker, Some("SPEAKER 1"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_split_prefix() -> Result<(), Box<dyn std::error::Error>> {
+        let segmenter = SegmenterBuilder::new()
+            .transcript(true)
+            .max_length(8, Overlong::Split)
+            .build()?;
+        let document = "SPEAKER 1: Hello there my friend. Bye.";
+        let mut batch = Batch::new();
+        batch.push(&segmenter, document);
+        let expected: Vec<_> = segmenter.segments(document).collect();
+        assert_eq!(batch.segments().collect::<Vec<_>>(), expected);
+        Ok(())
+    }
+}
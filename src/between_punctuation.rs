@@ -0,0 +1,185 @@
+use std::ops::Range;
+
+/// Pairs of enclosing punctuations whose contents are never split, in the order of pySBD's
+/// `BetweenPunctuation.sub_punctuation_between_*()` functions. Single quotes are not here, since
+/// they can't be told apart from apostrophes without looking at the surrounding words.
+pub(crate) const DEFAULT_PAIRS: &[(&str, &str)] = &[
+    ("\"", "\""),
+    ("[", "]"),
+    ("(", ")"),
+    ("«", "»"),
+    ("--", "--"),
+    ("“", "”"),
+];
+
+/// Pairs of brackets for [`SegmenterBuilder::multiline_brackets()`](crate::SegmenterBuilder::multiline_brackets).
+pub(crate) const BRACKETS: &[(&str, &str)] = &[("(", ")"), ("[", "]"), ("{", "}")];
+
+/// Finds regions enclosed by pairs of punctuations like quotation marks and brackets.
+///
+/// NOTE: pySBD는 짝마다 `"(?=(?<tmp>[^\"\\]+|\\{2}|\\.)*)\k<tmp>"` 같은 backreference 정규표현식을
+/// 하나씩 돌리는데, 중첩된 괄호나 짝이 맞지 않는 따옴표를 처리하지 못하고, 닫히지 않은 여는
+/// 문자마다 문단 끝까지 다시 훑으므로 따옴표가 많은 긴 문단에서 느리다. 여기선 스택 하나로 모든
+/// 짝을 한번에 훑는다.
+#[derive(Debug, Clone)]
+pub(crate) struct BetweenPunctuation {
+    pairs: Vec<(String, String)>,
+}
+
+impl BetweenPunctuation {
+    pub fn new(pairs: impl IntoIterator<Item = (String, String)>) -> Self {
+        BetweenPunctuation {
+            pairs: pairs
+                .into_iter()
+                .filter(|(open, close)| !open.is_empty() && !close.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Byte ranges of the outermost enclosed regions including the punctuations, in order.
+    ///
+    /// A closing punctuation closes the innermost open region of its pair, and every region
+    /// opened inside it which is still open is discarded as unbalanced. Regions which are never
+    /// closed are discarded as well. A backslash escapes the next character.
+    pub fn enclosed_ranges(&self, text: &str) -> Vec<Range<usize>> {
+        // (index of the pair, start of the opening punctuation)
+        let mut stack: Vec<(usize, usize)> = Vec::new();
+        // Number of open regions of each pair, to skip searching the stack for a closing
+        // punctuation whose pair is not open at all.
+        let mut open_count = vec![0; self.pairs.len()];
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+
+        let mut idx = 0;
+        while let Some(c) = text[idx..].chars().next() {
+            let rest = &text[idx..];
+            if c == '\\' {
+                idx += 1;
+                idx += text[idx..].chars().next().map_or(0, char::len_utf8);
+                continue;
+            }
+
+            let closes =
+                |pair: usize| open_count[pair] > 0 && rest.starts_with(&self.pairs[pair].1);
+            if (0..self.pairs.len()).any(closes) {
+                let depth = stack.iter().rposition(|&(pair, _)| closes(pair)).unwrap(); // Must exist
+                for &(pair, _) in &stack[depth..] {
+                    open_count[pair] -= 1;
+                }
+                let (pair, start) = stack[depth];
+                stack.truncate(depth);
+                let end = idx + self.pairs[pair].1.len();
+                // 안쪽 영역은 바깥 영역보다 먼저 닫히므로, 바깥 영역이 닫힐때 지워준다.
+                while ranges.last().is_some_and(|last| last.start >= start) {
+                    ranges.pop();
+                }
+                if end - start > self.pairs[pair].0.len() + self.pairs[pair].1.len() {
+                    ranges.push(start..end);
+                }
+                idx = end;
+                continue;
+            }
+
+            if let Some(pair) = self
+                .pairs
+                .iter()
+                .position(|(open, _)| rest.starts_with(open))
+            {
+                stack.push((pair, idx));
+                open_count[pair] += 1;
+                idx += self.pairs[pair].0.len();
+                continue;
+            }
+
+            idx += c.len_utf8();
+        }
+        ranges
+    }
+
+    /// Same as [`enclosed_ranges()`](BetweenPunctuation::enclosed_ranges), but a region never
+    /// spans a blank line, so that a stray quotation mark can't swallow the following
+    /// paragraphs.
+    pub fn enclosed_ranges_by_paragraph(&self, text: &str) -> Vec<Range<usize>> {
+        let mut ranges = Vec::new();
+        let mut add_paragraph = |paragraph: Range<usize>| {
+            let offset = paragraph.start;
+            ranges.extend(
+                self.enclosed_ranges(&text[paragraph])
+                    .into_iter()
+                    .map(|range| offset + range.start..offset + range.end),
+            );
+        };
+
+        let mut paragraph_start = 0;
+        let mut line_start = 0;
+        for line in text.split_inclusive('\n') {
+            if line.trim().is_empty() {
+                add_paragraph(paragraph_start..line_start);
+                paragraph_start = line_start + line.len();
+            }
+            line_start += line.len();
+        }
+        add_paragraph(paragraph_start..text.len());
+        ranges
+    }
+
+    /// Replace every outermost enclosed region with the result of `f`.
+    pub fn replace_all(&self, text: &str, f: impl Fn(&str) -> String) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut last = 0;
+        for range in self.enclosed_ranges(text) {
+            result += &text[last..range.start];
+            result += &f(&text[range.clone()]);
+            last = range.end;
+        }
+        result += &text[last..];
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn between() -> BetweenPunctuation {
+        BetweenPunctuation::new(
+            DEFAULT_PAIRS
+                .iter()
+                .map(|&(open, close)| (open.to_string(), close.to_string())),
+        )
+    }
+
+    fn enclosed(text: &str) -> Vec<&str> {
+        between()
+            .enclosed_ranges(text)
+            .into_iter()
+            .map(|range| &text[range])
+            .collect()
+    }
+
+    #[test]
+    fn test_enclosed_ranges() {
+        assert_eq!(
+            enclosed(r#"He said "hi." and (left.)"#),
+            vec![r#""hi.""#, "(left.)"]
+        );
+        assert_eq!(
+            enclosed("A (nested (deep.) one.) B"),
+            vec!["(nested (deep.) one.)"]
+        );
+        assert_eq!(enclosed("“Say (hi.”) ok"), vec!["“Say (hi.”"]);
+        assert_eq!(enclosed("Open ( never (closed.) ok"), vec!["(closed.)"]);
+        assert_eq!(enclosed(r#"An \"escaped" quote"#), Vec::<&str>::new());
+        assert_eq!(enclosed("Empty () and -- dash. -- ok"), vec!["-- dash. --"]);
+    }
+
+    #[test]
+    fn test_enclosed_ranges_by_paragraph() {
+        let text = "\"Stop.\nNow.\" \"Open.\n\nNew.\" (ok)";
+        let ranges: Vec<_> = between()
+            .enclosed_ranges_by_paragraph(text)
+            .into_iter()
+            .map(|range| &text[range])
+            .collect();
+        assert_eq!(ranges, vec!["\"Stop.\nNow.\"", "(ok)"]);
+    }
+}
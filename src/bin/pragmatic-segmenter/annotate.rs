@@ -0,0 +1,72 @@
+use std::io::{self, Write};
+
+use pragmatic_segmenter::Segmenter;
+
+/// Mark of a boundary between two segments.
+const MARKER: &str = "⏐";
+const COLORS: &[&str] = &["\x1b[36m", "\x1b[33m"];
+const MARKER_COLOR: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+/// Write `input` as it is, with a marker at the start of every segment but the first, and every
+/// segment in an alternating color if `colors` is true. Text which is not in any segment is
+/// written without a color.
+pub fn write(
+    out: &mut impl Write,
+    segmenter: &Segmenter,
+    input: &str,
+    colors: bool,
+) -> io::Result<()> {
+    let mut end = 0;
+    for (idx, segment) in segmenter.segments(input).enumerate() {
+        out.write_all(&input.as_bytes()[end..segment.start])?;
+        if idx > 0 {
+            if colors {
+                write!(out, "{}{}{}", MARKER_COLOR, MARKER, RESET)?;
+            } else {
+                out.write_all(MARKER.as_bytes())?;
+            }
+        }
+        if colors {
+            // 줄바꿈 뒤까지 색이 이어지지 않도록 줄마다 색을 다시 켠다.
+            let color = COLORS[idx % COLORS.len()];
+            for line in segment.text.split_inclusive('\n') {
+                let (text, newline) = match line.strip_suffix('\n') {
+                    Some(text) => (text, "\n"),
+                    None => (line, ""),
+                };
+                write!(out, "{}{}{}{}", color, text, RESET, newline)?;
+            }
+        } else {
+            out.write_all(segment.text.as_bytes())?;
+        }
+        end = segment.start + segment.text.len();
+    }
+    out.write_all(&input.as_bytes()[end..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn test_write() -> Result<(), Box<dyn Error>> {
+        let segmenter = Segmenter::new()?;
+        let annotate = |input: &str, colors: bool| -> io::Result<String> {
+            let mut out = Vec::new();
+            write(&mut out, &segmenter, input, colors)?;
+            Ok(String::from_utf8(out).unwrap())
+        };
+        assert_eq!(
+            annotate("  Hi Mr. Kim.  Bye.\n\nOk.\n", false)?,
+            "  Hi Mr. Kim.  ⏐Bye.\n\n⏐Ok.\n"
+        );
+        assert_eq!(
+            annotate("Hi. Bye.", true)?,
+            "\x1b[36mHi. \x1b[0m\x1b[2m⏐\x1b[0m\x1b[33mBye.\x1b[0m"
+        );
+        assert_eq!(annotate("", true)?, "");
+        Ok(())
+    }
+}
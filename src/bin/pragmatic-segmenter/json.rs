@@ -0,0 +1,38 @@
+use std::io::{self, Write};
+
+pub use pragmatic_segmenter::jsonl::write_string;
+use pragmatic_segmenter::training::Decision;
+
+/// Write a line of the training data of a boundary classifier:
+/// `{"file":...,"offset":...,"left":...,"punct":...,"right":...,"boundary":...}`, where `offset`
+/// is the byte offset of the punctuations.
+pub fn write_decision(out: &mut impl Write, file: &str, decision: &Decision) -> io::Result<()> {
+    out.write_all(br#"{"file":"#)?;
+    write_string(out, file)?;
+    let offset = decision.start + decision.punct.start;
+    write!(out, r#","offset":{},"left":"#, offset)?;
+    write_string(out, decision.left())?;
+    out.write_all(br#","punct":"#)?;
+    write_string(out, decision.punct())?;
+    out.write_all(br#","right":"#)?;
+    write_string(out, decision.right())?;
+    writeln!(out, r#","boundary":{}}}"#, decision.boundary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_decision() -> Result<(), Box<dyn std::error::Error>> {
+        let segmenter = pragmatic_segmenter::Segmenter::new()?;
+        let decisions = pragmatic_segmenter::training::decisions(&segmenter, "Hi. \"Bye\"", 3);
+        let mut out = Vec::new();
+        write_decision(&mut out, "a.txt", &decisions[0])?;
+        assert_eq!(
+            String::from_utf8(out)?,
+            "{\"file\":\"a.txt\",\"offset\":2,\"left\":\"Hi\",\"punct\":\".\",\"right\":\" \\\"B\",\"boundary\":true}\n"
+        );
+        Ok(())
+    }
+}
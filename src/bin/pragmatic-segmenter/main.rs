@@ -0,0 +1,554 @@
+//! Command line interface of pragmatic-segmenter. Install it with:
+//!
+//! ```bash
+//! cargo install pragmatic-segmenter --features cli
+//! ```
+//!
+//! Enable the `gzip`, `xz` and `zstd` features as well to read compressed inputs.
+
+mod annotate;
+mod json;
+mod shard;
+mod verify;
+
+use std::error::Error;
+use std::fs;
+use std::io::{self, BufWriter, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+
+use pragmatic_segmenter::compression::{self, Compression};
+use pragmatic_segmenter::jsonl::JsonlWriter;
+use pragmatic_segmenter::{minimize, training, Segmenter};
+use rayon::prelude::*;
+
+/// Errors are sent across the threads of rayon.
+type Result<T> = std::result::Result<T, Box<dyn Error + Send + Sync>>;
+
+const USAGE: &str = "\
+Usage:
+    pragmatic-segmenter [--normalized] [FILE]
+    pragmatic-segmenter --annotate [FILE]
+    pragmatic-segmenter --training-data [FILE | --dir DIR [--glob PATTERN]... [--jsonl PATH]]
+    pragmatic-segmenter [--normalized] --dir DIR [--glob PATTERN]... [--output-dir OUT | --jsonl PATH]
+    pragmatic-segmenter [--normalized] [FILE | --dir DIR [--glob PATTERN]...] --shard-dir OUT
+                        [--max-sentences N] [--max-bytes SIZE]
+    pragmatic-segmenter --verify [FILE | --dir DIR [--glob PATTERN]...]
+    pragmatic-segmenter --minimize [FILE]
+
+Split FILE (or the standard input) into sentences and print one sentence per line.
+
+With --dir, every file under DIR which matches one of the glob patterns (default: **/*.txt) is
+segmented in parallel. The results are written to OUT, mirroring the directory structure of DIR,
+or written to PATH as JSON Lines like {\"text\":...,\"start\":...,\"end\":...,\"file\":...}, where
+start and end are the byte offsets of the sentence in the file. JSON Lines are written to the
+standard output when neither is given.
+
+Compressed inputs are decompressed if the support of their format is compiled in. With
+--output-dir, the extension of the compression format is removed from the output file names.
+
+With --shard-dir, the sentences are written one per line into OUT/shard-00000.txt,
+OUT/shard-00001.txt and so on, in the order of the input files. A new shard is started whenever
+the current one would exceed --max-sentences or --max-bytes, so that each shard can be fed to a
+training job as it is. OUT/manifest.jsonl lists every shard with its numbers of sentences and
+bytes. SIZE is a number of bytes, optionally followed by K, M or G.
+
+With --normalized, each sentence is followed by a tab and its normalized form, which is
+lowercased and has no punctuations and repeated whitespaces, for near-duplicate detection. In JSON
+Lines, the normalized form is written to the \"normalized\" field.
+
+With --annotate, FILE is printed as it is, with a marker at every sentence boundary. Sentences
+are colored alternately when the standard output is a terminal.
+
+With --training-data, every run of terminal punctuations is written as JSON Lines instead, with
+up to 40 characters of text on each side and whether a sentence ends there, to train boundary
+classifiers on the decisions of the rules.
+
+With --verify, nothing is written except the places where text of the input is missing from the
+sentences, and the exit status is 1 if there is any. Whitespaces between sentences are ignored.
+
+With --minimize, the input must make the segmentation panic, lose some text or overlap. It is
+shrunk to a small input which still does, and the result is printed as it is, for a bug report.
+
+Options:
+    -l, --language CODE     Segment with the rules of the language (default: en)
+    -j, --jobs N            Number of threads for --dir (default: number of CPUs)
+    --dir DIR               Segment every matching file under DIR
+    --glob PATTERN          Glob pattern relative to DIR, can be given multiple times
+    --output-dir OUT        Write one output file per input file under OUT
+    --jsonl PATH            Write every sentence to PATH as JSON Lines, '-' for the standard output
+    --shard-dir OUT         Write the sentences into shards under OUT, with a manifest
+    --max-sentences N       Maximum number of sentences of a shard
+    --max-bytes SIZE        Maximum size of a shard, like 512, 64K, 100M or 2G
+    --normalized            Write the normalized form of each sentence as well
+    --verify                Check that the sentences reassemble to the input
+    --minimize              Shrink an input which breaks the segmentation to a reproducer
+    --annotate              Print the input with the sentence boundaries marked
+    --training-data         Write every punctuation decision as JSON Lines
+    -h, --help              Print this message
+";
+
+/// Number of characters on each side of the punctuations in `--training-data`.
+const TRAINING_WINDOW: usize = 40;
+
+#[derive(Debug, Default)]
+struct Args {
+    file: Option<PathBuf>,
+    dir: Option<PathBuf>,
+    globs: Vec<String>,
+    output_dir: Option<PathBuf>,
+    jsonl: Option<PathBuf>,
+    shard_dir: Option<PathBuf>,
+    limits: shard::Limits,
+    verify: bool,
+    minimize: bool,
+    annotate: bool,
+    training_data: bool,
+    normalized: bool,
+    language: Option<String>,
+    jobs: Option<usize>,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> std::result::Result<Self, String> {
+        let mut parsed = Args::default();
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .ok_or_else(|| format!("missing value for {}", name))
+            };
+            match arg.as_str() {
+                "-h" | "--help" => {
+                    print!("{}", USAGE);
+                    process::exit(0);
+                }
+                "--dir" => parsed.dir = Some(value(&arg)?.into()),
+                "--glob" => parsed.globs.push(value(&arg)?),
+                "--output-dir" => parsed.output_dir = Some(value(&arg)?.into()),
+                "--jsonl" => parsed.jsonl = Some(value(&arg)?.into()),
+                "--shard-dir" => parsed.shard_dir = Some(value(&arg)?.into()),
+                "--max-sentences" => {
+                    let max = value(&arg)?;
+                    match max.parse() {
+                        Ok(max) if max > 0 => parsed.limits.max_sentences = Some(max),
+                        _ => return Err(format!("invalid number of sentences: {}", max)),
+                    }
+                }
+                "--max-bytes" => {
+                    let max = value(&arg)?;
+                    match shard::parse_size(&max) {
+                        Some(max) if max > 0 => parsed.limits.max_bytes = Some(max),
+                        _ => return Err(format!("invalid size: {}", max)),
+                    }
+                }
+                "--verify" => parsed.verify = true,
+                "--minimize" => parsed.minimize = true,
+                "--annotate" => parsed.annotate = true,
+                "--training-data" => parsed.training_data = true,
+                "--normalized" => parsed.normalized = true,
+                "-l" | "--language" => parsed.language = Some(value(&arg)?),
+                "-j" | "--jobs" => {
+                    let jobs = value(&arg)?;
+                    match jobs.parse() {
+                        Ok(jobs) if jobs > 0 => parsed.jobs = Some(jobs),
+                        _ => return Err(format!("invalid number of jobs: {}", jobs)),
+                    }
+                }
+                _ if arg.starts_with('-') && arg != "-" => {
+                    return Err(format!("unknown option: {}", arg))
+                }
+                _ if parsed.file.is_none() => parsed.file = Some(arg.into()),
+                _ => return Err(format!("unexpected argument: {}", arg)),
+            }
+        }
+
+        if parsed.minimize
+            && (parsed.verify
+                || parsed.annotate
+                || parsed.training_data
+                || parsed.normalized
+                || parsed.dir.is_some()
+                || parsed.shard_dir.is_some())
+        {
+            return Err(
+                "--minimize cannot be used with --verify, --annotate, --training-data, \
+--normalized, --dir or --shard-dir"
+                    .into(),
+            );
+        }
+        if parsed.verify && (parsed.output_dir.is_some() || parsed.jsonl.is_some()) {
+            return Err("--verify cannot be used with --output-dir or --jsonl".into());
+        }
+        if parsed.annotate && (parsed.verify || parsed.dir.is_some()) {
+            return Err("--annotate cannot be used with --verify or --dir".into());
+        }
+        if parsed.training_data && (parsed.verify || parsed.annotate || parsed.output_dir.is_some())
+        {
+            return Err(
+                "--training-data cannot be used with --verify, --annotate or --output-dir".into(),
+            );
+        }
+        if parsed.normalized && (parsed.verify || parsed.annotate || parsed.training_data) {
+            return Err(
+                "--normalized cannot be used with --verify, --annotate or --training-data".into(),
+            );
+        }
+        let limited = parsed.limits.max_sentences.is_some() || parsed.limits.max_bytes.is_some();
+        if parsed.shard_dir.is_some() {
+            if parsed.verify
+                || parsed.annotate
+                || parsed.training_data
+                || parsed.output_dir.is_some()
+                || parsed.jsonl.is_some()
+            {
+                return Err("--shard-dir cannot be used with --verify, --annotate, \
+--training-data, --output-dir or --jsonl"
+                    .into());
+            }
+            if !limited {
+                return Err("--shard-dir requires --max-sentences or --max-bytes".into());
+            }
+        } else if limited {
+            return Err("--max-sentences and --max-bytes require --shard-dir".into());
+        }
+        if parsed.dir.is_some() {
+            if parsed.file.is_some() {
+                return Err("FILE cannot be used with --dir".into());
+            }
+            if parsed.output_dir.is_some() && parsed.jsonl.is_some() {
+                return Err("--output-dir and --jsonl cannot be used together".into());
+            }
+            if parsed.globs.is_empty() {
+                parsed.globs.push("**/*.txt".into());
+            }
+        } else if !parsed.globs.is_empty() || parsed.output_dir.is_some() || parsed.jsonl.is_some()
+        {
+            return Err("--glob, --output-dir and --jsonl require --dir".into());
+        }
+        Ok(parsed)
+    }
+}
+
+fn main() {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(msg) => {
+            eprint!("error: {}\n\n{}", msg, USAGE);
+            process::exit(2);
+        }
+    };
+    // 전역 스레드 풀 대신, 주어진 수의 스레드를 가진 풀 안에서 실행한다.
+    let result = match args.jobs {
+        Some(jobs) => rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs)
+            .build()
+            .map_err(Into::into)
+            .and_then(|pool| pool.install(|| run(&args))),
+        None => run(&args),
+    };
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+fn run(args: &Args) -> Result<()> {
+    let mut builder = Segmenter::builder();
+    if let Some(code) = &args.language {
+        builder = builder.language(code).map_err(|err| {
+            format!(
+                "{} (available: {})",
+                err,
+                pragmatic_segmenter::languages().join(", ")
+            )
+        })?;
+    }
+    let segmenter = builder.build().map_err(|err| err.to_string())?;
+
+    let dir = match &args.dir {
+        Some(dir) => dir,
+        None => {
+            let input = match &args.file {
+                Some(path) if path != Path::new("-") => read_file(path)?,
+                _ => {
+                    let mut input = String::new();
+                    compression::decompress(io::BufReader::new(io::stdin()))?
+                        .read_to_string(&mut input)?;
+                    input
+                }
+            };
+            if args.minimize {
+                return minimize(&segmenter, &input);
+            }
+            if args.verify {
+                let name = match &args.file {
+                    Some(path) => path.to_string_lossy(),
+                    None => "-".into(),
+                };
+                let report = verify::report(&segmenter, &name, &input);
+                return finish_verification(&[report]);
+            }
+            if let Some(shard_dir) = &args.shard_dir {
+                let mut shards = shard::Shards::create(shard_dir, args.limits)?;
+                for line in lines(&segmenter, &input, args.normalized) {
+                    shards.write_line(&line)?;
+                }
+                shards.finish()?;
+                return Ok(());
+            }
+            let stdout = io::stdout();
+            let colors = stdout.is_terminal();
+            let mut out = BufWriter::new(stdout.lock());
+            if args.annotate {
+                annotate::write(&mut out, &segmenter, &input, colors)?;
+            } else if args.training_data {
+                let name = match &args.file {
+                    Some(path) => path.to_string_lossy(),
+                    None => "-".into(),
+                };
+                for decision in training::decisions(&segmenter, &input, TRAINING_WINDOW) {
+                    json::write_decision(&mut out, &name, &decision)?;
+                }
+            } else {
+                write_lines(&mut out, &segmenter, &input, args.normalized)?;
+            }
+            out.flush()?;
+            return Ok(());
+        }
+    };
+
+    let files = find_files(dir, &args.globs)?;
+
+    if args.verify {
+        let reports: Vec<String> = files
+            .par_iter()
+            .map(|path| -> Result<_> {
+                let input = read_file(path)?;
+                let name = path.strip_prefix(dir)?.to_string_lossy();
+                Ok(verify::report(&segmenter, &name, &input))
+            })
+            .collect::<Result<_>>()?;
+        return finish_verification(&reports);
+    }
+
+    if let Some(output_dir) = &args.output_dir {
+        return files.par_iter().try_for_each(|path| {
+            let input = read_file(path)?;
+            let mut output_path = output_dir.join(path.strip_prefix(dir)?);
+            if is_compressed(&output_path) {
+                output_path.set_extension("");
+            }
+            if let Some(parent) = output_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let mut out = BufWriter::new(fs::File::create(&output_path)?);
+            write_lines(&mut out, &segmenter, &input, args.normalized)?;
+            out.flush()?;
+            Ok(())
+        });
+    }
+
+    if let Some(shard_dir) = &args.shard_dir {
+        // NOTE: 조각의 경계가 앞 파일들의 문장 수에 달려 있으므로, 파일 순서대로 쓴다.
+        let mut shards = shard::Shards::create(shard_dir, args.limits)?;
+        for_each_ordered(
+            &files,
+            |path| {
+                let input = read_file(path)?;
+                Ok(lines(&segmenter, &input, args.normalized).collect::<Vec<_>>())
+            },
+            |lines| {
+                for line in lines {
+                    shards.write_line(&line)?;
+                }
+                Ok(())
+            },
+        )?;
+        shards.finish()?;
+        return Ok(());
+    }
+
+    let mut out: Box<dyn Write> = match &args.jsonl {
+        Some(path) if path != Path::new("-") => Box::new(fs::File::create(path)?),
+        _ => Box::new(io::stdout()),
+    };
+    let mut out = BufWriter::new(&mut out);
+    // 파일별로 병렬로 처리한 뒤, 입력 순서대로 출력한다.
+    let process = |path: &Path| -> Result<_> {
+        let input = read_file(path)?;
+        let name = path.strip_prefix(dir)?.to_string_lossy();
+        if args.training_data {
+            let mut record = Vec::new();
+            for decision in training::decisions(&segmenter, &input, TRAINING_WINDOW) {
+                json::write_decision(&mut record, &name, &decision)?;
+            }
+            return Ok(record);
+        }
+        let mut writer = JsonlWriter::new(Vec::new());
+        for segment in segmenter.segments(&input) {
+            let normalized = args.normalized.then(|| segment.normalized());
+            let mut fields = vec![("file", name.as_ref())];
+            fields.extend(
+                normalized
+                    .as_deref()
+                    .map(|normalized| ("normalized", normalized)),
+            );
+            writer.write_with_fields(segment.text, segment.start, &fields)?;
+        }
+        Ok(writer.finish()?)
+    };
+    for_each_ordered(&files, process, |record| Ok(out.write_all(&record)?))?;
+    out.flush()?;
+    Ok(())
+}
+
+/// Process `files` in parallel, and pass the results to `write` in the order of `files`. Only a
+/// window of as many files as there are threads is in memory at once.
+fn for_each_ordered<T: Send>(
+    files: &[PathBuf],
+    process: impl Fn(&Path) -> Result<T> + Sync,
+    mut write: impl FnMut(T) -> Result<()>,
+) -> Result<()> {
+    for window in files.chunks(rayon::current_num_threads()) {
+        let results: Vec<_> = window.par_iter().map(|path| process(path)).collect();
+        for result in results {
+            write(result?)?;
+        }
+    }
+    Ok(())
+}
+
+/// Print the reports of `--verify`, and exit with 1 if any document lost text.
+fn finish_verification(reports: &[String]) -> Result<()> {
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    for report in reports {
+        out.write_all(report.as_bytes())?;
+    }
+    out.flush()?;
+
+    let failed = reports.iter().filter(|report| !report.is_empty()).count();
+    if failed > 0 {
+        eprintln!("{} of {} documents lost text", failed, reports.len());
+        process::exit(1);
+    }
+    Ok(())
+}
+
+fn minimize(segmenter: &Segmenter, input: &str) -> Result<()> {
+    // NOTE: 줄이는 동안 패닉이 수천 번 날 수 있으므로 메시지를 찍지 않는다.
+    std::panic::set_hook(Box::new(|_| {}));
+    let minimized = minimize::minimize(segmenter, input);
+    let _ = std::panic::take_hook();
+    let minimized = minimized.ok_or("the input neither panics, loses text nor overlaps")?;
+    let mut out = io::stdout().lock();
+    out.write_all(minimized.text.as_bytes())?;
+    out.flush()?;
+    eprintln!(
+        "{} ({} to {} bytes, {} segmentations)",
+        minimized.violation,
+        input.len(),
+        minimized.text.len(),
+        minimized.checks
+    );
+    Ok(())
+}
+
+/// Every file under `dir` which matches one of `patterns`, sorted and deduplicated.
+fn find_files(dir: &Path, patterns: &[String]) -> Result<Vec<PathBuf>> {
+    // NOTE: 디렉토리 이름에 "[", "*", "?"가 있어도 글자 그대로 찾도록 이스케이프한다.
+    let dir = PathBuf::from(glob::Pattern::escape(&dir.to_string_lossy()));
+    let mut files = Vec::new();
+    for pattern in patterns {
+        let pattern = dir.join(pattern);
+        for entry in glob::glob(&pattern.to_string_lossy())? {
+            let path = entry?;
+            if path.is_file() {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    let read = || -> io::Result<String> {
+        let mut input = String::new();
+        compression::open(path)?.read_to_string(&mut input)?;
+        Ok(input)
+    };
+    read().map_err(|err| format!("{}: {}", path.display(), err).into())
+}
+
+/// Whether `path` has the extension of a compression format, like "a.txt.gz".
+fn is_compressed(path: &Path) -> bool {
+    let compressions = [Compression::Gzip, Compression::Xz, Compression::Zstd];
+    path.extension().is_some_and(|extension| {
+        compressions
+            .iter()
+            .any(|compression| compression.extension() == extension.to_str())
+    })
+}
+
+/// Write each sentence in a line, as [`lines()`] makes them.
+fn write_lines(
+    out: &mut impl Write,
+    segmenter: &Segmenter,
+    input: &str,
+    normalized: bool,
+) -> io::Result<()> {
+    for line in lines(segmenter, input, normalized) {
+        writeln!(out, "{}", line)?;
+    }
+    Ok(())
+}
+
+/// Each sentence as a line, without the line break. Line breaks in the middle of a sentence are
+/// replaced with spaces, and empty sentences are skipped. With `normalized`, the sentence is
+/// followed by a tab and its normalized form.
+fn lines<'a>(
+    segmenter: &'a Segmenter,
+    input: &'a str,
+    normalized: bool,
+) -> impl Iterator<Item = String> + 'a {
+    segmenter.segments(input).filter_map(move |segment| {
+        let sentence = segment.text.trim();
+        if sentence.is_empty() {
+            return None;
+        }
+        let sentence = sentence.replace(&['\r', '\n'][..], " ");
+        Some(if normalized {
+            // NOTE: 문장 안의 탭은 구분자와 헷갈리지 않도록 공백으로 바꾼다.
+            format!("{}\t{}", sentence.replace('\t', " "), segment.normalized())
+        } else {
+            sentence
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_files() -> Result<()> {
+        let root =
+            std::env::temp_dir().join(format!("pragmatic-segmenter-find-{}", std::process::id()));
+        let dir = root.join("corpus [2024]");
+        fs::create_dir_all(dir.join("news"))?;
+        fs::create_dir_all(root.join("corpus 2"))?;
+        for path in ["a.txt", "b.md", "news/c.txt"] {
+            fs::write(dir.join(path), "Hi.")?;
+        }
+        fs::write(root.join("corpus 2/d.txt"), "Hi.")?;
+
+        let patterns = ["**/*.txt".to_string()];
+        let files = find_files(&dir, &patterns)?;
+        assert_eq!(files, vec![dir.join("a.txt"), dir.join("news/c.txt")]);
+
+        fs::remove_dir_all(&root)?;
+        Ok(())
+    }
+}
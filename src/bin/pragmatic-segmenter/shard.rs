@@ -0,0 +1,168 @@
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+
+use crate::json;
+
+/// Name of the manifest written by [`Shards::finish()`].
+pub const MANIFEST: &str = "manifest.jsonl";
+
+/// Limits of a single shard. A line longer than `max_bytes` gets a shard of its own.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Limits {
+    pub max_sentences: Option<usize>,
+    pub max_bytes: Option<u64>,
+}
+
+/// A shard written so far.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shard {
+    pub name: String,
+    pub sentences: usize,
+    pub bytes: u64,
+}
+
+/// Writer of lines into files named `shard-00000.txt`, `shard-00001.txt`, ... under a
+/// directory, starting a new file whenever the current one would exceed the limits.
+pub struct Shards {
+    dir: PathBuf,
+    limits: Limits,
+    current: Option<BufWriter<File>>,
+    shards: Vec<Shard>,
+}
+
+impl Shards {
+    pub fn create(dir: impl Into<PathBuf>, limits: Limits) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Shards {
+            dir,
+            limits,
+            current: None,
+            shards: Vec::new(),
+        })
+    }
+
+    /// Write `line` followed by a line break.
+    pub fn write_line(&mut self, line: &str) -> io::Result<()> {
+        let bytes = line.len() as u64 + 1;
+        let full = self.shards.last().is_none_or(|shard| {
+            self.limits
+                .max_sentences
+                .is_some_and(|max| shard.sentences >= max)
+                || self
+                    .limits
+                    .max_bytes
+                    .is_some_and(|max| shard.bytes + bytes > max)
+        });
+        if full {
+            self.next_shard()?;
+        }
+
+        let out = self.current.as_mut().unwrap(); // Must exist
+        out.write_all(line.as_bytes())?;
+        out.write_all(b"\n")?;
+        let shard = self.shards.last_mut().unwrap(); // Must exist
+        shard.sentences += 1;
+        shard.bytes += bytes;
+        Ok(())
+    }
+
+    fn next_shard(&mut self) -> io::Result<()> {
+        if let Some(mut out) = self.current.take() {
+            out.flush()?;
+        }
+        let name = format!("shard-{:05}.txt", self.shards.len());
+        self.current = Some(BufWriter::new(File::create(self.dir.join(&name))?));
+        self.shards.push(Shard {
+            name,
+            sentences: 0,
+            bytes: 0,
+        });
+        Ok(())
+    }
+
+    /// Flush the last shard, and write the manifest of every shard as JSON Lines:
+    /// `{"file":...,"sentences":...,"bytes":...}`.
+    pub fn finish(mut self) -> io::Result<Vec<Shard>> {
+        if let Some(mut out) = self.current.take() {
+            out.flush()?;
+        }
+        let mut manifest = BufWriter::new(File::create(self.dir.join(MANIFEST))?);
+        for shard in &self.shards {
+            manifest.write_all(br#"{"file":"#)?;
+            json::write_string(&mut manifest, &shard.name)?;
+            writeln!(
+                manifest,
+                r#","sentences":{},"bytes":{}}}"#,
+                shard.sentences, shard.bytes
+            )?;
+        }
+        manifest.flush()?;
+        Ok(self.shards)
+    }
+}
+
+/// Parse a number of bytes with an optional binary suffix: "512", "64K", "100M" or "2G".
+pub fn parse_size(size: &str) -> Option<u64> {
+    let (digits, unit) = match size.char_indices().last()? {
+        (idx, 'K' | 'k') => (&size[..idx], 1 << 10),
+        (idx, 'M' | 'm') => (&size[..idx], 1 << 20),
+        (idx, 'G' | 'g') => (&size[..idx], 1 << 30),
+        _ => (size, 1),
+    };
+    digits.parse::<u64>().ok()?.checked_mul(unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shards() -> io::Result<()> {
+        let dir = std::env::temp_dir().join(format!("pragmatic-segmenter-{}", std::process::id()));
+        let write = |limits| -> io::Result<Vec<(usize, u64)>> {
+            let mut shards = Shards::create(&dir, limits)?;
+            for line in ["Hi.", "Bye now.", "A long sentence.", "Ok."] {
+                shards.write_line(line)?;
+            }
+            let shards = shards.finish()?;
+            Ok(shards
+                .into_iter()
+                .map(|shard| (shard.sentences, shard.bytes))
+                .collect())
+        };
+
+        let limits = Limits {
+            max_sentences: Some(3),
+            max_bytes: None,
+        };
+        assert_eq!(write(limits)?, vec![(3, 30), (1, 4)]);
+        assert_eq!(fs::read_to_string(dir.join("shard-00001.txt"))?, "Ok.\n");
+        assert_eq!(
+            fs::read_to_string(dir.join(MANIFEST))?,
+            "{\"file\":\"shard-00000.txt\",\"sentences\":3,\"bytes\":30}\n\
+             {\"file\":\"shard-00001.txt\",\"sentences\":1,\"bytes\":4}\n"
+        );
+
+        // 한도보다 긴 줄은 혼자서 한 조각이 된다.
+        let limits = Limits {
+            max_sentences: Some(3),
+            max_bytes: Some(13),
+        };
+        assert_eq!(write(limits)?, vec![(2, 13), (1, 17), (1, 4)]);
+
+        fs::remove_dir_all(&dir)
+    }
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("512"), Some(512));
+        assert_eq!(parse_size("64K"), Some(64 * 1024));
+        assert_eq!(parse_size("100m"), Some(100 * 1024 * 1024));
+        assert_eq!(parse_size("2G"), Some(2 << 30));
+        assert_eq!(parse_size("G"), None);
+        assert_eq!(parse_size("1.5M"), None);
+        assert_eq!(parse_size(""), None);
+    }
+}
@@ -0,0 +1,52 @@
+use std::fmt::Write;
+use std::ops::Range;
+
+use pragmatic_segmenter::Segmenter;
+
+/// Byte ranges of `input` which are not covered by any segment, except whitespaces.
+pub fn missing_ranges(segmenter: &Segmenter, input: &str) -> Vec<Range<usize>> {
+    match segmenter.checked_segments(input) {
+        Ok(_) => Vec::new(),
+        Err(error) => error.lost,
+    }
+}
+
+/// Lines of the `--verify` report of a document, or an empty string if nothing was lost.
+pub fn report(segmenter: &Segmenter, name: &str, input: &str) -> String {
+    let mut report = String::new();
+    for Range { start, end } in missing_ranges(segmenter, input) {
+        // Must not fail, since writing to a String never fails
+        writeln!(
+            report,
+            "{}:{}: missing {} bytes: {:?}",
+            name,
+            start,
+            end - start,
+            &input[start..end]
+        )
+        .unwrap();
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn test_missing_ranges() -> Result<(), Box<dyn Error>> {
+        let segmenter = Segmenter::new()?;
+        assert!(missing_ranges(&segmenter, "  Hi Mr. Kim. Let's meet. ").is_empty());
+        assert!(missing_ranges(&segmenter, "").is_empty());
+
+        // "∯" is used as a placeholder of periods, so the sentence which has it is lost
+        let input = "Hi. It costs 5∯ now. Ok.";
+        assert_eq!(missing_ranges(&segmenter, input), vec![4..23]);
+        assert_eq!(
+            report(&segmenter, "a.txt", input),
+            "a.txt:4: missing 19 bytes: \"It costs 5∯ now. \"\n"
+        );
+        Ok(())
+    }
+}
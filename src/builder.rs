@@ -0,0 +1,1278 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::sync::Arc;
+
+use crate::abbreviation_pack::{self, AbbreviationConflict, AbbreviationPack};
+use crate::language::{self, UnknownLanguage};
+use crate::merge_rule::MergeRule;
+#[cfg(feature = "model")]
+use crate::model::NgramModel;
+use crate::preset::Preset;
+use crate::rerank::{BoundaryScorer, MergeScorer};
+use crate::rule::Rule;
+use crate::Segmenter;
+
+/// Builder for [`Segmenter`]. Every option defaults to the behavior of pySBD, so
+/// `SegmenterBuilder::new().build()` is equivalent to [`Segmenter::new()`].
+///
+/// ```rust
+/// use pragmatic_segmenter::Segmenter;
+///
+/// let segmenter = Segmenter::builder()
+///     .lowercase_sentence_starts(true)
+///     .build()?;
+/// let result: Vec<_> = segmenter.segment("wow! that was fun").collect();
+/// assert_eq!(result, vec!["wow! ", "that was fun"]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SegmenterBuilder {
+    pub(crate) language: Option<String>,
+    pub(crate) lowercase_sentence_starts: bool,
+    pub(crate) uppercase_letters: UppercaseLetters,
+    pub(crate) name_initials: bool,
+    pub(crate) abbreviation_packs: Vec<AbbreviationPack>,
+    pub(crate) abbreviation_priority: Vec<String>,
+    pub(crate) numerals_in_parens_in_any_context: bool,
+    pub(crate) arabic_numerals_in_parens: bool,
+    pub(crate) protected_patterns: Vec<String>,
+    pub(crate) enclosing_pairs: Vec<(String, String)>,
+    pub(crate) merge_unbalanced_quotes: bool,
+    pub(crate) multiline_brackets: bool,
+    pub(crate) collapse_whitespace: bool,
+    pub(crate) fold_all_caps: bool,
+    pub(crate) repair_mojibake: bool,
+    pub(crate) literal_newlines: bool,
+    pub(crate) salutations: bool,
+    pub(crate) speech_recognition: Option<SpeechRecognition>,
+    pub(crate) transcript: bool,
+    pub(crate) datelines: bool,
+    pub(crate) colon_lists: bool,
+    pub(crate) join_wrapped_lines: bool,
+    pub(crate) post_process: PostProcess,
+    pub(crate) max_length: Option<(usize, Overlong)>,
+    pub(crate) boundary_scorer: Option<Arc<dyn BoundaryScorer>>,
+    pub(crate) merge_scorer: Option<MergeScorer>,
+    pub(crate) acronym_boundary: AcronymBoundary,
+    pub(crate) numeral_conflict: NumeralConflict,
+    pub(crate) exclamation_words: Vec<String>,
+    pub(crate) file_extensions: Vec<String>,
+    pub(crate) terminal_punctuations: Vec<char>,
+    pub(crate) numbered_references: NumberedReferences,
+    pub(crate) numeric_expressions: NumericExpressions,
+    pub(crate) rules: Vec<Rule>,
+    pub(crate) disabled_rules: Vec<String>,
+    pub(crate) merge_rules: Vec<MergeRule>,
+}
+
+/// Characters recognized as uppercase letters, which is how most rules decide whether the next
+/// word starts a new sentence.
+///
+/// ```rust
+/// use pragmatic_segmenter::{Segmenter, UppercaseLetters};
+///
+/// let segmenter = Segmenter::builder()
+///     .uppercase_letters(UppercaseLetters::Unicode)
+///     .build()?;
+/// let result: Vec<_> = segmenter.segment(r#"Он сказал "Да." Она ушла."#).collect();
+/// assert_eq!(result, vec![r#"Он сказал "Да." "#, "Она ушла."]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum UppercaseLetters {
+    /// `[A-Z]` only. This is the behavior of pySBD.
+    #[default]
+    Ascii,
+    /// Every uppercase and titlecase letter of Unicode, including accented Latin (É, Ü, Š), Greek
+    /// and Cyrillic capitals.
+    Unicode,
+    /// Custom character class written in Oniguruma (Ruby) syntax, for example `"[A-ZÄÖÜ]"`. Any
+    /// other letter, like "ä" or "é", is taken for a lowercase letter, which doesn't start a
+    /// sentence unless [`lowercase_sentence_starts()`](SegmenterBuilder::lowercase_sentence_starts)
+    /// is enabled.
+    Custom(String),
+}
+
+/// How each segment is cleaned up after it is split off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PostProcess {
+    /// A segment made only of ASCII letters, possibly followed by a line break, skips the cleanup
+    /// and keeps the line break. With
+    /// [`literal_newlines()`](SegmenterBuilder::literal_newlines), a literal `\n` in the input
+    /// becomes a line break during the segmentation, so such a segment no longer matches the
+    /// input and is dropped. This is the behavior of pySBD.
+    #[default]
+    Compatible,
+    /// Every segment is cleaned up the same way.
+    Corrected,
+}
+
+/// What happens to a segment longer than
+/// [`max_length()`](SegmenterBuilder::max_length).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overlong {
+    /// Split the segment into pieces which are short enough, at the end of a clause like ", " or
+    /// "; " if possible, otherwise at a whitespace, otherwise anywhere. Every piece but the first
+    /// is a [`Kind::Clause`](crate::Kind::Clause).
+    Split,
+    /// Keep the segment, and set [`Segment::overlong`](crate::Segment::overlong).
+    Flag,
+}
+
+/// When the last period of an acronym with multiple periods, like "D.C." or "U.S.S.R.", also
+/// ends the sentence.
+///
+/// ```rust
+/// use pragmatic_segmenter::{AcronymBoundary, Segmenter};
+///
+/// let input = "He lives in Washington D.C. He works there.";
+/// let segmenter = Segmenter::new()?;
+/// let result: Vec<_> = segmenter.segment(input).collect();
+/// assert_eq!(result, vec![input]);
+///
+/// let segmenter = Segmenter::builder()
+///     .acronym_boundary(AcronymBoundary::SentenceStarters)
+///     .build()?;
+/// let result: Vec<_> = segmenter.segment(input).collect();
+/// assert_eq!(result, vec!["He lives in Washington D.C. ", "He works there."]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AcronymBoundary {
+    /// Only "U.S.", "U.K.", "E.U." and "U.S.A." end a sentence, when a common sentence starter
+    /// like "The" or "He" follows. This is the behavior of pySBD.
+    #[default]
+    Compatible,
+    /// Every acronym ends a sentence when a common sentence starter like "The" or "He" follows.
+    SentenceStarters,
+    /// Every acronym ends a sentence when an uppercase letter follows. "The U.S.S.R. Army" is
+    /// split as well.
+    Uppercase,
+}
+
+/// Whether the letters "i", "v" and "x" of a list item marker, like "i)" or "(v)", are Latin
+/// letters or Roman numerals. The list item rules look for alphabetical lists and for Roman
+/// numeral lists one after the other, and these letters can be a part of both.
+///
+/// ```rust
+/// use pragmatic_segmenter::{NumeralConflict, Segmenter};
+///
+/// let latin = "Options: u) red v) green w) blue";
+/// let roman = "Steps: iv) red v) green vi) blue";
+/// let segmenter = |policy| Segmenter::builder().numeral_conflict(policy).build();
+///
+/// let roman_only = segmenter(NumeralConflict::Roman)?;
+/// let result: Vec<_> = roman_only.segment(latin).collect();
+/// assert_eq!(result, vec!["Options: ", "u) red v) green ", "w) blue"]);
+///
+/// let latin_only = segmenter(NumeralConflict::Latin)?;
+/// let result: Vec<_> = latin_only.segment(roman).collect();
+/// assert_eq!(result, vec!["Steps: ", "iv) red v) green ", "vi) blue"]);
+///
+/// let context = segmenter(NumeralConflict::Context)?;
+/// let result: Vec<_> = context.segment(latin).collect();
+/// assert_eq!(result, vec!["Options: ", "u) red ", "v) green ", "w) blue"]);
+/// let result: Vec<_> = context.segment(roman).collect();
+/// assert_eq!(result, vec!["Steps: ", "iv) red ", "v) green ", "vi) blue"]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumeralConflict {
+    /// The letters are looked for in both lists. This is the behavior of pySBD.
+    #[default]
+    Both,
+    /// The letters are Roman numerals only.
+    Roman,
+    /// The letters are Latin letters only.
+    Latin,
+    /// The letters are Roman numerals if the text has a list item marker of two or more Roman
+    /// numeral letters in the same style, like "ii)" or "(iv)", and Latin letters otherwise.
+    Context,
+}
+
+/// Styles of numbered references like "[1]" which are recognized right after the period at the
+/// end of a sentence, so that the reference stays in the sentence it belongs to.
+///
+/// ```rust
+/// use pragmatic_segmenter::{NumberedReferences, Segmenter};
+///
+/// let references = NumberedReferences::default().superscripts(true);
+/// let segmenter = Segmenter::builder().numbered_references(references).build()?;
+/// let result: Vec<_> = segmenter.segment("It was shown.¹² Then we left.").collect();
+/// assert_eq!(result, vec!["It was shown.¹² ", "Then we left."]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberedReferences {
+    max_digits: usize,
+    square_brackets: bool,
+    parentheses: bool,
+    superscripts: bool,
+    bare_numbers: bool,
+}
+
+impl Default for NumberedReferences {
+    /// References in square brackets like "[1][2]" or "[1, 3-5]" and bare numbers like "12",
+    /// with up to 3 digits. This is the behavior of pySBD.
+    fn default() -> Self {
+        NumberedReferences {
+            max_digits: 3,
+            square_brackets: true,
+            parentheses: false,
+            superscripts: false,
+            bare_numbers: true,
+        }
+    }
+}
+
+impl NumberedReferences {
+    /// Recognize no numbered reference at all.
+    pub fn disabled() -> Self {
+        NumberedReferences {
+            square_brackets: false,
+            bare_numbers: false,
+            ..Self::default()
+        }
+    }
+
+    /// Maximum number of digits of a reference number. Defaults to 3.
+    pub fn max_digits(mut self, digits: usize) -> Self {
+        self.max_digits = digits;
+        self
+    }
+
+    /// References in square brackets like "[1][2]" or "[1, 3-5]".
+    pub fn square_brackets(mut self, yes: bool) -> Self {
+        self.square_brackets = yes;
+        self
+    }
+
+    /// References in parentheses like "(1)" or "(1, 3-5)".
+    pub fn parentheses(mut self, yes: bool) -> Self {
+        self.parentheses = yes;
+        self
+    }
+
+    /// References written in superscript digits like "¹²" or "¹,³".
+    pub fn superscripts(mut self, yes: bool) -> Self {
+        self.superscripts = yes;
+        self
+    }
+
+    /// Bare numbers like "12" or "1 2". They are easily confused with other numbers after a
+    /// period, like scores or versions.
+    pub fn bare_numbers(mut self, yes: bool) -> Self {
+        self.bare_numbers = yes;
+        self
+    }
+
+    /// Regex which matches a sequence of references, without capture groups. `None` if no style
+    /// is enabled.
+    pub(crate) fn pattern(&self) -> Option<String> {
+        let digits = format!(r"\d{{1,{}}}", self.max_digits.max(1));
+        let enclosed = |open: &str, close: &str| {
+            format!(
+                r"(?:{open}(?:{digits},?\s?-?\s?)*\b{digits}{close})+",
+                open = open,
+                close = close,
+                digits = digits
+            )
+        };
+        let mut styles = Vec::new();
+        if self.square_brackets {
+            styles.push(enclosed(r"\[", r"\]"));
+        }
+        if self.parentheses {
+            styles.push(enclosed(r"\(", r"\)"));
+        }
+        if self.superscripts {
+            styles.push("[⁰¹²³⁴⁵⁶⁷⁸⁹]+(?:[,⁻-][⁰¹²³⁴⁵⁶⁷⁸⁹]+)*".to_string());
+        }
+        if self.bare_numbers {
+            styles.push(format!(r"(?:{digits}\s?)?{digits}", digits = digits));
+        }
+        if styles.is_empty() {
+            None
+        } else {
+            Some(styles.join("|"))
+        }
+    }
+}
+
+/// Rules for the output of speech recognition, which often has no punctuation at all. Sentence
+/// boundaries are guessed from pause markers and from capitalized words which usually start a
+/// sentence, like "So" or "Then", in lines without any punctuation. A segment made of fillers
+/// like "uh" and "um" is merged into the following one.
+///
+/// ```rust
+/// use pragmatic_segmenter::{Segmenter, SpeechRecognition};
+///
+/// let segmenter = Segmenter::builder()
+///     .speech_recognition(SpeechRecognition::default())
+///     .build()?;
+/// let result: Vec<_> = segmenter
+///     .segment("we met at noon <pause> um <pause> it went well So we signed it")
+///     .collect();
+/// assert_eq!(
+///     result,
+///     vec!["we met at noon <pause> ", "um <pause> it went well ", "So we signed it"]
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpeechRecognition {
+    pub(crate) pause_markers: Vec<String>,
+    pub(crate) capitalization: bool,
+    pub(crate) fillers: Vec<String>,
+}
+
+impl Default for SpeechRecognition {
+    /// Pause markers `<pause>` and `…`, capitalization cues, and English fillers.
+    fn default() -> Self {
+        SpeechRecognition {
+            pause_markers: vec!["<pause>".into(), "…".into()],
+            capitalization: true,
+            fillers: ["uh", "um", "er", "erm", "ah", "hmm", "mm"]
+                .iter()
+                .map(|&filler| filler.into())
+                .collect(),
+        }
+    }
+}
+
+impl SpeechRecognition {
+    /// Replace the tokens which the recognizer emits for a pause, like `"<pause>"` or `"…"`. A
+    /// pause ends a sentence.
+    pub fn pause_markers<I, S>(mut self, markers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.pause_markers = markers.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Whether a capitalized sentence starter like "So" or "Then" after a lowercase word starts
+    /// a new sentence, in lines without any punctuation.
+    pub fn capitalization(mut self, yes: bool) -> Self {
+        self.capitalization = yes;
+        self
+    }
+
+    /// Replace the fillers, which are matched case insensitively.
+    pub fn fillers<I, S>(mut self, fillers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.fillers = fillers.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+/// Rules for numeric expressions with a degree sign, like coordinates and temperatures.
+///
+/// ```rust
+/// use pragmatic_segmenter::{NumericExpressions, Segmenter};
+///
+/// let numeric = NumericExpressions::default().coordinates(true).degrees(true);
+/// let segmenter = Segmenter::builder().numeric_expressions(numeric).build()?;
+/// let result: Vec<_> = segmenter
+///     .segment("Paris is at lat. 48.8566, long. 2.3522° E. It is warm, 20 °C. Nice.")
+///     .collect();
+/// assert_eq!(
+///     result,
+///     vec![
+///         "Paris is at lat. 48.8566, long. 2.3522° E. ",
+///         "It is warm, 20 °C. ",
+///         "Nice."
+///     ]
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumericExpressions {
+    geo_location: bool,
+    coordinates: bool,
+    degrees: bool,
+}
+
+impl Default for NumericExpressions {
+    /// Only pySBD's `GeoLocationRule`.
+    fn default() -> Self {
+        NumericExpressions {
+            geo_location: true,
+            coordinates: false,
+            degrees: false,
+        }
+    }
+}
+
+impl NumericExpressions {
+    /// pySBD's `GeoLocationRule`, which protects a period between a letter with a degree sign
+    /// and a number, like "N°. 5".
+    pub fn geo_location(mut self, yes: bool) -> Self {
+        self.geo_location = yes;
+        self
+    }
+
+    /// Protect the periods of coordinates, like "lat. 48.85" or "48°51′24″N. 2°21′03″E".
+    pub fn coordinates(mut self, yes: bool) -> Self {
+        self.coordinates = yes;
+        self
+    }
+
+    /// Treat a period after a degree with a unit or a compass direction, like "20 °C." or
+    /// "2.35° E.", as a sentence boundary when a sentence follows. Otherwise the unit is taken
+    /// for the initial of a name.
+    pub fn degrees(mut self, yes: bool) -> Self {
+        self.degrees = yes;
+        self
+    }
+
+    pub(crate) fn rules(&self, upper: &str) -> Result<Vec<Rule>, Box<dyn Error>> {
+        let mut rules = Vec::new();
+        if self.geo_location {
+            rules.push(Rule::new(
+                "GeoLocationRule",
+                r"(?<=[a-zA-z]°)\.(?=\s*\d+)",
+                "∯",
+            )?);
+        }
+        if self.coordinates {
+            rules.push(
+                Rule::new(
+                    "CoordinateAbbreviationRule",
+                    r"(?<=\b[Ll]at|\b[Ll]on|\b[Ll]ong|\b[Ll]ng)\.(?=\s*[-−]?\d)",
+                    "∯",
+                )?
+                .with_example("at lat. 48.85, long. 2.35", "at lat∯ 48.85, long∯ 2.35"),
+            );
+            rules.push(
+                Rule::new(
+                    "CoordinatePairRule",
+                    r#"(?<=[°º′″'"][NSEW]|[°º′″'"]\s[NSEW])\.(?=\s+[-−]?\d+(?:[.,]\d+)?\s?[°º])"#,
+                    "∯",
+                )?
+                .with_example("48°51′24″N. 2°21′03″E.", "48°51′24″N∯ 2°21′03″E."),
+            );
+        }
+        if self.degrees {
+            rules.push(
+                Rule::new(
+                    "DegreeUnitRule",
+                    &format!(
+                        r"(?<=[°º′″][CFKNSEW]|[°º′″]\s[CFKNSEW])∯(?=\s+{upper})",
+                        upper = upper
+                    ),
+                    ".",
+                )?
+                .with_example("It was 20 °C∯ Then", "It was 20 °C. Then"),
+            );
+        }
+        Ok(rules)
+    }
+}
+
+impl SegmenterBuilder {
+    /// Create a new builder with the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply the rules of a built-in or [registered](crate::register) language, such as `"en"`.
+    /// Options set after this call override the ones set by the language.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::builder().language("en")?.build()?;
+    /// assert!(Segmenter::builder().language("tlh").is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn language(mut self, code: &str) -> Result<Self, UnknownLanguage> {
+        let language = language::lookup(code).ok_or_else(|| UnknownLanguage(code.into()))?;
+        self.language = Some(code.into());
+        Ok(language.configure(self))
+    }
+
+    /// Apply the options of a [`Preset`] for a kind of text, like news articles or chat
+    /// messages. Options set after this call override the ones set by the preset.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::{Preset, Segmenter};
+    ///
+    /// let segmenter = Segmenter::builder().preset(Preset::Chat).build()?;
+    /// let result: Vec<_> = segmenter.segment("lol ok!  see you   there").collect();
+    /// assert_eq!(result, vec!["lol ok!  ", "see you   there"]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn preset(self, preset: Preset) -> Self {
+        preset.configure(self)
+    }
+
+    /// Allow sentence boundaries before lowercase letters.
+    ///
+    /// By default, most rules only split when the next sentence starts with an uppercase letter,
+    /// so poorly capitalized text (chat logs, ASR transcripts, web text) tends to end up as a
+    /// single giant segment. When enabled, lowercase letters are accepted as sentence starts as
+    /// well. Abbreviation handling is not affected, since a period after a known abbreviation
+    /// followed by a lowercase word is far more likely to be in the middle of a sentence.
+    pub fn lowercase_sentence_starts(mut self, yes: bool) -> Self {
+        self.lowercase_sentence_starts = yes;
+        self
+    }
+
+    /// Set which characters are treated as uppercase letters at the start of a sentence. Defaults
+    /// to [`UppercaseLetters::Ascii`].
+    pub fn uppercase_letters(mut self, letters: UppercaseLetters) -> Self {
+        self.uppercase_letters = letters;
+        self
+    }
+
+    /// Protect sequences of name initials such as "J. R. R. Tolkien" or "W. E. B. Du Bois", even
+    /// when they come right after an opening bracket, a quotation mark or a dash, so that the
+    /// periods of the initials are never treated as sentence boundaries.
+    ///
+    /// pySBD only protects a single uppercase letter preceded by whitespace, so this is disabled
+    /// by default.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::builder().name_initials(true).build()?;
+    /// let result: Vec<_> = segmenter.segment("He quoted 'J. R. R. Tolkien' twice.").collect();
+    /// assert_eq!(result, vec!["He quoted 'J. R. R. Tolkien' twice."]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn name_initials(mut self, yes: bool) -> Self {
+        self.name_initials = yes;
+        self
+    }
+
+    /// Add a set of abbreviations, on top of the built-in English ones. Use
+    /// [`AbbreviationPack::honorifics()`] to recognize the honorifics and titles of other
+    /// languages.
+    ///
+    /// When packs give the same abbreviation different kinds, the kind of the pack which comes
+    /// first in [`abbreviation_priority()`](SegmenterBuilder::abbreviation_priority) is used,
+    /// and among the packs not listed there, the kind of the pack added first. See
+    /// [`abbreviation_conflicts()`](SegmenterBuilder::abbreviation_conflicts).
+    pub fn abbreviations(mut self, pack: AbbreviationPack) -> Self {
+        self.abbreviation_packs.push(pack);
+        self
+    }
+
+    /// Names of the packs, from the highest priority, which decide the kind of an abbreviation
+    /// when packs disagree. Packs which are not listed have lower priority than every listed
+    /// one. The built-in English abbreviations are not affected, since a pack can only add to
+    /// them.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::{AbbreviationKind, AbbreviationPack, Preset, Segmenter};
+    ///
+    /// let user = AbbreviationPack::new("user").with("cf", AbbreviationKind::Plain);
+    /// let builder = Segmenter::builder()
+    ///     .preset(Preset::Legal)
+    ///     .abbreviations(user)
+    ///     .abbreviation_priority(["user"]);
+    /// let conflicts = builder.abbreviation_conflicts();
+    /// assert_eq!(
+    ///     conflicts[0].to_string(),
+    ///     r#""cf" is Plain in "user", overriding Prepositive in "legal""#
+    /// );
+    ///
+    /// let segmenter = builder.build()?;
+    /// let result: Vec<_> = segmenter.segment("See Smith, cf. The rule.").collect();
+    /// assert_eq!(result, vec!["See Smith, cf. ", "The rule."]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn abbreviation_priority<I>(mut self, packs: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.abbreviation_priority = packs.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Abbreviations which are given different kinds by the packs added with
+    /// [`abbreviations()`](SegmenterBuilder::abbreviations), and how each is resolved, sorted
+    /// in the order the abbreviations are first seen. Empty if the packs agree.
+    pub fn abbreviation_conflicts(&self) -> Vec<AbbreviationConflict> {
+        abbreviation_pack::merge(&self.abbreviation_packs, &self.abbreviation_priority).1
+    }
+
+    /// Protect numerals in parentheses like "(iv)" wherever they appear, including before a
+    /// lowercase word, a digit or a punctuation mark.
+    ///
+    /// pySBD only protects them when they are followed by a whitespace and a letter which can
+    /// start a sentence, so this is disabled by default.
+    pub fn numerals_in_parens_in_any_context(mut self, yes: bool) -> Self {
+        self.numerals_in_parens_in_any_context = yes;
+        self
+    }
+
+    /// Protect Arabic numerals in parentheses like "(12)" the same way as roman numerals. They
+    /// appear constantly in statutes and contracts.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::builder().arabic_numerals_in_parens(true).build()?;
+    /// let result: Vec<_> = segmenter.segment("See the rule. (12) The court may act.").collect();
+    /// assert_eq!(result, vec!["See the rule. ", "(12) The court may act."]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn arabic_numerals_in_parens(mut self, yes: bool) -> Self {
+        self.arabic_numerals_in_parens = yes;
+        self
+    }
+
+    /// Add a regular expression, written in Oniguruma (Ruby) syntax, whose matches are never split.
+    /// Sentence-ending punctuations inside the matched regions are masked during boundary
+    /// detection and restored afterwards. This is an escape hatch for domain-specific patterns
+    /// like chemical names or gene identifiers.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::builder()
+    ///     .protected_pattern(r"pH \d+\.\d+ sol\.")
+    ///     .build()?;
+    /// let result: Vec<_> = segmenter.segment("Use pH 7.4 sol. Tris was added.").collect();
+    /// assert_eq!(result, vec!["Use pH 7.4 sol. Tris was added."]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn protected_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.protected_patterns.push(pattern.into());
+        self
+    }
+
+    /// Add a pair of punctuations whose contents are never split, on top of the built-in ones:
+    /// `"…"`, `[…]`, `(…)`, `«…»`, `--…--` and `“…”`. The pairs may nest, and the opening and
+    /// closing punctuations may be the same.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::builder().enclosing_pair("「", "」").build()?;
+    /// let result: Vec<_> = segmenter.segment("He said 「Stop. Now.」 and left. Then?").collect();
+    /// assert_eq!(result, vec!["He said 「Stop. Now.」 and left. ", "Then?"]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn enclosing_pair(mut self, open: impl Into<String>, close: impl Into<String>) -> Self {
+        self.enclosing_pairs.push((open.into(), close.into()));
+        self
+    }
+
+    /// Merge two segments when the boundary between them falls inside a pair of quotation marks
+    /// or brackets, which would leave a dangling quotation mark in both of them. The pairs are
+    /// the same as the ones of [`enclosing_pair()`](SegmenterBuilder::enclosing_pair), and a pair
+    /// never spans a blank line.
+    ///
+    /// Many downstream tools, like machine translation, reject sentences with unbalanced quotes.
+    /// pySBD doesn't merge anything, so this is disabled by default.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::builder().merge_unbalanced_quotes(true).build()?;
+    /// let result: Vec<_> = segmenter.segment("\"Stop.\nNow,\" he said. Then he left.").collect();
+    /// assert_eq!(result, vec!["\"Stop.\nNow,\" he said. ", "Then he left."]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn merge_unbalanced_quotes(mut self, yes: bool) -> Self {
+        self.merge_unbalanced_quotes = yes;
+        self
+    }
+
+    /// Never split inside parentheses, square brackets or braces which span line breaks, like a
+    /// definition in a contract which continues on the next line. Line breaks are sentence
+    /// boundaries for pySBD, so its rules for brackets only protect a bracket within a line.
+    /// Like [`merge_unbalanced_quotes()`](SegmenterBuilder::merge_unbalanced_quotes), a bracket
+    /// never spans a blank line, and quotation marks are left as is.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::builder().multiline_brackets(true).build()?;
+    /// let text = "\"Affiliate\" means any entity (including\nits subsidiaries. See Sec. 2) that\ncontrols it. Next.";
+    /// let result: Vec<_> = segmenter.segment(text).collect();
+    /// assert_eq!(
+    ///     result,
+    ///     vec![
+    ///         "\"Affiliate\" means any entity (including\nits subsidiaries. See Sec. 2) that\n",
+    ///         "controls it. ",
+    ///         "Next."
+    ///     ]
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn multiline_brackets(mut self, yes: bool) -> Self {
+        self.multiline_brackets = yes;
+        self
+    }
+
+    /// Collapse every run of spaces and tabs into a single space before segmentation, for text
+    /// typed with two spaces after a period. Many rules expect exactly one space between
+    /// sentences. Segments are still slices of the input, with the whole run of whitespaces.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let input = "He said \"Stop.\"  Then he left.";
+    /// let segmenter = Segmenter::new()?;
+    /// let result: Vec<_> = segmenter.segment(input).collect();
+    /// assert_eq!(result, vec![input]);
+    ///
+    /// let segmenter = Segmenter::builder().collapse_whitespace(true).build()?;
+    /// let result: Vec<_> = segmenter.segment(input).collect();
+    /// assert_eq!(result, vec!["He said \"Stop.\"  ", "Then he left."]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn collapse_whitespace(mut self, yes: bool) -> Self {
+        self.collapse_whitespace = yes;
+        self
+    }
+
+    /// Lowercase the lines written in capital letters only, like headlines or legal text, before
+    /// segmentation. Otherwise every abbreviation in such a line is followed by an uppercase
+    /// word, and ends a sentence. Words of a single letter are kept as they are, and segments
+    /// are still slices of the input, in capital letters.
+    ///
+    /// The other side is that an abbreviation at the end of a sentence, like "ETC. THE", does
+    /// not end it anymore.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let input = "THIS AGREEMENT IS MADE BY ACME INC. AND JOHN DOE. IT STARTS NOW.";
+    /// let segmenter = Segmenter::new()?;
+    /// let result: Vec<_> = segmenter.segment(input).collect();
+    /// assert_eq!(result.len(), 3);
+    ///
+    /// let segmenter = Segmenter::builder().fold_all_caps(true).build()?;
+    /// let result: Vec<_> = segmenter.segment(input).collect();
+    /// assert_eq!(
+    ///     result,
+    ///     vec!["THIS AGREEMENT IS MADE BY ACME INC. AND JOHN DOE. ", "IT STARTS NOW."]
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn fold_all_caps(mut self, yes: bool) -> Self {
+        self.fold_all_caps = yes;
+        self
+    }
+
+    /// Repair typographic punctuations which were encoded in UTF-8 and decoded as Windows-1252,
+    /// like "â€™" for "’" or "â€œ" for "“", before segmentation. Web pages scraped with the
+    /// wrong encoding are full of them, and the rules for quotations don't see them as quotation
+    /// marks. Segments are still slices of the input, with the mojibake as it is.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let input = "He said â€œStop.â€\u{9d} Then he left.";
+    /// let segmenter = Segmenter::new()?;
+    /// let result: Vec<_> = segmenter.segment(input).collect();
+    /// assert_eq!(result, vec!["He said â€œStop.", "â€\u{9d} Then he left."]);
+    ///
+    /// let segmenter = Segmenter::builder().repair_mojibake(true).build()?;
+    /// let result: Vec<_> = segmenter.segment(input).collect();
+    /// assert_eq!(result, vec!["He said â€œStop.â€\u{9d} ", "Then he left."]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn repair_mojibake(mut self, yes: bool) -> Self {
+        self.repair_mojibake = yes;
+        self
+    }
+
+    /// Treat a salutation at the start of a letter or an email, like "Dear Dr. Smith," or "Hi
+    /// all,", as a segment of its own, even when the body continues on the same line. Subject
+    /// lines like "RE: Contract No. 5" before the salutation are skipped, and are segments of
+    /// their own since they end with a line break.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let input = "Subject: Update\nDear Dr. Smith, the contract is late. Sorry.";
+    /// let segmenter = Segmenter::builder().salutations(true).build()?;
+    /// let result: Vec<_> = segmenter.segment(input).collect();
+    /// assert_eq!(
+    ///     result,
+    ///     vec!["Subject: Update\n", "Dear Dr. Smith, ", "the contract is late. ", "Sorry."]
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn salutations(mut self, yes: bool) -> Self {
+        self.salutations = yes;
+        self
+    }
+
+    /// Enable the rules for the output of speech recognition. See [`SpeechRecognition`].
+    pub fn speech_recognition(mut self, mode: SpeechRecognition) -> Self {
+        self.speech_recognition = Some(mode);
+        self
+    }
+
+    /// Separate the dateline of a news wire article, like "WASHINGTON (Reuters) - " or
+    /// "PARIS, May 3 (AP) — ", from the first sentence. A dateline must start the input or a
+    /// paragraph, and its city must be written in capital letters. The dateline becomes a
+    /// segment of its own, whose kind is [`Kind::Dateline`](crate::Kind::Dateline).
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::{Kind, Segmenter};
+    ///
+    /// let segmenter = Segmenter::builder().datelines(true).build()?;
+    /// let result: Vec<_> = segmenter
+    ///     .segments("PARIS, May 3 (AP) — Markets fell. Bonds rose.")
+    ///     .map(|segment| (segment.text, segment.kind))
+    ///     .collect();
+    /// assert_eq!(
+    ///     result,
+    ///     vec![
+    ///         ("PARIS, May 3 (AP) — ", Kind::Dateline),
+    ///         ("Markets fell. ", Kind::Sentence),
+    ///         ("Bonds rose.", Kind::Sentence),
+    ///     ]
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn datelines(mut self, yes: bool) -> Self {
+        self.datelines = yes;
+        self
+    }
+
+    /// Split before every item of an enumeration which follows a colon on the same line, like
+    /// "required: (1) a passport (2) a visa". The list item rules of pySBD look for markers at
+    /// the start of a line or after a run of text, and miss markers like "(1)", "i." or "A."
+    /// there. An enumeration must have at least two items whose markers have the same style and
+    /// count up from one, so "It costs: 1. 5 dollars." is left as is.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::builder().colon_lists(true).build()?;
+    /// let result: Vec<_> = segmenter
+    ///     .segment("We need: (1) a passport (2) a visa. Then go.")
+    ///     .collect();
+    /// assert_eq!(
+    ///     result,
+    ///     vec!["We need: ", "(1) a passport ", "(2) a visa. ", "Then go."]
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn colon_lists(mut self, yes: bool) -> Self {
+        self.colon_lists = yes;
+        self
+    }
+
+    /// Don't split at a line break in the middle of a sentence, like the hard wraps of text
+    /// extracted from a PDF. A line break is taken for a wrap when the line doesn't end with a
+    /// punctuation and the next one starts with a lowercase letter. This is `doc_type="pdf"` of
+    /// pySBD, except that segments keep the line breaks, so they are still slices of the input.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let input = "The court held that the\ncontract was void. It\nappealed.\nThe end.";
+    /// let segmenter = Segmenter::builder().join_wrapped_lines(true).build()?;
+    /// let result: Vec<_> = segmenter.segment(input).collect();
+    /// assert_eq!(
+    ///     result,
+    ///     vec!["The court held that the\ncontract was void. ", "It\nappealed.\n", "The end."]
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn join_wrapped_lines(mut self, yes: bool) -> Self {
+        self.join_wrapped_lines = yes;
+        self
+    }
+
+    /// Treat the input as a transcript of a conversation. A line which starts with a speaker
+    /// label like "SPEAKER 1:", "Dr. Smith:" or "[John]", a timestamp like "[00:01:23]", or both,
+    /// starts a new turn. A sentence never spans two turns, and every segment is tagged with the
+    /// [speaker](crate::Segment::speaker) of its turn.
+    ///
+    /// A label followed by a colon in mixed case, like "Dr. Smith:", starts a turn only after a
+    /// timestamp or if it starts another line as well, so that a line like "Note: this is
+    /// fine." stays a sentence.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::{Kind, Segmenter};
+    ///
+    /// let segmenter = Segmenter::builder().transcript(true).build()?;
+    /// let text = "SPEAKER 1: So we agreed\n[00:00:05] SPEAKER 2: Yes. Let's go.";
+    /// let result: Vec<_> = segmenter
+    ///     .segments(text)
+    ///     .map(|segment| (segment.text, segment.speaker))
+    ///     .collect();
+    /// assert_eq!(
+    ///     result,
+    ///     vec![
+    ///         ("SPEAKER 1: So we agreed\n", Some("SPEAKER 1")),
+    ///         ("[00:00:05] SPEAKER 2: Yes. ", Some("SPEAKER 2")),
+    ///         ("Let's go.", Some("SPEAKER 2")),
+    ///     ]
+    /// );
+    ///
+    /// let kind = segmenter.segments(text).next().unwrap().kind;
+    /// assert_eq!(kind, Kind::Turn { prefix: "SPEAKER 1: " });
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn transcript(mut self, yes: bool) -> Self {
+        self.transcript = yes;
+        self
+    }
+
+    /// Treat the two characters `\n` as a line break, for text whose line breaks were escaped,
+    /// like a JSON string which was not decoded. pySBD always does this, which corrupts text
+    /// that really contains a backslash, like code in prose, so this is disabled by default.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let input = r"Print a\nb first. Then exit.";
+    /// let segmenter = Segmenter::new()?;
+    /// let result: Vec<_> = segmenter.segment(input).collect();
+    /// assert_eq!(result, vec![r"Print a\nb first. ", "Then exit."]);
+    ///
+    /// let segmenter = Segmenter::builder().literal_newlines(true).build()?;
+    /// let result: Vec<_> = segmenter.segment(r"Hey! Hello\n").collect();
+    /// assert_eq!(result, vec!["Hey! "]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn literal_newlines(mut self, yes: bool) -> Self {
+        self.literal_newlines = yes;
+        self
+    }
+
+    /// Set how each segment is cleaned up after it is split off. Defaults to
+    /// [`PostProcess::Compatible`].
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::{PostProcess, Segmenter};
+    ///
+    /// let input = r"Hey! Hello\n";
+    /// let segmenter = Segmenter::builder().literal_newlines(true).build()?;
+    /// let result: Vec<_> = segmenter.segment(input).collect();
+    /// assert_eq!(result, vec!["Hey! "]);
+    ///
+    /// let segmenter = Segmenter::builder()
+    ///     .literal_newlines(true)
+    ///     .post_process(PostProcess::Corrected)
+    ///     .build()?;
+    /// let result: Vec<_> = segmenter.segment(input).collect();
+    /// assert_eq!(result, vec!["Hey! ", "Hello"]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn post_process(mut self, policy: PostProcess) -> Self {
+        self.post_process = policy;
+        self
+    }
+
+    /// Limit the length of segments to `chars` characters, including the trailing whitespaces,
+    /// for consumers with a fixed buffer. A run-on text without any sentence boundary would
+    /// otherwise come out as a single segment. Disabled by default.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::{Kind, Overlong, Segmenter};
+    ///
+    /// let input = "First, we cut the wood; then, we paint it and wait. Done.";
+    /// let segmenter = Segmenter::builder()
+    ///     .max_length(30, Overlong::Split)
+    ///     .build()?;
+    /// let result: Vec<_> = segmenter
+    ///     .segments(input)
+    ///     .map(|segment| (segment.text, segment.kind))
+    ///     .collect();
+    /// assert_eq!(
+    ///     result,
+    ///     vec![
+    ///         ("First, we cut the wood; ", Kind::Sentence),
+    ///         ("then, we paint it and wait. ", Kind::Clause),
+    ///         ("Done.", Kind::Sentence),
+    ///     ]
+    /// );
+    ///
+    /// let segmenter = Segmenter::builder()
+    ///     .max_length(30, Overlong::Flag)
+    ///     .build()?;
+    /// let flags: Vec<_> = segmenter
+    ///     .segments(input)
+    ///     .map(|segment| segment.overlong)
+    ///     .collect();
+    /// assert_eq!(flags, vec![true, false]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn max_length(mut self, chars: usize, overlong: Overlong) -> Self {
+        self.max_length = Some((chars, overlong));
+        self
+    }
+
+    /// Decide with `scorer` whether the period of an abbreviation, an initial or an acronym
+    /// followed by an uppercase letter ends a sentence, instead of the rules. Every other
+    /// boundary is left as the rules put it. See [`BoundaryScorer`].
+    ///
+    /// The [`fingerprint()`](Segmenter::fingerprint) tells different scorers apart by their
+    /// [`Debug`](std::fmt::Debug) output only.
+    pub fn boundary_scorer(mut self, scorer: impl BoundaryScorer + 'static) -> Self {
+        self.boundary_scorer = Some(Arc::new(scorer));
+        self
+    }
+
+    /// Merge segments which look like pieces of a single sentence, as judged by `score`, for
+    /// example with the perplexity of a language model. `score` is called with the text of two
+    /// adjacent segments, and the second one is merged into the first one if the score is at
+    /// least `threshold`. A merged segment is scored again with the segment after it, and keeps
+    /// the kind of its first piece. Segments of different speakers are never merged.
+    ///
+    /// This runs after every other step but [`max_length()`](SegmenterBuilder::max_length), and
+    /// the [`fingerprint()`](Segmenter::fingerprint) tells different closures apart by the
+    /// threshold only.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// // A sentence rarely starts with a lowercase letter.
+    /// let segmenter = Segmenter::builder()
+    ///     .merge_scorer(0.5, |_before, after| {
+    ///         if after.starts_with(char::is_lowercase) {
+    ///             1.0
+    ///         } else {
+    ///             0.0
+    ///         }
+    ///     })
+    ///     .build()?;
+    /// let result: Vec<_> = segmenter
+    ///     .segment("See Fig. 3a. it shows the drop. Done.")
+    ///     .collect();
+    /// assert_eq!(result, vec!["See Fig. 3a. it shows the drop. ", "Done."]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn merge_scorer(
+        mut self,
+        threshold: f32,
+        score: impl Fn(&str, &str) -> f32 + Send + Sync + 'static,
+    ) -> Self {
+        self.merge_scorer = Some(MergeScorer {
+            threshold,
+            score: Arc::new(score),
+        });
+        self
+    }
+
+    /// Same as [`boundary_scorer()`](SegmenterBuilder::boundary_scorer) with `model`. See
+    /// [`model`](crate::model).
+    #[cfg(feature = "model")]
+    pub fn model(self, model: NgramModel) -> Self {
+        self.boundary_scorer(model)
+    }
+
+    /// Set when an acronym with multiple periods, like "D.C.", ends a sentence. Defaults to
+    /// [`AcronymBoundary::Compatible`].
+    pub fn acronym_boundary(mut self, policy: AcronymBoundary) -> Self {
+        self.acronym_boundary = policy;
+        self
+    }
+
+    /// Set whether "i", "v" and "x" of a list item marker are Latin letters or Roman numerals.
+    /// Defaults to [`NumeralConflict::Both`].
+    pub fn numeral_conflict(mut self, policy: NumeralConflict) -> Self {
+        self.numeral_conflict = policy;
+        self
+    }
+
+    /// Add a word which contains an exclamation mark that never ends a sentence, like a brand or
+    /// a stage name, on top of the built-in ones such as "Yahoo!" and "!Kung". Matching is case
+    /// sensitive.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::builder()
+    ///     .exclamation_word("P!nk")
+    ///     .exclamation_word("Joomla!")
+    ///     .build()?;
+    /// let result: Vec<_> = segmenter.segment("P!nk uses Joomla! For real. Wow!").collect();
+    /// assert_eq!(result, vec!["P!nk uses Joomla! For real. ", "Wow!"]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn exclamation_word(mut self, word: impl Into<String>) -> Self {
+        self.exclamation_words.push(word.into());
+        self
+    }
+
+    /// Add a file extension, without the leading period, on top of the built-in ones such as
+    /// "pdf" and "docx". The period of a file extension written on its own, like ".ipynb", never
+    /// ends a sentence. Matching is case sensitive.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::builder()
+    ///     .file_extension("ipynb")
+    ///     .file_extension("yaml")
+    ///     .build()?;
+    /// let result: Vec<_> = segmenter
+    ///     .segment("Save it as .ipynb or .yaml files. Then run it.")
+    ///     .collect();
+    /// assert_eq!(result, vec!["Save it as .ipynb or .yaml files. ", "Then run it."]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn file_extension(mut self, extension: impl Into<String>) -> Self {
+        self.file_extensions.push(extension.into());
+        self
+    }
+
+    /// Add a punctuation which ends a sentence, on top of the built-in ones such as "." and "?",
+    /// like "؟" of Arabic. Closing quotation marks and brackets right after it stay in the
+    /// sentence, but unlike the built-in ones, it is never ignored inside quotations or
+    /// parentheses.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::builder().terminal_punctuation('؟').build()?;
+    /// let result: Vec<_> = segmenter.segment("Why؟ Because.").collect();
+    /// assert_eq!(result, vec!["Why؟ ", "Because."]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn terminal_punctuation(mut self, punctuation: char) -> Self {
+        self.terminal_punctuations.push(punctuation);
+        self
+    }
+
+    /// Set which styles of numbered references are recognized after the period at the end of a
+    /// sentence. Defaults to [`NumberedReferences::default()`], the behavior of pySBD.
+    pub fn numbered_references(mut self, references: NumberedReferences) -> Self {
+        self.numbered_references = references;
+        self
+    }
+
+    /// Set the rules for numeric expressions with a degree sign. Defaults to
+    /// [`NumericExpressions::default()`], the behavior of pySBD.
+    pub fn numeric_expressions(mut self, numeric: NumericExpressions) -> Self {
+        self.numeric_expressions = numeric;
+        self
+    }
+
+    /// Add a [`Rule`], which runs after the built-in rules which protect periods that are not
+    /// sentence boundaries. At this point, line breaks are written as `\r` and protected periods
+    /// are written as `∯`, so a rule can protect a period by replacing it with `∯`.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::{Rule, Segmenter};
+    ///
+    /// let rule = Rule::new("ChapterRule", r"(?<=\bch)\.(?=\s\d)", "∯")?;
+    /// let segmenter = Segmenter::builder().rule(rule).build()?;
+    /// let result: Vec<_> = segmenter.segment("See ch. 5 for details.").collect();
+    /// assert_eq!(result, vec!["See ch. 5 for details."]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Turn off a rule by its [name](Rule::name), like `"FileFormatRule"` or
+    /// `"NumberedReferenceRule"`, keeping the rest of the segmentation as it is. Every rule listed
+    /// by [`Segmenter::rules()`] can be disabled, including the ones added with
+    /// [`rule()`](SegmenterBuilder::rule). [`build()`](SegmenterBuilder::build) fails with
+    /// [`UnknownRule`](crate::UnknownRule) if the segmenter has no rule of the name.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::builder().disable_rule("FileFormatRule").build()?;
+    /// let result: Vec<_> = segmenter.segment("Save it as a .docx file. Then send it.").collect();
+    /// assert_eq!(result, vec!["Save it as a .", "docx file. ", "Then send it."]);
+    /// assert!(segmenter.rules().all(|rule| rule.name() != "FileFormatRule"));
+    ///
+    /// assert!(Segmenter::builder().disable_rule("NoSuchRule").build().is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn disable_rule(mut self, name: impl Into<String>) -> Self {
+        self.disabled_rules.push(name.into());
+        self
+    }
+
+    /// Add a [`MergeRule`], which merges adjacent segments back into one after the split. Merge
+    /// rules run in the order they were added, after
+    /// [`merge_scorer()`](SegmenterBuilder::merge_scorer) and before
+    /// [`max_length()`](SegmenterBuilder::max_length). A merged segment is checked again with
+    /// the segment after it, and keeps the kind of its first piece. Segments of different
+    /// speakers are never merged.
+    pub fn merge_rule(mut self, rule: MergeRule) -> Self {
+        self.merge_rules.push(rule);
+        self
+    }
+
+    /// Compile the regular expressions with the configured options.
+    pub fn build(&self) -> Result<Segmenter, Box<dyn Error>> {
+        for pack in &self.abbreviation_packs {
+            pack.validate()?;
+        }
+        Segmenter::with_builder(self)
+    }
+
+    /// Regex which matches a single uppercase letter.
+    pub(crate) fn uppercase_pattern(&self) -> &str {
+        match &self.uppercase_letters {
+            UppercaseLetters::Ascii => "[A-Z]",
+            UppercaseLetters::Unicode => r"[\p{Lu}\p{Lt}]",
+            UppercaseLetters::Custom(class) => class,
+        }
+    }
+
+    /// Letters of the rules which look for letters of any case, like the letters of "e.g.",
+    /// written as the inside of a character class. A custom class is added to the letters of
+    /// Unicode, since it may have characters which are not letters.
+    pub(crate) fn letter_class(&self) -> Cow<'_, str> {
+        match &self.uppercase_letters {
+            UppercaseLetters::Ascii => "a-zA-Z".into(),
+            UppercaseLetters::Unicode => r"\p{L}".into(),
+            // NOTE: Oniguruma은 문자 클래스 안에 문자 클래스를 넣을 수 있다.
+            UppercaseLetters::Custom(class) => format!(r"\p{{L}}{}", class).into(),
+        }
+    }
+
+    /// Regex which matches a single lowercase letter. With a custom class, this is any letter
+    /// which is not in the class, that is, a letter which doesn't start a sentence.
+    pub(crate) fn lowercase_pattern(&self) -> Cow<'_, str> {
+        match &self.uppercase_letters {
+            UppercaseLetters::Ascii => "[a-z]".into(),
+            UppercaseLetters::Unicode => r"\p{Ll}".into(),
+            UppercaseLetters::Custom(class) => format!(r"(?:(?!{})\p{{L}})", class).into(),
+        }
+    }
+
+    /// Regex for the `replace_parens` rule. Group 1 is the numeral without the parentheses.
+    pub(crate) fn numerals_in_parens_pattern(&self) -> String {
+        // ROMAN_NUMERALS_IN_PARENTHESES
+        let mut numeral = r"(?=[mdclxvi])m*(c[md]|d?c*)(x[cl]|l?x*)(i[xv]|v?i*)".to_string();
+        if self.arabic_numerals_in_parens {
+            numeral = format!(r"{}|\d{{1,3}}", numeral);
+        }
+        let lookahead = if self.numerals_in_parens_in_any_context {
+            r"(?=\s|\p{P}|\z)".to_string()
+        } else {
+            format!(r"(?=\s{})", self.sentence_start_pattern())
+        };
+        format!(r"\(({})\){}", numeral, lookahead)
+    }
+
+    /// Regex which matches a single letter that can start a new sentence.
+    pub(crate) fn sentence_start_pattern(&self) -> String {
+        if self.lowercase_sentence_starts {
+            format!(
+                "(?:{}|{})",
+                self.uppercase_pattern(),
+                self.lowercase_pattern()
+            )
+        } else {
+            self.uppercase_pattern().to_string()
+        }
+    }
+}
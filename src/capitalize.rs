@@ -0,0 +1,144 @@
+//! Post-process which capitalizes the first letter of each sentence, for text written without
+//! capitals like the output of speech recognition. Only the first letter of a sentence is
+//! replaced, and every other character is kept as is.
+//!
+//! Lowercase text is usually segmented with
+//! [`lowercase_sentence_starts()`](crate::SegmenterBuilder::lowercase_sentence_starts) enabled.
+//!
+//! ```rust
+//! use pragmatic_segmenter::{capitalize, Segmenter};
+//!
+//! let segmenter = Segmenter::builder().lowercase_sentence_starts(true).build()?;
+//! let text = "okay. so we met at noon. then we left with the iPhone";
+//! assert_eq!(
+//!     capitalize::document(&segmenter, text),
+//!     "Okay. So we met at noon. Then we left with the iPhone"
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::borrow::Cow;
+
+use crate::{Kind, Segment, Segmenter};
+
+/// Byte offset of the letter which should be capitalized in `sentence`, which is the first
+/// alphanumeric character after leading whitespaces and punctuations like quotation marks.
+/// `None` if the sentence starts with a digit, or if the first word should be left alone since
+/// it already has an uppercase letter ("iPhone") or looks like a URL or an email address.
+pub fn sentence_start(sentence: &str) -> Option<usize> {
+    let (idx, c) = sentence.char_indices().find(|(_, c)| c.is_alphanumeric())?;
+    if !c.is_lowercase() {
+        return None;
+    }
+    let word = sentence[idx..].split(char::is_whitespace).next().unwrap(); // Must exist
+    if word.chars().any(char::is_uppercase) || word.contains(&['@', '/'][..]) {
+        return None;
+    }
+    Some(idx)
+}
+
+/// Capitalize the first letter of `sentence`.
+///
+/// ```rust
+/// use pragmatic_segmenter::capitalize;
+///
+/// assert_eq!(capitalize::sentence("\"straße\" is a word."), "\"Straße\" is a word.");
+/// assert_eq!(capitalize::sentence("eBay is a company."), "eBay is a company.");
+/// ```
+pub fn sentence(sentence: &str) -> Cow<'_, str> {
+    match sentence_start(sentence) {
+        Some(idx) => Cow::Owned(replace_at(sentence, idx)),
+        None => Cow::Borrowed(sentence),
+    }
+}
+
+/// Capitalize the first letter of a segment. The marker of a list item and the prefix of a
+/// speaker turn are skipped, so "a) item" becomes "a) Item".
+pub fn segment<'a>(segment: &Segment<'a>) -> Cow<'a, str> {
+    let marker_len = match segment.kind {
+        Kind::ListItem { marker } => marker.len(),
+        Kind::Turn { prefix } => prefix.len(),
+        _ => 0,
+    };
+    match sentence_start(&segment.text[marker_len..]) {
+        Some(idx) => Cow::Owned(replace_at(segment.text, marker_len + idx)),
+        None => Cow::Borrowed(segment.text),
+    }
+}
+
+/// Segment `text` and capitalize the first letter of every sentence.
+pub fn document(segmenter: &Segmenter, text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut last = 0;
+    for seg in segmenter.segments(text) {
+        result += &text[last..seg.start];
+        result += &segment(&seg);
+        last = seg.start + seg.text.len();
+    }
+    result += &text[last..];
+    result
+}
+
+/// Replace the character at `idx` with its titlecase.
+fn replace_at(text: &str, idx: usize) -> String {
+    let c = text[idx..].chars().next().unwrap(); // Must exist
+    let mut result = String::with_capacity(text.len() + 2);
+    result += &text[..idx];
+    titlecase(c, &mut result);
+    result += &text[idx + c.len_utf8()..];
+    result
+}
+
+/// Titlecase of a character. Rust has no titlecase mapping, so it is approximated with the
+/// uppercase mapping, except for the few characters whose titlecase is neither lowercase nor
+/// uppercase.
+fn titlecase(c: char, out: &mut String) {
+    let digraph = match c {
+        'Ǆ' | 'ǅ' | 'ǆ' => Some('ǅ'),
+        'Ǉ' | 'ǈ' | 'ǉ' => Some('ǈ'),
+        'Ǌ' | 'ǋ' | 'ǌ' => Some('ǋ'),
+        'Ǳ' | 'ǲ' | 'ǳ' => Some('ǲ'),
+        _ => None,
+    };
+    if let Some(digraph) = digraph {
+        out.push(digraph);
+        return;
+    }
+    // "ß" -> "Ss", "ﬁ" -> "Fi"
+    let mut upper = c.to_uppercase();
+    out.extend(upper.next());
+    out.extend(upper.flat_map(char::to_lowercase));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sentence() {
+        assert_eq!(sentence("hello."), "Hello.");
+        assert_eq!(sentence("  (¿qué?) dijo."), "  (¿Qué?) dijo.");
+        assert_eq!(sentence("ǆep je pun."), "ǅep je pun.");
+        assert_eq!(sentence("ßa."), "Ssa.");
+        assert_eq!(sentence("5 people came."), "5 people came.");
+        assert_eq!(
+            sentence("www.example.com/a is down."),
+            "www.example.com/a is down."
+        );
+        assert_eq!(sentence("kim@example.com wrote."), "kim@example.com wrote.");
+        assert_eq!(sentence(""), "");
+    }
+
+    #[test]
+    fn test_document() -> Result<(), Box<dyn std::error::Error>> {
+        let segmenter = Segmenter::builder()
+            .lowercase_sentence_starts(true)
+            .build()?;
+        let text = "a) first item\nb) second item\nand then? we left.  ok";
+        assert_eq!(
+            document(&segmenter, text),
+            "a) First item\nb) Second item\nAnd then? We left.  Ok"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+//! Segmentation of a large input on a background thread, which sends the segments through a
+//! bounded channel. The input is read only as fast as the segments are received, so a slow
+//! consumer like a network writer throttles the reading, and the memory stays bounded however
+//! large the input is.
+//!
+//! The input is read and segmented one chunk of paragraphs at a time. Sentences never cross a
+//! blank line, so the segments are the same as [`Segmenter::segments()`] of the whole input. A
+//! paragraph larger than a megabyte is cut at its last sentence boundary found so far instead,
+//! where the segments may rarely differ from the ones of the whole input. Only a single sentence
+//! larger than that is held in memory whole.
+//!
+//! ```rust
+//! use std::io::Cursor;
+//! use std::sync::Arc;
+//!
+//! use pragmatic_segmenter::channel;
+//! use pragmatic_segmenter::Segmenter;
+//!
+//! let segmenter = Arc::new(Segmenter::new()?);
+//! let input = Cursor::new("Hi Mr. Kim. Bye.\n\nSee you.");
+//! let mut texts = Vec::new();
+//! for segment in channel::spawn(segmenter, input, 16) {
+//!     let segment = segment?;
+//!     texts.push((segment.start, segment.text));
+//! }
+//! assert_eq!(
+//!     texts,
+//!     vec![
+//!         (0, "Hi Mr. Kim. ".to_string()),
+//!         (12, "Bye.\n\n".to_string()),
+//!         (18, "See you.".to_string()),
+//!     ]
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::io::{self, BufRead, Read};
+use std::str;
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::Arc;
+use std::thread;
+
+use crate::Segmenter;
+
+/// Minimum number of bytes segmented at once.
+const CHUNK: usize = 64 * 1024;
+
+/// Number of bytes of a paragraph after which it is cut at a sentence boundary.
+const LIMIT: usize = 16 * CHUNK;
+
+/// A segment sent by [`spawn()`]. Unlike [`Segment`](crate::Segment), it owns its text, since
+/// the input is dropped as soon as it is segmented.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct OwnedSegment {
+    /// Text of the segment, including the trailing whitespaces.
+    pub text: String,
+    /// Byte offset of [`text`](OwnedSegment::text) in the whole input.
+    pub start: usize,
+}
+
+/// Segment `input` with `segmenter` on a new thread, and send the segments in order through a
+/// channel which holds at most `capacity` segments. The thread blocks while the channel is
+/// full, and stops when the [`Receiver`] is dropped.
+///
+/// An error of reading `input`, including invalid UTF-8, is sent as the last item.
+pub fn spawn<R>(
+    segmenter: Arc<Segmenter>,
+    input: R,
+    capacity: usize,
+) -> Receiver<io::Result<OwnedSegment>>
+where
+    R: BufRead + Send + 'static,
+{
+    let (sender, receiver) = mpsc::sync_channel(capacity);
+    thread::spawn(move || {
+        if let Err(err) = produce(&segmenter, input, CHUNK, LIMIT, &sender) {
+            // NOTE: 받는 쪽이 이미 사라졌다면 알릴 곳이 없으므로 무시한다.
+            let _ = sender.send(Err(err));
+        }
+    });
+    receiver
+}
+
+/// Read `input` in chunks of at least `chunk` bytes which end at a blank line, and send the
+/// segments of each chunk. A chunk which grows over `limit` bytes is cut before its last
+/// segment, which may go on. Returns `Ok` early if the receiver is gone.
+fn produce(
+    segmenter: &Segmenter,
+    mut input: impl BufRead,
+    chunk: usize,
+    limit: usize,
+    sender: &SyncSender<io::Result<OwnedSegment>>,
+) -> io::Result<()> {
+    let send = |text: &str, start: usize| {
+        let segment = OwnedSegment {
+            text: text.to_string(),
+            start,
+        };
+        sender.send(Ok(segment)).is_ok()
+    };
+
+    let mut buf = String::new();
+    let mut offset = 0;
+    let mut bytes = Vec::new();
+    let mut blank = false;
+    let mut line_start = true;
+    let mut cap = limit;
+    loop {
+        // NOTE: 줄바꿈이 없는 큰 입력도 한 번에 읽지 않도록, 한 번에 limit 바이트까지만 읽는다.
+        // 중간에 잘린 UTF-8 글자는 다음에 읽은 바이트와 합친다.
+        let eof = input
+            .by_ref()
+            .take(limit.max(1) as u64)
+            .read_until(b'\n', &mut bytes)?
+            == 0;
+        let valid = match str::from_utf8(&bytes) {
+            Ok(text) => text.len(),
+            Err(err) if err.error_len().is_none() && !eof => err.valid_up_to(),
+            Err(err) => return Err(io::Error::new(io::ErrorKind::InvalidData, err)),
+        };
+        let line = String::from_utf8(bytes.drain(..valid).collect()).unwrap(); // Must be valid
+
+        // 빈 줄 뒤에 내용이 있는 줄이 오면, 그 앞에서 자른다. 빈 줄들은 앞 문단의 뒤따르는
+        // 공백이므로 앞 조각에 남긴다.
+        let is_blank = line.trim().is_empty();
+        if eof || (blank && !is_blank && buf.len() >= chunk) {
+            for segment in segmenter.segments(&buf) {
+                if !send(segment.text, offset + segment.start) {
+                    return Ok(());
+                }
+            }
+            offset += buf.len();
+            buf.clear();
+            cap = limit;
+        }
+        if eof {
+            return Ok(());
+        }
+        blank = line_start && is_blank && line.ends_with('\n');
+        line_start = line.ends_with('\n');
+        buf.push_str(&line);
+
+        // 빈 줄 없이 너무 커지면 마지막 세그먼트 앞에서 자른다. 마지막 세그먼트는 뒤에 이어질
+        // 수 있으므로 남겨서 다음 텍스트와 함께 다시 나눈다.
+        if buf.len() >= cap {
+            let segments: Vec<_> = segmenter.segments(&buf).collect();
+            if let [done @ .., last] = segments.as_slice() {
+                if !done.is_empty() {
+                    for segment in done {
+                        if !send(segment.text, offset + segment.start) {
+                            return Ok(());
+                        }
+                    }
+                    let cut = last.start;
+                    offset += cut;
+                    buf.drain(..cut);
+                }
+            }
+            // NOTE: 문장 하나가 limit보다 길면, limit만큼 더 읽은 뒤에 다시 자른다.
+            cap = buf.len() + limit;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_produce() -> Result<(), Box<dyn std::error::Error>> {
+        let segmenter = Segmenter::new()?;
+        let text = "Hi Mr. Kim. Bye.\n\n\nIt is 3 P.M.\nNow.\n\nOk. Fine\nYes.\n\nEnd";
+        let expected: Vec<_> = segmenter
+            .segments(text)
+            .map(|segment| {
+                Ok(OwnedSegment {
+                    text: segment.text.to_string(),
+                    start: segment.start,
+                })
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        for chunk in [0, 10, 1000] {
+            let (sender, receiver) = mpsc::sync_channel(100);
+            produce(&segmenter, Cursor::new(text), chunk, 1000, &sender)?;
+            drop(sender);
+            let received = receiver.iter().collect::<io::Result<Vec<_>>>()?;
+            assert_eq!(received, expected, "chunk of {} bytes", chunk);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_limit() -> Result<(), Box<dyn std::error::Error>> {
+        let segmenter = Segmenter::new()?;
+        let text = "Hi Mr. Kim. It is 3 P.M. now. 가나다. 라마바. Ok.\nYes. Fine.\n\nEnd.";
+        let expected: Vec<_> = segmenter
+            .segments(text)
+            .map(|segment| (segment.text.to_string(), segment.start))
+            .collect();
+        for limit in [0, 1, 2, 5, 20] {
+            let (sender, receiver) = mpsc::sync_channel(100);
+            produce(&segmenter, Cursor::new(text), 1000, limit, &sender)?;
+            drop(sender);
+            let received = receiver
+                .iter()
+                .map(|segment| segment.map(|segment| (segment.text, segment.start)))
+                .collect::<io::Result<Vec<_>>>()?;
+            assert_eq!(received, expected, "limit of {} bytes", limit);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_spawn() -> Result<(), Box<dyn std::error::Error>> {
+        let segmenter = Arc::new(Segmenter::new()?);
+        let input = Cursor::new(b"Hi. Bye.\n\n\xff".to_vec());
+        let received: Vec<_> = spawn(segmenter.clone(), input, 1).into_iter().collect();
+        assert_eq!(received.len(), 1);
+        assert_eq!(
+            received[0].as_ref().unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+
+        // 받는 쪽이 먼저 사라져도 멈추지 않고 끝나야 한다.
+        let input = Cursor::new("Hi. ".repeat(10_000));
+        let mut receiver = spawn(segmenter, input, 1).into_iter();
+        assert_eq!(receiver.next().unwrap()?.text, "Hi. ");
+        drop(receiver);
+        Ok(())
+    }
+}
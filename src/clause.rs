@@ -0,0 +1,83 @@
+/// Punctuations which end a clause, with their priority as a place to split. A higher priority
+/// is preferred even if it makes a shorter piece.
+const CLAUSE_ENDS: &[(char, u8)] = &[(';', 3), (':', 3), (',', 2), ('—', 2), ('–', 2), (')', 2)];
+
+/// Split `text` into pieces of at most `max` characters, at the end of a clause if possible,
+/// otherwise at a whitespace, otherwise anywhere. Every piece keeps the whitespaces after it.
+pub(crate) fn split(text: &str, max: usize) -> Vec<&str> {
+    let max = max.max(1);
+    let mut pieces = Vec::new();
+    let mut rest = text;
+    while let Some((limit, _)) = rest.char_indices().nth(max) {
+        let cut = clause_cut(rest, limit).unwrap_or(limit);
+        let (piece, tail) = rest.split_at(cut);
+        pieces.push(piece);
+        rest = tail;
+    }
+    if !rest.is_empty() {
+        pieces.push(rest);
+    }
+    pieces
+}
+
+/// The end of the last run of whitespaces with the highest priority, which ends at or before
+/// `limit`, so that the whitespaces stay in the same piece.
+fn clause_cut(text: &str, limit: usize) -> Option<usize> {
+    best_cut(text, limit, |idx| {
+        let prev = text[..idx].trim_end().chars().next_back();
+        prev.and_then(|p| CLAUSE_ENDS.iter().find(|&&(c, _)| c == p))
+            .map_or(1, |&(_, priority)| priority)
+    })
+}
+
+/// The start of a word of `text` at or before `limit` with the highest `priority`, the last one
+/// among equals. Shared with [`Rechunker`](crate::tts::Rechunker), which ranks the words with
+/// conjunctions as well.
+pub(crate) fn best_cut(
+    text: &str,
+    limit: usize,
+    mut priority: impl FnMut(usize) -> u8,
+) -> Option<usize> {
+    let mut best: Option<(u8, usize)> = None;
+    let mut prev: Option<char> = None;
+    for (idx, c) in text.char_indices() {
+        if idx > limit {
+            break;
+        }
+        let word_start = !c.is_whitespace() && prev.is_some_and(char::is_whitespace);
+        prev = Some(c);
+        if !word_start {
+            continue;
+        }
+        let priority = priority(idx);
+        if best.is_none_or(|(best, _)| priority >= best) {
+            best = Some((priority, idx));
+        }
+    }
+    best.map(|(_, idx)| idx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split() {
+        assert_eq!(
+            split("one two, three four; five six seven", 21),
+            vec!["one two, three four; ", "five six seven"]
+        );
+        assert_eq!(
+            split("one two, three four; five six seven", 20),
+            vec!["one two, ", "three four; ", "five six seven"]
+        );
+        assert_eq!(
+            split("aa, bb cc dd ee", 10),
+            vec!["aa, ", "bb cc dd ", "ee"]
+        );
+        assert_eq!(split("abcdefgh", 3), vec!["abc", "def", "gh"]);
+        assert_eq!(split("가나다 라마", 4), vec!["가나다 ", "라마"]);
+        assert_eq!(split("short", 10), vec!["short"]);
+        assert!(split("", 10).is_empty());
+    }
+}
@@ -0,0 +1,154 @@
+use onig::Regex;
+
+use crate::util::re;
+
+#[rustfmt::skip]
+const ROMAN_NUMERALS: &[&str] = &[
+    "i", "ii", "iii", "iv", "v", "vi", "vii", "viii", "ix", "x",
+    "xi", "xii", "xiii", "xiv", "xv", "xvi", "xvii", "xviii", "xix", "xx",
+];
+
+/// Enumerations which follow a colon on the same line, like "required: 1. A passport 2. A visa",
+/// enabled by [`SegmenterBuilder::colon_lists()`](crate::SegmenterBuilder::colon_lists).
+pub(crate) struct ColonLists {
+    marker_regex: Regex,
+}
+
+/// How the items of an enumeration are counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Counter {
+    Number,
+    Lower,
+    Upper,
+    LowerRoman,
+    UpperRoman,
+}
+
+impl Counter {
+    /// Counter and value of the first item of an enumeration. "i" is a roman numeral here,
+    /// since an alphabetical list never starts with it.
+    fn first(label: &str) -> Option<Self> {
+        match label {
+            "1" => Some(Counter::Number),
+            "a" => Some(Counter::Lower),
+            "A" => Some(Counter::Upper),
+            "i" => Some(Counter::LowerRoman),
+            "I" => Some(Counter::UpperRoman),
+            _ => None,
+        }
+    }
+
+    fn value(self, label: &str) -> Option<usize> {
+        let letter = || match label.as_bytes() {
+            &[c] => Some(c),
+            _ => None,
+        };
+        match self {
+            Counter::Number => label.parse().ok(),
+            Counter::Lower => letter()
+                .filter(u8::is_ascii_lowercase)
+                .map(|c| (c - b'a') as usize + 1),
+            Counter::Upper => letter()
+                .filter(u8::is_ascii_uppercase)
+                .map(|c| (c - b'A') as usize + 1),
+            Counter::LowerRoman => ROMAN_NUMERALS
+                .iter()
+                .position(|&r| r == label)
+                .map(|i| i + 1),
+            Counter::UpperRoman => ROMAN_NUMERALS
+                .iter()
+                .position(|&r| r.to_uppercase() == label)
+                .map(|i| i + 1),
+        }
+    }
+}
+
+impl ColonLists {
+    pub fn new() -> Result<Self, onig::Error> {
+        Ok(ColonLists {
+            // 1: "(1)" 꼴의 번호, 2: "1." 또는 "1)" 꼴의 번호, 3: 번호 뒤의 문장부호
+            marker_regex: re(
+                r"(?<=[\s:])(?:\((\d{1,2}|[a-zA-Z]|[ivxIVX]{1,5})\)|(\d{1,2}|[a-zA-Z]|[ivxIVX]{1,5})([.)]))(?=[ \t])",
+            )?,
+        })
+    }
+
+    /// Byte offsets of the items of enumerations which follow a colon on the same line. An
+    /// enumeration must have at least two items, whose markers have the same style and count up
+    /// from one, like "(a) … (b)" or "i. … ii.".
+    pub fn find(&self, text: &str) -> Vec<usize> {
+        let mut items = Vec::new();
+        for (colon, _) in text.match_indices(':') {
+            let line_end = text[colon..]
+                .find(['\n', '\r'])
+                .map_or(text.len(), |idx| colon + idx);
+            let line = &text[..line_end];
+            let first = line.len() - line[colon + 1..].trim_start().len();
+
+            let mut found = Vec::new();
+            let mut state = None;
+            for c in self.marker_regex.captures_iter(line) {
+                let (start, _) = c.pos(0).unwrap(); // Must exist
+                if start < first {
+                    continue;
+                }
+                let (label, style) = match c.at(1) {
+                    Some(label) => (label, "()"),
+                    None => (c.at(2).unwrap_or_default(), c.at(3).unwrap_or_default()),
+                };
+                match state {
+                    None if start == first => match Counter::first(label) {
+                        Some(counter) => state = Some((counter, style, 1)),
+                        None => break,
+                    },
+                    None => break,
+                    Some((counter, s, value)) => {
+                        if s != style || counter.value(label) != Some(value + 1) {
+                            continue;
+                        }
+                        state = Some((counter, s, value + 1));
+                    }
+                }
+                found.push(start);
+            }
+            if found.len() >= 2 {
+                items.extend(found);
+            }
+        }
+        items
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() -> Result<(), onig::Error> {
+        let colon_lists = ColonLists::new()?;
+        let items = |text| -> Vec<_> {
+            colon_lists
+                .find(text)
+                .into_iter()
+                .map(|idx| &text[idx..])
+                .collect()
+        };
+        assert_eq!(
+            items("We need: (1) a pen (2) a bag."),
+            vec!["(1) a pen (2) a bag.", "(2) a bag."]
+        );
+        assert_eq!(
+            items("Use:i. red ii. blue iv. no"),
+            vec!["i. red ii. blue iv. no", "ii. blue iv. no"]
+        );
+        assert_eq!(
+            items("A: A. cat B) dog B. emu"),
+            vec!["A. cat B) dog B. emu", "B. emu"]
+        );
+        assert!(items("It costs: 1. 5 dollars.").is_empty());
+        assert!(items("Steps: 2. Go 3. Stop").is_empty());
+        assert!(items("Steps: 1. Go\n2. Stop").is_empty());
+        assert!(items("Ratio 2:1 2 3").is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,4 @@
+//! Reimplementations of the functions of other languages whose exact semantics this crate
+//! depends on, for other ports which need the same behavior.
+
+pub mod python;
@@ -0,0 +1,180 @@
+//! Python's string methods, as pySBD uses them. Rust's standard library has similar functions
+//! with subtly different semantics: [`str::lines()`] only splits on `\n` and `\r\n`, and
+//! [`char::is_uppercase()`] looks at a single character.
+//!
+//! ```rust
+//! use pragmatic_segmenter::compat::python::{isupper, SplitLines};
+//!
+//! let lines: Vec<_> = SplitLines::new().splitlines_keepends("a\rb\u{2028}c").collect();
+//! assert_eq!(lines, vec!["a\r", "b\u{2028}", "c"]);
+//! assert!(isupper("Ä1_B"));
+//! assert!(!isupper("1_2"));
+//! ```
+
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, AhoCorasickKind, FindIter, MatchKind};
+use unic_ucd_case::is_cased;
+
+/// Rust implementation of Python's [`str.splitlines()`][ref]. The line boundaries are built
+/// once, so keep it around for splitting many strings.
+///
+/// [ref]: https://docs.python.org/3/library/stdtypes.html#str.splitlines
+#[derive(Debug, Clone)]
+pub struct SplitLines(AhoCorasick);
+
+impl SplitLines {
+    pub fn new() -> Self {
+        let newlines = &[
+            "\r\n",     // Carriage Return + Line Feed
+            "\n",       // Line Feed
+            "\r",       // Carriage Return
+            "\x0b",     // Line Tabulation
+            "\x0c",     // Form Feed
+            "\x1c",     // File Separator
+            "\x1d",     // Group Separator
+            "\x1e",     // Record Separator
+            "\u{85}",   // Next Line (C1 Control Code)
+            "\u{2028}", // Line Separator
+            "\u{2029}", // Paragraph Separator
+        ];
+
+        Self(
+            AhoCorasickBuilder::new()
+                .match_kind(MatchKind::LeftmostFirst)
+                .kind(Some(AhoCorasickKind::DFA))
+                .build(newlines)
+                .unwrap(), // NOTE: It does not fails with our small input
+        )
+    }
+
+    /// Same as `str.splitlines(keepends=True)`. Each line includes its line boundary.
+    pub fn splitlines_keepends<'a>(&self, input: &'a str) -> SplitLinesKeepEnds<'_, 'a> {
+        SplitLinesKeepEnds {
+            input,
+            last_index: 0,
+            searcher: self.0.find_iter(input),
+        }
+    }
+
+    /// Same as `str.splitlines()`. The line boundaries are removed.
+    pub fn splitlines<'a>(&'a self, input: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        // 각 줄의 유일한 줄바꿈은 줄 끝에 있다.
+        self.splitlines_keepends(input)
+            .map(move |line| &line[..self.0.find(line).map_or(line.len(), |mat| mat.start())])
+    }
+}
+
+impl Default for SplitLines {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Iterator over the lines of a string with their line boundaries, returned by
+/// [`SplitLines::splitlines_keepends()`].
+#[derive(Debug)]
+pub struct SplitLinesKeepEnds<'ac, 'input> {
+    input: &'input str,
+    last_index: usize,
+    searcher: FindIter<'ac, 'input>,
+}
+
+impl<'ac, 'input> Iterator for SplitLinesKeepEnds<'ac, 'input> {
+    type Item = &'input str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.searcher.next() {
+            Some(mat) => {
+                let begin = self.last_index;
+                let end = mat.end();
+                self.last_index = end;
+                Some(&self.input[begin..end])
+            }
+            None => {
+                let last = self.last_index;
+                let len = self.input.len();
+                if last < len {
+                    self.last_index = len;
+                    Some(&self.input[last..len])
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+/// Rust implementation of Python's [`str.isupper()`][ref]. True if there is at least one cased
+/// character and every cased character is uppercase.
+///
+/// [ref]: https://docs.python.org/3/library/stdtypes.html#str.isupper
+///
+/// Reference: https://github.com/RustPython/RustPython/pull/1577
+pub fn isupper(text: &str) -> bool {
+    let mut cased = false;
+    for c in text.chars() {
+        if is_cased(c) && c.is_uppercase() {
+            cased = true
+        } else if is_cased(c) && c.is_lowercase() {
+            return false;
+        }
+    }
+    cased
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_splitlines_keepends() {
+        let splitter = SplitLines::new();
+
+        let input = "x\nx\rx\r\nx\x0bx\x0cx\x1cx\x1dx\x1ex\u{85}x\u{2028}x\u{2029}";
+        let output = [
+            "x\n",
+            "x\r",
+            "x\r\n",
+            "x\x0b",
+            "x\x0c",
+            "x\x1c",
+            "x\x1d",
+            "x\x1e",
+            "x\u{85}",
+            "x\u{2028}",
+            "x\u{2029}",
+        ];
+        assert_eq!(
+            splitter.splitlines_keepends(input).collect::<Vec<_>>(),
+            output
+        );
+
+        let input = "\n\na";
+        let output = ["\n", "\n", "a"];
+        assert_eq!(
+            splitter.splitlines_keepends(input).collect::<Vec<_>>(),
+            output
+        );
+    }
+
+    #[test]
+    fn test_isupper() {
+        assert!(!isupper("abc"));
+        assert!(!isupper("123"));
+        assert!(isupper("A_B"));
+        assert!(!isupper("a_b"));
+        assert!(isupper("A1"));
+        assert!(isupper("1A"));
+        assert!(!isupper("a1"));
+        assert!(!isupper("1a"));
+        assert!(!isupper("가나다a"));
+        assert!(isupper("가나다A"));
+    }
+
+    #[test]
+    fn test_splitlines() {
+        let splitter = SplitLines::new();
+        let lines: Vec<_> = splitter.splitlines("\n\na\r\nb\x0b").collect();
+        assert_eq!(lines, vec!["", "", "a", "b"]);
+        assert_eq!(splitter.splitlines("").count(), 0);
+    }
+}
@@ -0,0 +1,175 @@
+//! Transparent decompression of inputs, so that compressed corpora can be segmented without an
+//! external decompression step. The format is detected from the first bytes of the input, and
+//! each format is behind a feature of the same name: `gzip`, `xz` and `zstd`. Uncompressed
+//! inputs are passed through as they are.
+//!
+//! ```rust
+//! use std::sync::Arc;
+//!
+//! use pragmatic_segmenter::{channel, compression, Segmenter};
+//!
+//! let input = compression::decompress(&b"Hi Mr. Kim. Bye."[..])?;
+//! let segmenter = Arc::new(Segmenter::new()?);
+//! let texts = channel::spawn(segmenter, input, 16)
+//!     .into_iter()
+//!     .map(|segment| segment.map(|segment| segment.text))
+//!     .collect::<Result<Vec<_>, _>>()?;
+//! assert_eq!(texts, vec!["Hi Mr. Kim. ", "Bye."]);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+/// Compression format of an input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    None,
+    Gzip,
+    Xz,
+    Zstd,
+}
+
+impl Compression {
+    /// Detect the format from the first bytes of an input. At least 6 bytes are needed to tell
+    /// every format apart.
+    pub fn detect(bytes: &[u8]) -> Self {
+        if bytes.starts_with(&[0x1f, 0x8b]) {
+            Compression::Gzip
+        } else if bytes.starts_with(&[0xfd, b'7', b'z', b'X', b'Z', 0x00]) {
+            Compression::Xz
+        } else if bytes.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+            Compression::Zstd
+        } else {
+            Compression::None
+        }
+    }
+
+    /// Usual file extension of the format, like "gz". `None` for uncompressed inputs.
+    pub fn extension(self) -> Option<&'static str> {
+        match self {
+            Compression::None => None,
+            Compression::Gzip => Some("gz"),
+            Compression::Xz => Some("xz"),
+            Compression::Zstd => Some("zst"),
+        }
+    }
+}
+
+/// Wrap `reader` with a decoder of its compression format. Fails with
+/// [`io::ErrorKind::Unsupported`] if the format is detected, but its feature is disabled.
+pub fn decompress<'a>(
+    mut reader: impl BufRead + Send + 'a,
+) -> io::Result<Box<dyn BufRead + Send + 'a>> {
+    let compression = Compression::detect(reader.fill_buf()?);
+    match compression {
+        Compression::None => Ok(Box::new(reader)),
+        #[cfg(feature = "gzip")]
+        Compression::Gzip => Ok(Box::new(BufReader::new(
+            flate2::bufread::MultiGzDecoder::new(reader),
+        ))),
+        #[cfg(feature = "xz")]
+        Compression::Xz => Ok(Box::new(BufReader::new(
+            xz2::bufread::XzDecoder::new_multi_decoder(reader),
+        ))),
+        #[cfg(feature = "zstd")]
+        Compression::Zstd => Ok(Box::new(BufReader::new(
+            zstd::stream::read::Decoder::with_buffer(reader)?,
+        ))),
+        #[allow(unreachable_patterns)]
+        _ => {
+            let feature = match compression {
+                Compression::Gzip => "gzip",
+                Compression::Xz => "xz",
+                _ => "zstd",
+            };
+            Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!(
+                    "{:?} compression requires the `{}` feature",
+                    compression, feature
+                ),
+            ))
+        }
+    }
+}
+
+/// Open the file at `path` and [`decompress()`] it.
+pub fn open(path: impl AsRef<Path>) -> io::Result<Box<dyn BufRead + Send>> {
+    decompress(BufReader::new(File::open(path)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn read(bytes: &[u8]) -> io::Result<String> {
+        let mut text = String::new();
+        decompress(bytes)?.read_to_string(&mut text)?;
+        Ok(text)
+    }
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(Compression::detect(b"\x1f\x8b\x08"), Compression::Gzip);
+        assert_eq!(Compression::detect(b"\xfd7zXZ\x00\x00"), Compression::Xz);
+        assert_eq!(Compression::detect(b"\x28\xb5\x2f\xfd"), Compression::Zstd);
+        assert_eq!(Compression::detect(b"\xfd7zX"), Compression::None);
+        assert_eq!(Compression::detect(b""), Compression::None);
+    }
+
+    #[test]
+    fn test_decompress() -> io::Result<()> {
+        assert_eq!(read(b"Hi. Bye.")?, "Hi. Bye.");
+        assert_eq!(read(b"")?, "");
+        #[cfg(not(feature = "zstd"))]
+        {
+            let err = read(b"\x28\xb5\x2f\xfd\x00").unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+            assert_eq!(
+                err.to_string(),
+                "Zstd compression requires the `zstd` feature"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "gzip")]
+    fn test_gzip() -> io::Result<()> {
+        use std::io::Write;
+
+        // 여러 멤버를 이어붙인 파일도 끝까지 읽어야 한다.
+        let text = "Hi Mr. Kim. Bye.\n".repeat(100);
+        let mut compressed = Vec::new();
+        for _ in 0..2 {
+            let mut encoder = flate2::write::GzEncoder::new(Vec::new(), Default::default());
+            encoder.write_all(text.as_bytes())?;
+            compressed.extend(encoder.finish()?);
+        }
+        assert_eq!(read(&compressed)?, text.repeat(2));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "xz")]
+    fn test_xz() -> io::Result<()> {
+        use std::io::Write;
+
+        let text = "Hi Mr. Kim. Bye.\n".repeat(100);
+        let mut encoder = xz2::write::XzEncoder::new(Vec::new(), 6);
+        encoder.write_all(text.as_bytes())?;
+        assert_eq!(read(&encoder.finish()?)?, text);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "zstd")]
+    fn test_zstd() -> io::Result<()> {
+        let text = "Hi Mr. Kim. Bye.\n".repeat(100);
+        assert_eq!(read(&zstd::encode_all(text.as_bytes(), 0)?)?, text);
+        Ok(())
+    }
+}
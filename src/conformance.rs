@@ -0,0 +1,609 @@
+//! Harness which checks a [`Segmenter`] against a corpus of expected segmentations, usually
+//! generated with pySBD.
+//!
+//! A corpus is a pair of line-oriented files. Each line of the inputs file is a document, and the
+//! same line of the outputs file holds its expected sentences separated by tabs. Backslashes,
+//! tabs and line breaks inside documents and sentences are escaped as `\\`, `\t`, `\n` and `\r`.
+//!
+//! Corpora of each language live in `{dir}/{language}/inputs.xz` and
+//! `{dir}/{language}/outputs.xz`, and can be loaded with [`load()`] when the `xz` feature is
+//! enabled.
+//!
+//! Gold-standard corpora of other formats can be checked as well, by reading them with
+//! [`read_sentence_per_line()`] for OPUS-style files of one sentence per line, or with
+//! [`read_conllu()`] for Universal Dependencies treebanks. Their documents are usually long, so
+//! the [precision](Report::precision) and [recall](Report::recall) of the boundaries tell more
+//! than the ratio of documents segmented perfectly.
+//!
+//! ```rust
+//! use pragmatic_segmenter::{conformance, Segmenter};
+//!
+//! let inputs = "Hi Mr. Kim. Let's meet.\n";
+//! let outputs = "Hi Mr. Kim.\tLet's meet.\n";
+//! let cases = conformance::read_cases(inputs.as_bytes(), outputs.as_bytes())?;
+//!
+//! let report = conformance::run(&Segmenter::new()?, &cases);
+//! assert_eq!(report.score(), 1.0);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::io::{self, BufRead};
+use std::mem;
+#[cfg(feature = "xz")]
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use crate::parallel::{Executor, Threads};
+use crate::Segmenter;
+
+/// A document and its expected sentences.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Case {
+    pub input: String,
+    pub expected: Vec<String>,
+}
+
+/// A case whose sentences differ from the expected ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    /// Line number of the case in the corpus, starting from 0.
+    pub index: usize,
+    pub expected: Vec<String>,
+    pub actual: Vec<String>,
+}
+
+/// Result of [`run()`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Report {
+    pub total: usize,
+    pub failures: Vec<Failure>,
+    /// Number of boundaries between the expected sentences of every case.
+    pub expected_boundaries: usize,
+    /// Number of boundaries between the segments of every case.
+    pub actual_boundaries: usize,
+    /// Number of boundaries which are both expected and actual. A boundary is identified by the
+    /// number of non-whitespace characters before it, so whitespaces around sentences don't
+    /// matter.
+    pub correct_boundaries: usize,
+}
+
+impl Report {
+    /// Number of cases which passed.
+    pub fn passed(&self) -> usize {
+        self.total - self.failures.len()
+    }
+
+    /// Ratio of cases which passed, from 0 to 1. An empty corpus scores 1.
+    pub fn score(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.passed() as f64 / self.total as f64
+        }
+    }
+
+    /// Ratio of the actual boundaries which are expected, from 0 to 1. Scores 1 if there's no
+    /// actual boundary.
+    pub fn precision(&self) -> f64 {
+        ratio(self.correct_boundaries, self.actual_boundaries)
+    }
+
+    /// Ratio of the expected boundaries which are found, from 0 to 1. Scores 1 if there's no
+    /// expected boundary.
+    pub fn recall(&self) -> f64 {
+        ratio(self.correct_boundaries, self.expected_boundaries)
+    }
+
+    /// Harmonic mean of [`precision()`](Report::precision) and [`recall()`](Report::recall).
+    pub fn f1(&self) -> f64 {
+        let (precision, recall) = (self.precision(), self.recall());
+        if precision + recall == 0.0 {
+            0.0
+        } else {
+            2.0 * precision * recall / (precision + recall)
+        }
+    }
+
+    /// Add the boundaries and the failure of a case.
+    fn add(&mut self, (counts, failure): (Counts, Option<Failure>)) {
+        self.expected_boundaries += counts.expected;
+        self.actual_boundaries += counts.actual;
+        self.correct_boundaries += counts.correct;
+        self.failures.extend(failure);
+    }
+}
+
+fn ratio(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        1.0
+    } else {
+        count as f64 / total as f64
+    }
+}
+
+/// Boundaries of a single case.
+#[derive(Debug, Clone, Copy)]
+struct Counts {
+    expected: usize,
+    actual: usize,
+    correct: usize,
+}
+
+impl Counts {
+    fn new(expected: &[&str], actual: &[&str]) -> Self {
+        let expected = boundaries(expected);
+        let actual = boundaries(actual);
+        Counts {
+            expected: expected.len(),
+            actual: actual.len(),
+            correct: expected
+                .iter()
+                .filter(|offset| actual.binary_search(offset).is_ok())
+                .count(),
+        }
+    }
+}
+
+/// Number of non-whitespace characters before every boundary between `sentences`, sorted.
+fn boundaries(sentences: &[&str]) -> Vec<usize> {
+    let mut chars = 0;
+    let mut boundaries: Vec<_> = sentences
+        .iter()
+        .map(|sentence| {
+            chars += sentence.chars().filter(|c| !c.is_whitespace()).count();
+            chars
+        })
+        .collect();
+    boundaries.pop();
+    // 빈 문장이 있으면 같은 경계가 두번 나온다.
+    boundaries.dedup();
+    boundaries
+}
+
+/// Read a corpus from its inputs and outputs files.
+pub fn read_cases(inputs: impl BufRead, outputs: impl BufRead) -> io::Result<Vec<Case>> {
+    let mut cases = Vec::new();
+    let mut outputs = outputs.lines();
+    for input in inputs.lines() {
+        let output = outputs.next().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "outputs has fewer lines than inputs",
+            )
+        })??;
+        cases.push(Case {
+            input: unescape(&input?),
+            expected: output.split('\t').map(unescape).collect(),
+        });
+    }
+    if outputs.next().is_some() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "outputs has more lines than inputs",
+        ));
+    }
+    Ok(cases)
+}
+
+/// Read a corpus of one sentence per line, like the Moses files of OPUS. A line which starts with
+/// `<doc` or is `</doc>` separates documents, and so does a blank line. Every document becomes a
+/// case whose input is its sentences joined with a space.
+///
+/// ```rust
+/// use pragmatic_segmenter::{conformance, Segmenter};
+///
+/// let corpus = "\
+/// <doc id=\"1\">
+/// The Hon. Judge Lee ruled.
+/// It was fair.
+/// </doc>
+/// <doc id=\"2\">
+/// It is 3 P.M. now.
+/// </doc>
+/// ";
+/// let cases = conformance::read_sentence_per_line(corpus.as_bytes())?;
+/// assert_eq!(cases.len(), 2);
+/// assert_eq!(cases[0].input, "The Hon. Judge Lee ruled. It was fair.");
+///
+/// let report = conformance::run(&Segmenter::new()?, &cases);
+/// assert_eq!(report.passed(), 1);
+/// assert_eq!((report.expected_boundaries, report.actual_boundaries), (1, 2));
+/// assert_eq!(report.precision(), 0.5);
+/// assert_eq!(report.recall(), 1.0);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn read_sentence_per_line(reader: impl BufRead) -> io::Result<Vec<Case>> {
+    let mut cases = Vec::new();
+    let mut sentences: Vec<String> = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with("<doc") || line == "</doc>" {
+            if !sentences.is_empty() {
+                cases.push(Case {
+                    input: sentences.join(" "),
+                    expected: mem::take(&mut sentences),
+                });
+            }
+        } else {
+            sentences.push(line.to_string());
+        }
+    }
+    if !sentences.is_empty() {
+        cases.push(Case {
+            input: sentences.join(" "),
+            expected: sentences,
+        });
+    }
+    Ok(cases)
+}
+
+/// Read the sentences of a treebank in the CoNLL-U format of Universal Dependencies. Every
+/// document started with a `# newdoc` comment becomes a case, or the whole file if there's
+/// none. The input is rebuilt from the `# text` comments, or from the tokens if a sentence has
+/// none. Sentences are joined with a space unless the last token has `SpaceAfter=No`, and
+/// paragraphs started with `# newpar` are joined with a line break.
+///
+/// ```rust
+/// use pragmatic_segmenter::conformance;
+///
+/// let corpus = "\
+/// # newdoc id = a
+/// # newpar
+/// # text = Hi Mr. Kim.
+/// 1\tHi\thi\tINTJ\t_\t_\t0\troot\t_\t_
+/// 2\tMr.\tMr.\tPROPN\t_\t_\t3\tflat\t_\t_
+/// 3\tKim\tKim\tPROPN\t_\t_\t1\tvocative\t_\tSpaceAfter=No
+/// 4\t.\t.\tPUNCT\t_\t_\t1\tpunct\t_\t_
+///
+/// 1\tBye\tbye\tINTJ\t_\t_\t0\troot\t_\tSpaceAfter=No
+/// 2\t!\t!\tPUNCT\t_\t_\t1\tpunct\t_\t_
+///
+/// ";
+/// let cases = conformance::read_conllu(corpus.as_bytes())?;
+/// assert_eq!(cases[0].input, "Hi Mr. Kim. Bye!");
+/// assert_eq!(cases[0].expected, vec!["Hi Mr. Kim.", "Bye!"]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn read_conllu(reader: impl BufRead) -> io::Result<Vec<Case>> {
+    let mut cases = Vec::new();
+    let mut document = ConlluDocument::default();
+    let mut sentence = ConlluSentence::default();
+    for line in reader.lines() {
+        let line = line?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            document.push(mem::take(&mut sentence));
+        } else if let Some(comment) = line.strip_prefix('#') {
+            let comment = comment.trim();
+            if comment.starts_with("newdoc") {
+                cases.extend(mem::take(&mut document).finish());
+            } else if comment.starts_with("newpar") {
+                document.separator = "\n";
+            } else if let Some(text) = comment.strip_prefix("text =") {
+                sentence.text = Some(text.trim().to_string());
+            }
+        } else {
+            sentence.token(line);
+        }
+    }
+    document.push(sentence);
+    cases.extend(document.finish());
+    Ok(cases)
+}
+
+/// Sentences of a CoNLL-U document read so far.
+#[derive(Default)]
+struct ConlluDocument {
+    input: String,
+    expected: Vec<String>,
+    /// What comes before the next sentence.
+    separator: &'static str,
+}
+
+impl ConlluDocument {
+    fn push(&mut self, sentence: ConlluSentence) {
+        let space_after = sentence.space_after;
+        let text = match sentence.text.or(sentence.tokens) {
+            Some(text) => text,
+            None => return,
+        };
+        if !self.input.is_empty() {
+            self.input += self.separator;
+        }
+        self.input += &text;
+        self.expected.push(text);
+        self.separator = if space_after { " " } else { "" };
+    }
+
+    fn finish(self) -> Option<Case> {
+        if self.expected.is_empty() {
+            return None;
+        }
+        Some(Case {
+            input: self.input,
+            expected: self.expected,
+        })
+    }
+}
+
+/// A CoNLL-U sentence read so far.
+struct ConlluSentence {
+    /// `# text` comment.
+    text: Option<String>,
+    /// Text rebuilt from the tokens.
+    tokens: Option<String>,
+    /// Whether the last token is followed by a space.
+    space_after: bool,
+    /// Last word of the multiword token read last.
+    covered: usize,
+}
+
+impl Default for ConlluSentence {
+    fn default() -> Self {
+        ConlluSentence {
+            text: None,
+            tokens: None,
+            space_after: true,
+            covered: 0,
+        }
+    }
+}
+
+impl ConlluSentence {
+    /// Read the line of a word, or of a multiword token like "3-4".
+    fn token(&mut self, line: &str) {
+        let columns: Vec<_> = line.split('\t').collect();
+        if columns.len() < 10 {
+            return;
+        }
+        // NOTE: "3-4"처럼 여러 단어로 이루어진 토큰이 원문의 글자를 갖고, 그 안의 단어들은
+        // 원문에 그대로 나타나지 않는다. "3.1" 같은 빈 노드도 원문에 없다.
+        match columns[0].split_once('-') {
+            Some((_, last)) => match last.parse() {
+                Ok(last) => self.covered = last,
+                Err(_) => return,
+            },
+            None => match columns[0].parse::<usize>() {
+                Ok(id) if id > self.covered => {}
+                _ => return,
+            },
+        }
+        let tokens = self.tokens.get_or_insert_with(String::new);
+        if self.space_after && !tokens.is_empty() {
+            tokens.push(' ');
+        }
+        tokens.push_str(columns[1]);
+        self.space_after = !columns[9].split('|').any(|misc| misc == "SpaceAfter=No");
+    }
+}
+
+/// Load the xz-compressed corpus of a language from `{dir}/{language}/`.
+#[cfg(feature = "xz")]
+pub fn load(dir: impl AsRef<Path>, language: &str) -> io::Result<Vec<Case>> {
+    use std::fs::File;
+    use std::io::BufReader;
+    use xz2::read::XzDecoder;
+
+    let dir = dir.as_ref().join(language);
+    let open = |name: &str| -> io::Result<_> {
+        Ok(BufReader::new(XzDecoder::new(File::open(dir.join(name))?)))
+    };
+    read_cases(open("inputs.xz")?, open("outputs.xz")?)
+}
+
+/// Segment every case and compare the sentences, ignoring leading and trailing whitespaces.
+pub fn run(segmenter: &Segmenter, cases: &[Case]) -> Report {
+    let mut report = Report {
+        total: cases.len(),
+        ..Report::default()
+    };
+    for (index, case) in cases.iter().enumerate() {
+        report.add(check(segmenter, index, case));
+    }
+    report
+}
+
+/// Same as [`run()`], but the cases are checked on as many threads as there are CPUs. Large
+/// corpora of language packs can be checked in a fraction of the time, and the failures are
+/// in the order of the cases all the same.
+///
+/// ```rust
+/// use pragmatic_segmenter::{conformance, Segmenter};
+///
+/// let inputs = "Hi Mr. Kim. Bye.\nIt is 3 P.M. now.\n".repeat(100);
+/// let outputs = "Hi Mr. Kim.\tBye.\nIt is 3 P.M.\tnow.\n".repeat(100);
+/// let cases = conformance::read_cases(inputs.as_bytes(), outputs.as_bytes())?;
+///
+/// let report = conformance::run_parallel(&Segmenter::new()?, &cases);
+/// assert_eq!(report.total, 200);
+/// assert_eq!(report.failures.len(), 100);
+/// assert_eq!(report.failures[0].index, 1);
+/// assert_eq!(report.failures[0].actual, vec!["It is 3 P.M. now."]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn run_parallel(segmenter: &Segmenter, cases: &[Case]) -> Report {
+    let next = AtomicUsize::new(0);
+    let report = Mutex::new(Report {
+        total: cases.len(),
+        ..Report::default()
+    });
+    Threads(0).run(&|| {
+        // 케이스마다 락을 잡지 않도록, 각 스레드가 모은 결과를 마지막에 한번에 넘긴다.
+        let mut local = Report::default();
+        loop {
+            let index = next.fetch_add(1, Ordering::Relaxed);
+            let case = match cases.get(index) {
+                Some(case) => case,
+                None => break,
+            };
+            local.add(check(segmenter, index, case));
+        }
+        // NOTE: 다른 스레드가 패닉하지 않는 한 실패하지 않는다.
+        let mut report = report.lock().unwrap();
+        report.expected_boundaries += local.expected_boundaries;
+        report.actual_boundaries += local.actual_boundaries;
+        report.correct_boundaries += local.correct_boundaries;
+        report.failures.append(&mut local.failures);
+    });
+
+    let mut report = report.into_inner().unwrap(); // NOTE: 위와 같은 이유로 실패하지 않는다.
+    report
+        .failures
+        .sort_unstable_by_key(|failure| failure.index);
+    report
+}
+
+fn check(segmenter: &Segmenter, index: usize, case: &Case) -> (Counts, Option<Failure>) {
+    let actual: Vec<_> = segmenter
+        .segment(&case.input)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect();
+    let expected: Vec<_> = case.expected.iter().map(|s| s.trim()).collect();
+    let counts = Counts::new(&expected, &actual);
+    if actual == expected {
+        return (counts, None);
+    }
+    let failure = Failure {
+        index,
+        expected: case.expected.clone(),
+        actual: actual.into_iter().map(String::from).collect(),
+    };
+    (counts, Some(failure))
+}
+
+/// Escape a document or a sentence so that it fits in a line of a corpus.
+pub fn escape(s: &str) -> String {
+    s.replace('\\', r"\\")
+        .replace('\t', r"\t")
+        .replace('\n', r"\n")
+        .replace('\r', r"\r")
+}
+
+fn unescape(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => result.push('\t'),
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        let s = "a\\tb\tc\nd\r";
+        assert_eq!(escape(s), r"a\\tb\tc\nd\r");
+        assert_eq!(unescape(&escape(s)), s);
+    }
+
+    #[test]
+    fn test_read_cases() -> io::Result<()> {
+        let cases = read_cases(&b"A. B.\nC\\nD\n"[..], &b"A.\tB.\nC\\nD\n"[..])?;
+        assert_eq!(
+            cases,
+            vec![
+                Case {
+                    input: "A. B.".into(),
+                    expected: vec!["A.".into(), "B.".into()],
+                },
+                Case {
+                    input: "C\nD".into(),
+                    expected: vec!["C\nD".into()],
+                },
+            ]
+        );
+        assert!(read_cases(&b"A.\nB.\n"[..], &b"A.\n"[..]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_conllu() -> io::Result<()> {
+        let corpus = "\
+# newdoc id = a
+# newpar
+# sent_id = 1
+1-2\tdel\t_\t_\t_\t_\t_\t_\t_\tSpaceAfter=No
+1\tde\tde\tADP\t_\t_\t0\troot\t_\t_
+2\tel\tel\tDET\t_\t_\t1\tdet\t_\t_
+3\t.\t.\tPUNCT\t_\t_\t1\tpunct\t_\tSpaceAfter=No
+
+# text = Sí.
+1\tSí\tsí\tINTJ\t_\t_\t0\troot\t_\tSpaceAfter=No
+2\t.\t.\tPUNCT\t_\t_\t1\tpunct\t_\t_
+
+# newpar
+# text = Fin.
+1\tFin\tfin\tNOUN\t_\t_\t0\troot\t_\t_
+
+# newdoc id = b
+# text = Otro.
+1\tOtro\totro\tPRON\t_\t_\t0\troot\t_\t_
+";
+        let cases = read_conllu(corpus.as_bytes())?;
+        assert_eq!(
+            cases,
+            vec![
+                Case {
+                    input: "del.Sí.\nFin.".into(),
+                    expected: vec!["del.".into(), "Sí.".into(), "Fin.".into()],
+                },
+                Case {
+                    input: "Otro.".into(),
+                    expected: vec!["Otro.".into()],
+                },
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_boundaries() {
+        assert_eq!(boundaries(&["Hi Mr. Kim.", "", "Bye.", "Ok."]), vec![9, 13]);
+        assert_eq!(boundaries(&[]), Vec::<usize>::new());
+
+        let counts = Counts::new(&["A b.", "C.", "D."], &["A b. C.", "D."]);
+        assert_eq!((counts.expected, counts.actual, counts.correct), (2, 1, 1));
+    }
+
+    #[test]
+    fn test_run_parallel() -> Result<(), Box<dyn std::error::Error>> {
+        let segmenter = Segmenter::new()?;
+        let cases: Vec<_> = (0..100)
+            .map(|i| Case {
+                input: format!("Doc {}. It is Mr. Kim's. Bye.", i),
+                expected: match i % 3 {
+                    0 => vec![
+                        format!("Doc {}.", i),
+                        "It is Mr. Kim's.".into(),
+                        "Bye.".into(),
+                    ],
+                    _ => vec![format!("Doc {}. It is Mr. Kim's. Bye.", i)],
+                },
+            })
+            .collect();
+        let report = run_parallel(&segmenter, &cases);
+        assert_eq!(report, run(&segmenter, &cases));
+        assert_eq!(report.failures.len(), 66);
+        assert_eq!(report.expected_boundaries, 68);
+        assert_eq!(report.actual_boundaries, 200);
+        assert_eq!(report.correct_boundaries, 68);
+        assert_eq!(run_parallel(&segmenter, &[]), Report::default());
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use std::ops::Range;
+
+use onig::Regex;
+
+use crate::util::re;
+
+/// Datelines of news wire articles like "WASHINGTON (Reuters) - " or "PARIS, May 3 (AP) — ",
+/// enabled by [`SegmenterBuilder::datelines()`](crate::SegmenterBuilder::datelines).
+pub(crate) struct Datelines {
+    regex: Regex,
+}
+
+impl Datelines {
+    pub fn new() -> Result<Self, onig::Error> {
+        // 도시 이름은 모두 대문자로 쓰고, 뒤에 지역이나 날짜, 통신사 이름이 올 수 있다. 대시는 꼭
+        // 있어야 하고, 대시 뒤에는 문장이 시작되어야 한다.
+        let city = r"\p{Lu}[\p{Lu}.'’-]*\p{Lu}\.?(?:[ \t]\p{Lu}[\p{Lu}.'’-]*\p{Lu}\.?){0,3}";
+        let detail = r"(?:,[ \t][\p{L}\d.]+(?:[ \t][\p{L}\d.]+){0,3}){0,3}";
+        let agency = r"(?:[ \t](?:\([\p{L}\d&.' /-]{1,30}\)|/[\p{L}\d&.' -]{1,30}/))?";
+        Ok(Datelines {
+            regex: re(&format!(
+                r#"(?:\A|(?<=\n\n))[ \t]*({city}{detail}{agency}[ \t]*(?:--?|—|–)[ \t]+)(?=[\p{{Lu}}\d"“‘'(])"#,
+                city = city,
+                detail = detail,
+                agency = agency,
+            ))?,
+        })
+    }
+
+    /// Byte ranges of the datelines which start a paragraph, including the dash and whitespaces
+    /// after it.
+    pub fn find(&self, text: &str) -> Vec<Range<usize>> {
+        self.regex
+            .captures_iter(text)
+            .filter_map(|c| c.pos(1))
+            .map(|(start, end)| start..end)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() -> Result<(), onig::Error> {
+        let datelines = Datelines::new()?;
+        let find = |text| -> Vec<_> {
+            datelines
+                .find(text)
+                .into_iter()
+                .map(|range| &text[range])
+                .collect()
+        };
+        assert_eq!(
+            find("WASHINGTON (Reuters) - The talks ended."),
+            vec!["WASHINGTON (Reuters) - "]
+        );
+        assert_eq!(
+            find("Intro.\n\nPARIS, May 3 (AP) — Markets fell."),
+            vec!["PARIS, May 3 (AP) — "]
+        );
+        assert_eq!(
+            find("ST. LOUIS, Mo. -- Rain fell."),
+            vec!["ST. LOUIS, Mo. -- "]
+        );
+        assert_eq!(find("NEW YORK — Stocks rose."), vec!["NEW YORK — "]);
+        assert_eq!(
+            find("AUSTIN, Texas, Jan. 5, 2021 /PRNewswire/ -- Acme"),
+            vec!["AUSTIN, Texas, Jan. 5, 2021 /PRNewswire/ -- "]
+        );
+        assert!(find("It rained.\nLONDON - Rain.").is_empty());
+        assert!(find("USA - a country.").is_empty());
+        assert!(find("Paris (AP) - Rain.").is_empty());
+        Ok(())
+    }
+}
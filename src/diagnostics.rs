@@ -0,0 +1,196 @@
+//! Checks for suspicious output of [`Segmenter`], so that a service can raise an alert instead of
+//! silently passing bad segments on. The segmentation itself is not changed: every problem found
+//! is reported to a sink, and the segments are returned as usual.
+//!
+//! ```rust
+//! use pragmatic_segmenter::diagnostics::{Diagnostic, Diagnostics};
+//! use pragmatic_segmenter::Segmenter;
+//!
+//! let segmenter = Segmenter::new()?;
+//! let mut found = Vec::new();
+//! let segments = Diagnostics::default().segments(&segmenter, "It costs 5∯ now. Ok.", |d| {
+//!     found.push(d)
+//! });
+//! assert_eq!(segments.len(), 1);
+//! assert_eq!(
+//!     found,
+//!     vec![
+//!         Diagnostic::Placeholder {
+//!             offset: 10,
+//!             character: '∯'
+//!         },
+//!         Diagnostic::Uncovered { start: 0, end: 19 },
+//!     ]
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! To decide what to do with a document before segmenting it, like escaping the placeholders,
+//! rejecting the document or routing it elsewhere, use [`placeholders()`] alone.
+
+use std::time::{Duration, Instant};
+
+use crate::{CoverageError, Segment, Segmenter};
+
+/// Characters which the segmenter uses internally to hide punctuations from the rules. If the
+/// input already contains one, it is turned into a punctuation in the middle of the pipeline,
+/// and the segment it belongs to usually can't be found in the input anymore.
+#[rustfmt::skip]
+pub const PLACEHOLDERS: &[char] = &[
+    '∯', '∮', '♬', '♭', '♨', '☝', 'ᓰ', 'ᓱ', 'ᓳ', 'ᓴ', 'ᓷ', 'ᓸ', '☉', '☇', '☈', '☄', '✂', '⌬',
+    'ȸ', 'ȹ', 'ƪ', '♟', '♝', '☏', '⎋',
+];
+
+/// Byte offset and character of every [`PLACEHOLDERS`] in `text`, in order. Unlike
+/// [`Diagnostics::segments()`], `text` is not segmented.
+///
+/// ```rust
+/// use pragmatic_segmenter::diagnostics::placeholders;
+///
+/// let found: Vec<_> = placeholders("It costs 5∯ now. Ok ♨").collect();
+/// assert_eq!(found, vec![(10, '∯'), (22, '♨')]);
+/// assert_eq!(placeholders("Hi Mr. Kim.").next(), None);
+/// ```
+pub fn placeholders(text: &str) -> impl Iterator<Item = (usize, char)> + '_ {
+    text.char_indices()
+        .filter(|(_, character)| PLACEHOLDERS.contains(character))
+}
+
+/// Problem found by [`Diagnostics::segments()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Diagnostic {
+    /// The input contains a character which the segmenter uses as a placeholder internally, at
+    /// the byte offset `offset`. Text around it is likely to be missing from the output.
+    Placeholder { offset: usize, character: char },
+    /// A segment is longer than [`long_segment()`](Diagnostics::long_segment), which usually
+    /// means that the text around it could not be split at all.
+    LongSegment { start: usize, chars: usize },
+    /// The segmentation took longer than [`time_limit()`](Diagnostics::time_limit). It was not
+    /// interrupted.
+    Slow { elapsed: Duration },
+    /// The byte range `start..end` of the input contains non-whitespace characters, but is not
+    /// covered by any segment.
+    Uncovered { start: usize, end: usize },
+}
+
+/// Configuration of the checks, which defaults to reporting segments longer than 1000 characters
+/// and no time limit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Diagnostics {
+    long_segment: usize,
+    time_limit: Option<Duration>,
+}
+
+impl Default for Diagnostics {
+    fn default() -> Self {
+        Diagnostics {
+            long_segment: 1000,
+            time_limit: None,
+        }
+    }
+}
+
+impl Diagnostics {
+    /// Report segments longer than `chars` characters, including the trailing whitespaces.
+    pub fn long_segment(mut self, chars: usize) -> Self {
+        self.long_segment = chars;
+        self
+    }
+
+    /// Report segmentations of a document which take longer than `limit`.
+    pub fn time_limit(mut self, limit: Duration) -> Self {
+        self.time_limit = Some(limit);
+        self
+    }
+
+    /// Segment `text` with `segmenter` like [`Segmenter::segments()`], and report every problem
+    /// found to `sink`. Placeholders in the input are reported first, then long segments, then
+    /// uncovered ranges like [`Segmenter::checked_segments()`] finds them, each in the order of
+    /// the input, and then the time.
+    pub fn segments<'a>(
+        &self,
+        segmenter: &'a Segmenter,
+        text: &'a str,
+        mut sink: impl FnMut(Diagnostic),
+    ) -> Vec<Segment<'a>> {
+        for (offset, character) in placeholders(text) {
+            sink(Diagnostic::Placeholder { offset, character });
+        }
+
+        let begin = Instant::now();
+        let segments: Vec<_> = segmenter.segments(text).collect();
+        let elapsed = begin.elapsed();
+
+        for segment in &segments {
+            let chars = segment.text.chars().count();
+            if chars > self.long_segment {
+                sink(Diagnostic::LongSegment {
+                    start: segment.start,
+                    chars,
+                });
+            }
+        }
+        if let Err(error) = CoverageError::check(text, &segments) {
+            for range in error.lost {
+                sink(Diagnostic::Uncovered {
+                    start: range.start,
+                    end: range.end,
+                });
+            }
+        }
+
+        if self.time_limit.is_some_and(|limit| elapsed > limit) {
+            sink(Diagnostic::Slow { elapsed });
+        }
+        segments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnose(diagnostics: &Diagnostics, text: &str) -> Vec<Diagnostic> {
+        let segmenter = Segmenter::new().unwrap();
+        let mut found = Vec::new();
+        diagnostics.segments(&segmenter, text, |d| found.push(d));
+        found
+    }
+
+    #[test]
+    fn test_segments() {
+        let diagnostics = Diagnostics::default().long_segment(20);
+        assert!(diagnose(&diagnostics, "Hi Mr. Kim. Bye.\n\n").is_empty());
+        assert_eq!(
+            diagnose(&diagnostics, "Short. This one is much too long. Ok."),
+            vec![Diagnostic::LongSegment {
+                start: 7,
+                chars: 27
+            }]
+        );
+        assert_eq!(
+            diagnose(&diagnostics, "He said ♨ hi."),
+            vec![
+                Diagnostic::Placeholder {
+                    offset: 8,
+                    character: '♨'
+                },
+                Diagnostic::Uncovered { start: 0, end: 15 },
+            ]
+        );
+
+        let diagnostics = Diagnostics::default().time_limit(Duration::ZERO);
+        assert!(matches!(
+            diagnose(&diagnostics, "Hi.").as_slice(),
+            [Diagnostic::Slow { .. }]
+        ));
+    }
+
+    #[test]
+    fn test_placeholders() {
+        let found: Vec<_> = placeholders("It&⎋&s ∯∮ fine.").collect();
+        assert_eq!(found, vec![(3, '⎋'), (9, '∯'), (12, '∮')]);
+    }
+}
@@ -0,0 +1,234 @@
+//! Comparison of two segmentations of a document, like two versions of an edited text.
+//! Sentences are compared by their text without the trailing whitespaces, so a sentence is not
+//! reported as changed only because it became the last one of the document.
+//!
+//! ```rust
+//! use pragmatic_segmenter::diff::{compare, Change};
+//! use pragmatic_segmenter::Segmenter;
+//!
+//! let segmenter = Segmenter::new()?;
+//! let old: Vec<_> = segmenter.segments("Hi Mr. Kim. See you at 3. Bye.").collect();
+//! let new: Vec<_> = segmenter.segments("Hi Mr. Kim. See you at 4. Take care. Bye.").collect();
+//!
+//! let changes: Vec<_> = compare(&old, &new)
+//!     .into_iter()
+//!     .filter_map(|change| match change {
+//!         Change::Unchanged { .. } => None,
+//!         Change::Changed { old, new } => Some(format!("~ {} -> {}", old.text, new.text)),
+//!         Change::Added(new) => Some(format!("+ {}", new.text)),
+//!         Change::Removed(old) => Some(format!("- {}", old.text)),
+//!     })
+//!     .collect();
+//! assert_eq!(changes, vec!["~ See you at 3.  -> See you at 4. ", "+ Take care. "]);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::Segment;
+
+/// How a sentence differs between the old and the new segmentation, returned by [`compare()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change<'a, 'b> {
+    /// The sentence is in both segmentations.
+    Unchanged { old: Segment<'a>, new: Segment<'b> },
+    /// The sentence of the old segmentation was replaced with the one of the new segmentation.
+    Changed { old: Segment<'a>, new: Segment<'b> },
+    /// The sentence is only in the old segmentation.
+    Removed(Segment<'a>),
+    /// The sentence is only in the new segmentation.
+    Added(Segment<'b>),
+}
+
+/// Align two segmentations by their longest common subsequence of sentences, and report how
+/// every sentence changed, in the order of the documents. Where some sentences were removed and
+/// others were added in their place, they are paired up in order as
+/// [`Changed`](Change::Changed), and the rest are [`Removed`](Change::Removed) or
+/// [`Added`](Change::Added).
+///
+/// Time is proportional to the product of the numbers of sentences and memory to their sum,
+/// excluding the common sentences at the start and at the end.
+pub fn compare<'a, 'b>(old: &[Segment<'a>], new: &[Segment<'b>]) -> Vec<Change<'a, 'b>> {
+    let old_keys: Vec<_> = old.iter().map(|segment| segment.text.trim_end()).collect();
+    let new_keys: Vec<_> = new.iter().map(|segment| segment.text.trim_end()).collect();
+    let prefix = old_keys
+        .iter()
+        .zip(&new_keys)
+        .take_while(|(o, n)| o == n)
+        .count();
+    let suffix = old_keys[prefix..]
+        .iter()
+        .rev()
+        .zip(new_keys[prefix..].iter().rev())
+        .take_while(|(o, n)| o == n)
+        .count();
+    let old_end = old.len() - suffix;
+    let new_end = new.len() - suffix;
+
+    let mut matches = Vec::new();
+    common(
+        &old_keys[prefix..old_end],
+        &new_keys[prefix..new_end],
+        (prefix, prefix),
+        &mut matches,
+    );
+
+    let mut changes: Vec<_> = old[..prefix]
+        .iter()
+        .zip(&new[..prefix])
+        .map(|(&old, &new)| Change::Unchanged { old, new })
+        .collect();
+    let mut removed = Vec::new();
+    let mut added = Vec::new();
+    let (mut i, mut j) = (prefix, prefix);
+    for (next_i, next_j) in matches.into_iter().chain(Some((old_end, new_end))) {
+        removed.extend_from_slice(&old[i..next_i]);
+        added.extend_from_slice(&new[j..next_j]);
+        flush(&mut changes, &mut removed, &mut added);
+        if next_i < old_end {
+            changes.push(Change::Unchanged {
+                old: old[next_i],
+                new: new[next_j],
+            });
+        }
+        (i, j) = (next_i + 1, next_j + 1);
+    }
+    changes.extend(
+        old[old_end..]
+            .iter()
+            .zip(&new[new_end..])
+            .map(|(&old, &new)| Change::Unchanged { old, new }),
+    );
+    changes
+}
+
+/// Push the index pairs of a longest common subsequence of `old` and `new` to `matches` in
+/// order, shifted by `offset`. It is Hirschberg's algorithm, which splits `old` in half and
+/// finds where the subsequence crosses the middle with two rows of the table at a time.
+fn common(old: &[&str], new: &[&str], offset: (usize, usize), matches: &mut Vec<(usize, usize)>) {
+    if old.is_empty() || new.is_empty() {
+        return;
+    }
+    if let [sentence] = old {
+        if let Some(j) = new.iter().position(|other| other == sentence) {
+            matches.push((offset.0, offset.1 + j));
+        }
+        return;
+    }
+
+    let mid = old.len() / 2;
+    let forward = lengths(old[..mid].iter(), new.iter());
+    let backward = lengths(old[mid..].iter().rev(), new.iter().rev());
+    // NOTE: 동점이면 앞쪽을 골라, 지워진 문장이 추가된 문장보다 먼저 나오게 한다.
+    let split = (0..=new.len())
+        .max_by_key(|&k| (forward[k] + backward[new.len() - k], usize::MAX - k))
+        .unwrap(); // Must exist
+    common(&old[..mid], &new[..split], offset, matches);
+    common(
+        &old[mid..],
+        &new[split..],
+        (offset.0 + mid, offset.1 + split),
+        matches,
+    );
+}
+
+/// Last row of the table of longest common subsequences: the length for `old` and every prefix
+/// of `new`, with a single row of memory.
+fn lengths<'s>(
+    old: impl Iterator<Item = &'s &'s str>,
+    new: impl Iterator<Item = &'s &'s str> + Clone,
+) -> Vec<usize> {
+    let mut row = vec![0; new.clone().count() + 1];
+    for sentence in old {
+        let mut diagonal = 0;
+        for (j, other) in new.clone().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if sentence == other {
+                diagonal + 1
+            } else {
+                above.max(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row
+}
+
+/// Pair up the sentences removed and added between two unchanged sentences.
+fn flush<'a, 'b>(
+    changes: &mut Vec<Change<'a, 'b>>,
+    removed: &mut Vec<Segment<'a>>,
+    added: &mut Vec<Segment<'b>>,
+) {
+    let paired = removed.len().min(added.len());
+    changes.extend(
+        removed
+            .iter()
+            .zip(added.iter())
+            .map(|(&old, &new)| Change::Changed { old, new }),
+    );
+    changes.extend(removed[paired..].iter().map(|&old| Change::Removed(old)));
+    changes.extend(added[paired..].iter().map(|&new| Change::Added(new)));
+    removed.clear();
+    added.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Kind;
+
+    fn segments(texts: &[&'static str]) -> Vec<Segment<'static>> {
+        let mut start = 0;
+        texts
+            .iter()
+            .map(|&text| {
+                start += text.len();
+                Segment {
+                    text,
+                    start: start - text.len(),
+                    kind: Kind::Sentence,
+                    speaker: None,
+                    overlong: false,
+                }
+            })
+            .collect()
+    }
+
+    fn summary(old: &[&'static str], new: &[&'static str]) -> Vec<String> {
+        compare(&segments(old), &segments(new))
+            .into_iter()
+            .map(|change| match change {
+                Change::Unchanged { old, .. } => format!("={}", old.text.trim_end()),
+                Change::Changed { old, new } => {
+                    format!("~{}>{}", old.text.trim_end(), new.text.trim_end())
+                }
+                Change::Removed(old) => format!("-{}", old.text.trim_end()),
+                Change::Added(new) => format!("+{}", new.text.trim_end()),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(
+            summary(
+                &["A. ", "B. ", "C. ", "D."],
+                &["A. ", "X. ", "C. ", "D. ", "E."]
+            ),
+            vec!["=A.", "~B.>X.", "=C.", "=D.", "+E."]
+        );
+        assert_eq!(
+            summary(&["A. ", "B. ", "C. ", "D."], &["B. ", "Y. ", "Z. ", "D."]),
+            vec!["-A.", "=B.", "~C.>Y.", "+Z.", "=D."]
+        );
+        assert_eq!(summary(&["A."], &[]), vec!["-A."]);
+        assert_eq!(summary(&[], &["A."]), vec!["+A."]);
+        assert!(summary(&[], &[]).is_empty());
+        assert_eq!(
+            summary(
+                &["A. ", "B. ", "C. ", "D. ", "E. ", "F. ", "G."],
+                &["X. ", "C. ", "B. ", "E. ", "Y. ", "G. ", "F."]
+            ),
+            vec!["~A.>X.", "-B.", "=C.", "~D.>B.", "=E.", "~F.>Y.", "=G.", "+F."]
+        );
+    }
+}
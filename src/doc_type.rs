@@ -0,0 +1,250 @@
+//! Detection of the kind of a document, to segment each document of a mixed corpus with the
+//! options which suit it. [`detect()`] looks at the lines of a document and tells whether it is
+//! prose, text extracted from a PDF, a list or a chat log, along with the counts it decided on,
+//! so that the decision can be audited later. [`AutoSegmenter`] segments a document with the
+//! options of its kind.
+//!
+//! ```rust
+//! use pragmatic_segmenter::doc_type::{AutoSegmenter, DocType};
+//! use pragmatic_segmenter::Segmenter;
+//!
+//! let segmenter = AutoSegmenter::new(Segmenter::builder())?;
+//! let pdf = "The court held that the contract\nwas void, and the claim of\nthe seller failed. It\nappealed.";
+//! let result = segmenter.segments(pdf);
+//! assert_eq!(result.detection.doc_type, DocType::Pdf);
+//! let texts: Vec<_> = result.segments.iter().map(|segment| segment.text).collect();
+//! assert_eq!(
+//!     texts,
+//!     vec![
+//!         "The court held that the contract\nwas void, and the claim of\nthe seller failed. ",
+//!         "It\nappealed."
+//!     ]
+//! );
+//!
+//! let chat = "hey\nu there?\nlol ok\nsee u at 5";
+//! assert_eq!(segmenter.segments(chat).detection.doc_type, DocType::Chat);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::error::Error;
+
+use crate::{Preset, Segment, Segmenter, SegmenterBuilder};
+
+/// Kind of a document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DocType {
+    /// Paragraphs of sentences, segmented with the options as they are.
+    Prose,
+    /// Text extracted from a PDF, whose lines are wrapped in the middle of sentences. Segmented
+    /// with [`join_wrapped_lines()`](SegmenterBuilder::join_wrapped_lines).
+    Pdf,
+    /// Mostly list items, segmented with [`colon_lists()`](SegmenterBuilder::colon_lists) and
+    /// [`numerals_in_parens_in_any_context()`](SegmenterBuilder::numerals_in_parens_in_any_context).
+    List,
+    /// Short messages which are rarely capitalized or punctuated, segmented with
+    /// [`Preset::Chat`].
+    Chat,
+}
+
+impl DocType {
+    /// Every kind, in the order [`detect()`] considers them.
+    pub const ALL: [DocType; 4] = [DocType::List, DocType::Pdf, DocType::Chat, DocType::Prose];
+
+    /// Apply the options of this kind to `builder`.
+    pub fn configure(self, builder: SegmenterBuilder) -> SegmenterBuilder {
+        match self {
+            DocType::Prose => builder,
+            DocType::Pdf => builder.join_wrapped_lines(true),
+            DocType::List => builder
+                .colon_lists(true)
+                .numerals_in_parens_in_any_context(true),
+            DocType::Chat => builder.preset(Preset::Chat),
+        }
+    }
+}
+
+/// Result of [`detect()`]: the kind of a document, and the counts of lines it was decided on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Detection {
+    /// Kind of the document.
+    pub doc_type: DocType,
+    /// Number of lines which are not blank.
+    pub lines: usize,
+    /// Number of lines which start with a list marker like "-", "•", "1." or "(a)".
+    pub list_items: usize,
+    /// Number of lines which end in the middle of a sentence, and are followed by a line of the
+    /// same paragraph which starts with a lowercase letter.
+    pub wrapped: usize,
+    /// Number of short lines which start with a lowercase letter or end without a punctuation.
+    pub messages: usize,
+}
+
+/// Lines of at least this many characters can be wrapped.
+const MIN_WRAPPED_LINE: usize = 25;
+
+/// Lines of at most this many characters can be chat messages.
+const MAX_MESSAGE: usize = 60;
+
+/// Tell the kind of `text` from its lines. A document of fewer than three lines is prose. Then a
+/// third of list items makes a list, a quarter of wrapped lines makes a PDF, and a half of
+/// messages makes a chat log, in this order.
+///
+/// ```rust
+/// use pragmatic_segmenter::doc_type::{detect, DocType};
+///
+/// let detection = detect("Shopping:\n- eggs\n- milk\n- bread\n");
+/// assert_eq!(detection.doc_type, DocType::List);
+/// assert_eq!((detection.lines, detection.list_items), (4, 3));
+/// assert_eq!(detect("Hi Mr. Kim. Let's meet at 3 P.M.").doc_type, DocType::Prose);
+/// ```
+pub fn detect(text: &str) -> Detection {
+    let mut detection = Detection {
+        doc_type: DocType::Prose,
+        lines: 0,
+        list_items: 0,
+        wrapped: 0,
+        messages: 0,
+    };
+    let mut lines = text.lines().map(str::trim).peekable();
+    while let Some(line) = lines.next() {
+        if line.is_empty() {
+            continue;
+        }
+        detection.lines += 1;
+        let ended = line.ends_with(|c: char| ".!?:;…".contains(c));
+        if is_list_item(line) {
+            detection.list_items += 1;
+        } else if !ended
+            && line.chars().count() >= MIN_WRAPPED_LINE
+            && lines
+                .peek()
+                .is_some_and(|next| next.starts_with(char::is_lowercase))
+        {
+            detection.wrapped += 1;
+        } else if line.chars().count() <= MAX_MESSAGE
+            && (line.starts_with(char::is_lowercase) || !ended)
+        {
+            detection.messages += 1;
+        }
+    }
+
+    let lines = detection.lines;
+    detection.doc_type = if lines < 3 {
+        DocType::Prose
+    } else if detection.list_items * 3 >= lines {
+        DocType::List
+    } else if detection.wrapped * 4 >= lines {
+        DocType::Pdf
+    } else if detection.messages * 2 >= lines {
+        DocType::Chat
+    } else {
+        DocType::Prose
+    };
+    detection
+}
+
+/// Whether `line` starts with a bullet, or a number, letter or roman numeral of up to four
+/// characters followed by "." or ")", or in parentheses.
+fn is_list_item(line: &str) -> bool {
+    if let Some(rest) = line.strip_prefix(['-', '*', '•', '‣', '◦', '▪']) {
+        return rest.starts_with(' ');
+    }
+    let (marker, rest) = match line.strip_prefix('(') {
+        Some(rest) => match rest.split_once(')') {
+            Some(split) => split,
+            None => return false,
+        },
+        None => match line.find(['.', ')']) {
+            Some(idx) => (&line[..idx], &line[idx + 1..]),
+            None => return false,
+        },
+    };
+    !marker.is_empty()
+        && marker.chars().count() <= 4
+        && marker.chars().all(|c| c.is_ascii_alphanumeric())
+        && (marker.len() == 1
+            || marker
+                .chars()
+                .all(|c| c.is_ascii_digit() || "ivxIVX".contains(c)))
+        && rest.starts_with(' ')
+}
+
+/// Segmenter which detects the kind of each document, and segments it with the options of the
+/// kind on top of the options of a builder. See the [module documentation](self).
+pub struct AutoSegmenter {
+    /// One segmenter for each of [`DocType::ALL`].
+    segmenters: Vec<(DocType, Segmenter)>,
+}
+
+/// Result of [`AutoSegmenter::segments()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Detected<'a> {
+    /// How the kind of the document was decided.
+    pub detection: Detection,
+    /// Segments of the document, like [`Segmenter::segments()`].
+    pub segments: Vec<Segment<'a>>,
+}
+
+impl AutoSegmenter {
+    /// Build a segmenter for each kind of document from `builder`.
+    pub fn new(builder: SegmenterBuilder) -> Result<Self, Box<dyn Error>> {
+        let segmenters = DocType::ALL
+            .iter()
+            .map(|&doc_type| Ok((doc_type, doc_type.configure(builder.clone()).build()?)))
+            .collect::<Result<_, Box<dyn Error>>>()?;
+        Ok(AutoSegmenter { segmenters })
+    }
+
+    /// Segmenter used for documents of `doc_type`.
+    pub fn segmenter(&self, doc_type: DocType) -> &Segmenter {
+        // NOTE: DocType::ALL의 모든 종류에 대해 만들어두었으므로 항상 있다.
+        &self
+            .segmenters
+            .iter()
+            .find(|(kind, _)| *kind == doc_type)
+            .unwrap()
+            .1
+    }
+
+    /// Detect the kind of `text` with [`detect()`], and segment it with the segmenter of the
+    /// kind.
+    pub fn segments<'a>(&'a self, text: &'a str) -> Detected<'a> {
+        let detection = detect(text);
+        Detected {
+            detection,
+            segments: self.segmenter(detection.doc_type).segments(text).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        let kinds = [
+            ("", DocType::Prose),
+            ("Hi Mr. Kim.\nLet's meet.\nSee you.", DocType::Prose),
+            ("Steps\n1. Open it.\n2. Close it.\n(iv) Done.", DocType::List),
+            ("• Red\n• Green\nNot an item.", DocType::List),
+            ("i told u\nwe r late\nomg\nok!", DocType::Chat),
+            (
+                "This section describes how the\nparties agree to the terms and\nconditions of this deal.",
+                DocType::Pdf,
+            ),
+        ];
+        for (text, doc_type) in kinds {
+            assert_eq!(detect(text).doc_type, doc_type, "{:?}", text);
+        }
+
+        assert!(is_list_item("a) apples"));
+        assert!(is_list_item("12. twelve"));
+        assert!(!is_list_item("Mr. Kim came."));
+        assert!(!is_list_item("e.g. this"));
+        assert!(!is_list_item("-5 degrees"));
+    }
+}
@@ -0,0 +1,51 @@
+//! Naive sentence splitter which works for any language, without regular expressions or
+//! abbreviation lists. A sentence ends at a terminal punctuation followed by whitespace and a
+//! letter which is not lowercase, so "Mr. Kim" is split while "e.g. this" is not. CJK full stops
+//! like "。" end a sentence without whitespace.
+//!
+//! It is much faster than [`Segmenter`](crate::Segmenter) and never fails, which makes it a
+//! baseline to compare the full pipeline against. Unlike the rest of the crate, it builds without
+//! std, see [`lite`](crate::lite).
+//!
+//! ```rust
+//! use pragmatic_segmenter::{fallback, Segmenter};
+//!
+//! let text = "Hi Mr. Kim. Let's meet at 3 p.m. today.";
+//! let result: Vec<_> = fallback::split(text).collect();
+//! assert_eq!(result, vec!["Hi Mr. ", "Kim. ", "Let's meet at 3 p.m. today."]);
+//!
+//! let segmenter = Segmenter::new()?;
+//! let result: Vec<_> = segmenter.segment(text).collect();
+//! assert_eq!(result, vec!["Hi Mr. Kim. ", "Let's meet at 3 p.m. today."]);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+pub use crate::lite::split;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sentences(text: &str) -> Vec<&str> {
+        split(text).collect()
+    }
+
+    #[test]
+    fn test_split() {
+        assert_eq!(
+            sentences("Is it 3.14? \"Yes.\" (Sure.) Ok"),
+            vec!["Is it 3.14? ", "\"Yes.\" ", "(Sure.) ", "Ok"]
+        );
+        assert_eq!(
+            sentences("See e.g. this one. 5 more. ¿Qué?"),
+            vec!["See e.g. this one. 5 more. ", "¿Qué?"]
+        );
+        assert_eq!(
+            sentences("안녕하세요. 반갑습니다."),
+            vec!["안녕하세요. ", "반갑습니다."]
+        );
+        assert_eq!(sentences("你好。我很好！"), vec!["你好。", "我很好！"]);
+        assert_eq!(sentences("Wait...  Why?!\n"), vec!["Wait...  ", "Why?!\n"]);
+        assert_eq!(sentences(""), Vec::<&str>::new());
+    }
+}
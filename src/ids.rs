@@ -0,0 +1,180 @@
+//! Stable identifiers of segments, for incremental pipelines which re-process documents and
+//! need to know which segments are new. An identifier is a hash of the document id, the span
+//! of the segment and its text, computed with the same function as
+//! [`Segmenter::fingerprint()`](crate::Segmenter::fingerprint), so it never changes between
+//! runs, processes or platforms.
+//!
+//! ```rust
+//! use pragmatic_segmenter::{ids, Segmenter};
+//!
+//! let segmenter = Segmenter::new()?;
+//! let old = ids::assign("doc-1", segmenter.segments("Hi Mr. Kim. See you. Bye."));
+//! let new = ids::assign("doc-1", segmenter.segments("Hi Mr. Kim. Bye. See you."));
+//!
+//! let diff = ids::diff(&old, &new);
+//! assert_eq!(diff.unchanged, vec![old[0]]);
+//! assert_eq!(diff.moved, vec![(old[2], new[1]), (old[1], new[2])]);
+//! assert!(diff.removed.is_empty() && diff.added.is_empty());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::util::fnv1a;
+use crate::Segment;
+
+/// Identifier of a segment in a document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SegmentId {
+    id: u64,
+    content: u64,
+}
+
+impl SegmentId {
+    /// Identifier of `segment` in the document whose id is `document`. Two segments have the
+    /// same identifier if they are in the same document, have the same span and the same text.
+    pub fn new(document: &str, segment: &Segment) -> Self {
+        let content = fnv1a(segment.text.trim_end().as_bytes());
+        // 플랫폼마다 usize의 크기가 다르므로 u64로 바꿔서 해싱한다.
+        let mut bytes = Vec::with_capacity(document.len() + segment.text.len() + 17);
+        bytes.extend_from_slice(document.as_bytes());
+        bytes.push(0);
+        bytes.extend_from_slice(&(segment.start as u64).to_le_bytes());
+        bytes.extend_from_slice(&(segment.text.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(segment.text.as_bytes());
+        SegmentId {
+            id: fnv1a(&bytes),
+            content,
+        }
+    }
+
+    /// The identifier as a number.
+    pub fn value(self) -> u64 {
+        self.id
+    }
+
+    /// Hash of the text of the segment only, without the trailing whitespaces. Segments with
+    /// the same text have the same content hash wherever they are.
+    pub fn content_hash(self) -> u64 {
+        self.content
+    }
+}
+
+/// 16 hexadecimal digits of [`value()`](SegmentId::value).
+impl fmt::Display for SegmentId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.id)
+    }
+}
+
+/// Identifiers of `segments` of the document whose id is `document`, in order.
+pub fn assign<'a>(
+    document: &str,
+    segments: impl IntoIterator<Item = Segment<'a>>,
+) -> Vec<SegmentId> {
+    segments
+        .into_iter()
+        .map(|segment| SegmentId::new(document, &segment))
+        .collect()
+}
+
+/// Difference between two sets of segment identifiers, returned by [`diff()`]. Every list is
+/// in the order of the segmentation it comes from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct IdDiff {
+    /// Segments which are in both, with the same span and text.
+    pub unchanged: Vec<SegmentId>,
+    /// Segments whose text is the same but whose span changed, as pairs of the old and the new
+    /// identifiers. Results computed from the text alone can be reused for them.
+    pub moved: Vec<(SegmentId, SegmentId)>,
+    /// Segments which are only in the old segmentation.
+    pub removed: Vec<SegmentId>,
+    /// Segments which are only in the new segmentation.
+    pub added: Vec<SegmentId>,
+}
+
+/// Compare the identifiers of an old and a new segmentation of a document. A segment of the old
+/// segmentation is paired with at most one segment of the new one.
+pub fn diff(old: &[SegmentId], new: &[SegmentId]) -> IdDiff {
+    let old_set: HashSet<_> = old.iter().collect();
+    let new_set: HashSet<_> = new.iter().collect();
+    let mut result = IdDiff::default();
+
+    // 같은 내용의 문장이 여러번 나오면 앞에서부터 차례대로 짝짓는다.
+    let mut moved_from: HashMap<u64, Vec<SegmentId>> = HashMap::new();
+    for id in old.iter().rev().filter(|id| !new_set.contains(id)) {
+        moved_from.entry(id.content).or_default().push(*id);
+    }
+    let mut paired = HashSet::new();
+    for &id in new {
+        if old_set.contains(&id) {
+            result.unchanged.push(id);
+        } else if let Some(from) = moved_from.get_mut(&id.content).and_then(Vec::pop) {
+            paired.insert(from);
+            result.moved.push((from, id));
+        } else {
+            result.added.push(id);
+        }
+    }
+    result.removed = old
+        .iter()
+        .filter(|id| !new_set.contains(id) && !paired.contains(id))
+        .copied()
+        .collect();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Kind;
+
+    fn segment(text: &str, start: usize) -> Segment<'_> {
+        Segment {
+            text,
+            start,
+            kind: Kind::Sentence,
+            speaker: None,
+            overlong: false,
+        }
+    }
+
+    #[test]
+    fn test_segment_id() {
+        let id = SegmentId::new("doc", &segment("Hi. ", 0));
+        assert_eq!(id, SegmentId::new("doc", &segment("Hi. ", 0)));
+        assert_ne!(id, SegmentId::new("doc2", &segment("Hi. ", 0)));
+        assert_ne!(id, SegmentId::new("doc", &segment("Hi. ", 4)));
+        assert_ne!(id, SegmentId::new("doc", &segment("Ho. ", 0)));
+        assert_ne!(id, SegmentId::new("doc", &segment("Hi.\n", 0)));
+        assert_eq!(
+            id.content_hash(),
+            SegmentId::new("doc2", &segment("Hi.", 9)).content_hash()
+        );
+        assert_eq!(id.to_string().len(), 16);
+    }
+
+    #[test]
+    fn test_diff() {
+        let ids = |texts: &[&'static str]| {
+            let mut start = 0;
+            let segments: Vec<_> = texts
+                .iter()
+                .map(|text| {
+                    start += text.len();
+                    segment(text, start - text.len())
+                })
+                .collect();
+            assign("doc", segments)
+        };
+        let old = ids(&["A. ", "B. ", "A. ", "C."]);
+        let new = ids(&["A. ", "A. ", "D. ", "C."]);
+        let diff = diff(&old, &new);
+        assert_eq!(diff.unchanged, vec![old[0], old[3]]);
+        assert_eq!(diff.moved, vec![(old[2], new[1])]);
+        assert_eq!(diff.removed, vec![old[1]]);
+        assert_eq!(diff.added, vec![new[2]]);
+    }
+}
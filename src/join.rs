@@ -0,0 +1,118 @@
+//! Reconstruction of text from a subset of segments, possibly in a different order, like the
+//! sentences picked by an extractive summarizer. Concatenating the segments as they are glues
+//! the last sentence of a document to the next one, and keeps line breaks of the input in the
+//! middle of the result.
+//!
+//! ```rust
+//! use pragmatic_segmenter::join::{join, Style};
+//! use pragmatic_segmenter::Segmenter;
+//!
+//! let segmenter = Segmenter::new()?;
+//! let segments: Vec<_> = segmenter
+//!     .segments("Results\nThe model wins. It is fast. See the table.")
+//!     .collect();
+//! let summary = [segments[3], segments[1], segments[0]];
+//! assert_eq!(
+//!     join(&summary, Style::Prose),
+//!     "See the table. The model wins. Results."
+//! );
+//! assert_eq!(
+//!     join(&summary, Style::Lines),
+//!     "See the table.\nThe model wins.\nResults"
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::lite::{CLOSINGS, TERMINALS};
+use crate::Segment;
+
+/// How [`join()`] puts segments together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Separate segments with a single space. A segment which doesn't end with a terminal
+    /// punctuation gets a period, or a trailing ",", ";" or ":" becomes a period, so that a
+    /// heading or the introduction of a list doesn't run into the next sentence.
+    Prose,
+    /// Put every segment on its own line, as it is.
+    Lines,
+}
+
+/// Join `segments` in the given order. Whitespaces around each segment are removed, and every
+/// run of whitespaces inside a segment becomes a single space. Empty segments are skipped.
+pub fn join(segments: &[Segment], style: Style) -> String {
+    let mut joined = String::new();
+    for segment in segments {
+        let mut words = segment.text.split_whitespace().peekable();
+        if words.peek().is_none() {
+            continue;
+        }
+        if !joined.is_empty() {
+            joined.push(match style {
+                Style::Prose => ' ',
+                Style::Lines => '\n',
+            });
+        }
+        let start = joined.len();
+        for (idx, word) in words.enumerate() {
+            if idx > 0 {
+                joined.push(' ');
+            }
+            joined.push_str(word);
+        }
+        if style == Style::Prose {
+            terminate(&mut joined, start);
+        }
+    }
+    joined
+}
+
+/// Make the sentence which starts at `start` of `text` end with a terminal punctuation.
+fn terminate(text: &mut String, start: usize) {
+    let sentence = &text[start..];
+    let body = sentence.trim_end_matches(CLOSINGS);
+    match body.chars().next_back() {
+        Some(c) if TERMINALS.contains(&c) => {}
+        Some(c @ (',' | ';' | ':')) => {
+            let at = start + body.len() - c.len_utf8();
+            text.replace_range(at..at + 1, ".");
+        }
+        // "(see above)" -> "(see above)."
+        _ => text.push('.'),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Kind;
+
+    fn segments(texts: &[&'static str]) -> Vec<Segment<'static>> {
+        texts
+            .iter()
+            .map(|&text| Segment {
+                text,
+                start: 0,
+                kind: Kind::Sentence,
+                speaker: None,
+                overlong: false,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_join() {
+        let prose = |texts: &[&'static str]| join(&segments(texts), Style::Prose);
+        assert_eq!(prose(&["Bye.", "Hi.\n\n"]), "Bye. Hi.");
+        assert_eq!(prose(&["Choose one:\n", "Red."]), "Choose one. Red.");
+        assert_eq!(
+            prose(&["He said \"wow!\" ", "(See above) "]),
+            "He said \"wow!\" (See above)."
+        );
+        assert_eq!(prose(&["It is\n  long. ", " ", "Ok?"]), "It is long. Ok?");
+        assert_eq!(prose(&[]), "");
+        assert_eq!(
+            join(&segments(&["1. Red\n", "Bye. "]), Style::Lines),
+            "1. Red\nBye."
+        );
+    }
+}
@@ -0,0 +1,228 @@
+//! Writer of segments as JSON Lines, one record per sentence:
+//! `{"text":...,"start":...,"end":...}`, where `start` and `end` are byte offsets in the input.
+//! The records are written as soon as the segments come, so together with
+//! [`Segmenter::segments()`](crate::Segmenter::segments) or [`channel`](crate::channel), a large
+//! input is turned into JSON Lines without ever holding it whole in memory.
+//!
+//! The output is buffered. By default it is flushed only when the buffer is full and when the
+//! writer is finished, and [`flush_every()`](JsonlWriter::flush_every) flushes it after every
+//! few records instead, for consumers which read the output while it is written.
+//!
+//! ```rust
+//! use pragmatic_segmenter::jsonl::JsonlWriter;
+//! use pragmatic_segmenter::Segmenter;
+//!
+//! let segmenter = Segmenter::new()?;
+//! let mut writer = JsonlWriter::new(Vec::new());
+//! writer.write_segments(segmenter.segments("Hi Mr. Kim. \"Bye.\""))?;
+//! assert_eq!(writer.records(), 2);
+//! let output = writer.finish()?;
+//! assert_eq!(
+//!     String::from_utf8(output)?,
+//!     concat!(
+//!         "{\"text\":\"Hi Mr. Kim. \",\"start\":0,\"end\":12}\n",
+//!         "{\"text\":\"\\\"Bye.\\\"\",\"start\":12,\"end\":18}\n",
+//!     )
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::io::{self, BufWriter, Write};
+
+use crate::channel::OwnedSegment;
+use crate::Segment;
+
+/// Buffered writer of segments as JSON Lines. See the [module documentation](self).
+#[derive(Debug)]
+pub struct JsonlWriter<W: Write> {
+    out: BufWriter<W>,
+    /// Number of records written so far
+    records: usize,
+    /// Number of records after which the output is flushed, or 0 to flush it only when the
+    /// buffer is full
+    flush_every: usize,
+}
+
+impl<W: Write> JsonlWriter<W> {
+    /// Writer to `inner` with the default buffer size of [`BufWriter`].
+    pub fn new(inner: W) -> Self {
+        Self::from_buf_writer(BufWriter::new(inner))
+    }
+
+    /// Writer to `inner` with a buffer of at least `capacity` bytes.
+    pub fn with_capacity(capacity: usize, inner: W) -> Self {
+        Self::from_buf_writer(BufWriter::with_capacity(capacity, inner))
+    }
+
+    fn from_buf_writer(out: BufWriter<W>) -> Self {
+        JsonlWriter {
+            out,
+            records: 0,
+            flush_every: 0,
+        }
+    }
+
+    /// Flush the output after every `records` records. 0, the default, flushes it only when the
+    /// buffer is full.
+    pub fn flush_every(mut self, records: usize) -> Self {
+        self.flush_every = records;
+        self
+    }
+
+    /// Write a record of `text` which starts at the byte offset `start` of the input.
+    pub fn write(&mut self, text: &str, start: usize) -> io::Result<()> {
+        self.write_with_fields(text, start, &[])
+    }
+
+    /// Write a record like [`write()`](JsonlWriter::write), followed by the string fields of
+    /// `fields`, like the name of the file which the text comes from.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::jsonl::JsonlWriter;
+    ///
+    /// let mut writer = JsonlWriter::new(Vec::new());
+    /// writer.write_with_fields("Hi. ", 0, &[("file", "a.txt")])?;
+    /// assert_eq!(
+    ///     String::from_utf8(writer.finish()?)?,
+    ///     "{\"text\":\"Hi. \",\"start\":0,\"end\":4,\"file\":\"a.txt\"}\n"
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_with_fields(
+        &mut self,
+        text: &str,
+        start: usize,
+        fields: &[(&str, &str)],
+    ) -> io::Result<()> {
+        self.out.write_all(br#"{"text":"#)?;
+        write_string(&mut self.out, text)?;
+        write!(
+            self.out,
+            r#","start":{},"end":{}"#,
+            start,
+            start + text.len()
+        )?;
+        for (name, value) in fields {
+            self.out.write_all(b",")?;
+            write_string(&mut self.out, name)?;
+            self.out.write_all(b":")?;
+            write_string(&mut self.out, value)?;
+        }
+        self.out.write_all(b"}\n")?;
+        self.records += 1;
+        if self.flush_every != 0 && self.records.is_multiple_of(self.flush_every) {
+            self.out.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Write a record of `segment`.
+    pub fn write_segment(&mut self, segment: &Segment) -> io::Result<()> {
+        self.write(segment.text, segment.start)
+    }
+
+    /// Write a record of a segment received from [`channel::spawn()`](crate::channel::spawn).
+    pub fn write_owned(&mut self, segment: &OwnedSegment) -> io::Result<()> {
+        self.write(&segment.text, segment.start)
+    }
+
+    /// Write a record of every segment of `segments`, in order, and return how many were
+    /// written.
+    pub fn write_segments<'a>(
+        &mut self,
+        segments: impl IntoIterator<Item = Segment<'a>>,
+    ) -> io::Result<usize> {
+        let before = self.records;
+        for segment in segments {
+            self.write_segment(&segment)?;
+        }
+        Ok(self.records - before)
+    }
+
+    /// Number of records written so far.
+    pub fn records(&self) -> usize {
+        self.records
+    }
+
+    /// Write out the buffered records.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+
+    /// Flush the output and return the underlying writer. Dropping the writer flushes it as
+    /// well, but ignores the errors.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.out.flush()?;
+        self.out.into_inner().map_err(io::IntoInnerError::into_error)
+    }
+}
+
+/// Write `s` as a JSON string literal.
+pub fn write_string(out: &mut impl Write, s: &str) -> io::Result<()> {
+    out.write_all(b"\"")?;
+    let mut last = 0;
+    for (idx, c) in s.char_indices() {
+        let escaped = match c {
+            '"' => "\\\"",
+            '\\' => "\\\\",
+            '\n' => "\\n",
+            '\r' => "\\r",
+            '\t' => "\\t",
+            c if (c as u32) < 0x20 => "",
+            _ => continue,
+        };
+        out.write_all(&s.as_bytes()[last..idx])?;
+        if escaped.is_empty() {
+            write!(out, "\\u{:04x}", c as u32)?;
+        } else {
+            out.write_all(escaped.as_bytes())?;
+        }
+        last = idx + c.len_utf8();
+    }
+    out.write_all(&s.as_bytes()[last..])?;
+    out.write_all(b"\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writer which records the length of the output at every flush.
+    #[derive(Default)]
+    struct Flushes {
+        written: Vec<u8>,
+        flushed: Vec<usize>,
+    }
+
+    impl Write for Flushes {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.written.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.flushed.push(self.written.len());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_flush_every() -> io::Result<()> {
+        let mut writer = JsonlWriter::new(Flushes::default()).flush_every(2);
+        writer.write("a\n", 0)?;
+        assert_eq!(writer.out.get_ref().written.len(), 0);
+        writer.write("b\u{1}", 2)?;
+        writer.write("c", 4)?;
+        let out = writer.finish()?;
+        assert_eq!(
+            String::from_utf8(out.written).unwrap(),
+            concat!(
+                "{\"text\":\"a\\n\",\"start\":0,\"end\":2}\n",
+                "{\"text\":\"b\\u0001\",\"start\":2,\"end\":4}\n",
+                "{\"text\":\"c\",\"start\":4,\"end\":5}\n",
+            )
+        );
+        assert_eq!(out.flushed, vec![70, 101]);
+        Ok(())
+    }
+}
@@ -0,0 +1,193 @@
+use std::error::Error;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+use crate::SegmenterBuilder;
+
+mod arabic;
+mod chinese;
+mod french;
+mod german;
+mod hindi;
+mod japanese;
+mod kazakh;
+mod marathi;
+mod persian;
+mod polish;
+mod portuguese;
+mod russian;
+mod spanish;
+
+pub use arabic::Arabic;
+pub use chinese::Chinese;
+pub use french::French;
+pub use german::German;
+pub use hindi::Hindi;
+pub use japanese::Japanese;
+pub use kazakh::Kazakh;
+pub use marathi::Marathi;
+pub use persian::Persian;
+pub use polish::Polish;
+pub use portuguese::Portuguese;
+pub use russian::Russian;
+pub use spanish::Spanish;
+
+/// Rules of a language. A language configures a [`SegmenterBuilder`] with its abbreviations,
+/// letters and patterns.
+///
+/// Languages which don't live in this crate can be made available by name with [`register()`]:
+///
+/// ```rust
+/// use pragmatic_segmenter::{AbbreviationKind, AbbreviationPack, Language, Segmenter};
+/// use pragmatic_segmenter::{SegmenterBuilder, UppercaseLetters};
+///
+/// struct Esperanto;
+///
+/// impl Language for Esperanto {
+///     fn code(&self) -> &str {
+///         "eo"
+///     }
+///
+///     fn configure(&self, builder: SegmenterBuilder) -> SegmenterBuilder {
+///         let pack = AbbreviationPack::new("eo").with("s-ro", AbbreviationKind::Prepositive);
+///         builder
+///             .uppercase_letters(UppercaseLetters::Unicode)
+///             .abbreviations(pack)
+///     }
+/// }
+///
+/// pragmatic_segmenter::register(Esperanto);
+///
+/// let segmenter = Segmenter::builder().language("eo")?.build()?;
+/// let result: Vec<_> = segmenter.segment("Mi vidis s-ro. Zamenhof. Ĉu vi?").collect();
+/// assert_eq!(result, vec!["Mi vidis s-ro. Zamenhof. ", "Ĉu vi?"]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub trait Language: Send + Sync {
+    /// Language code, usually ISO 639-1 like `"en"`.
+    fn code(&self) -> &str;
+
+    /// Apply the rules of this language to the builder.
+    fn configure(&self, builder: SegmenterBuilder) -> SegmenterBuilder;
+}
+
+/// English, the default. It uses the rules of pySBD as is.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct English;
+
+impl Language for English {
+    fn code(&self) -> &str {
+        "en"
+    }
+
+    fn configure(&self, builder: SegmenterBuilder) -> SegmenterBuilder {
+        builder
+    }
+}
+
+static REGISTRY: RwLock<Vec<Arc<dyn Language>>> = RwLock::new(Vec::new());
+
+/// Make a language available by its [code](Language::code) to
+/// [`SegmenterBuilder::language()`]. A language registered later replaces the built-in or
+/// registered one with the same code.
+pub fn register(language: impl Language + 'static) {
+    let mut registry = REGISTRY.write().unwrap_or_else(|err| err.into_inner());
+    registry.retain(|registered| registered.code() != language.code());
+    registry.push(Arc::new(language));
+}
+
+/// Find a language by its code, among the registered and the built-in ones.
+pub fn lookup(code: &str) -> Option<Arc<dyn Language>> {
+    let registry = REGISTRY.read().unwrap_or_else(|err| err.into_inner());
+    if let Some(language) = registry.iter().find(|language| language.code() == code) {
+        return Some(Arc::clone(language));
+    }
+    builtin_languages()
+        .into_iter()
+        .find(|language| language.code() == code)
+}
+
+/// Codes of every available language, sorted.
+pub fn languages() -> Vec<String> {
+    let registry = REGISTRY.read().unwrap_or_else(|err| err.into_inner());
+    let mut codes: Vec<_> = builtin_languages()
+        .iter()
+        .chain(registry.iter())
+        .map(|language| language.code().to_string())
+        .collect();
+    codes.sort();
+    codes.dedup();
+    codes
+}
+
+fn builtin_languages() -> Vec<Arc<dyn Language>> {
+    vec![
+        Arc::new(Arabic),
+        Arc::new(Chinese),
+        Arc::new(English),
+        Arc::new(French),
+        Arc::new(German),
+        Arc::new(Hindi),
+        Arc::new(Japanese),
+        Arc::new(Kazakh),
+        Arc::new(Marathi),
+        Arc::new(Persian),
+        Arc::new(Polish),
+        Arc::new(Portuguese),
+        Arc::new(Russian),
+        Arc::new(Spanish),
+    ]
+}
+
+/// Error returned when a language is neither built in nor [registered](register).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownLanguage(pub String);
+
+impl fmt::Display for UnknownLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown language: {:?}", self.0)
+    }
+}
+
+impl Error for UnknownLanguage {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rule;
+
+    struct Test;
+
+    impl Language for Test {
+        fn code(&self) -> &str {
+            "x-test"
+        }
+
+        fn configure(&self, builder: SegmenterBuilder) -> SegmenterBuilder {
+            builder.lowercase_sentence_starts(true)
+        }
+    }
+
+    #[test]
+    fn test_registry() {
+        assert!(lookup("en").is_some());
+        assert!(lookup("x-test").is_none());
+
+        register(Test);
+        assert_eq!(
+            lookup("x-test").map(|l| l.code().to_string()),
+            Some("x-test".into())
+        );
+        assert!(languages().contains(&"x-test".to_string()));
+        assert!(languages().contains(&"en".to_string()));
+    }
+
+    #[test]
+    fn test_rules() -> Result<(), Box<dyn Error>> {
+        for code in languages() {
+            let segmenter = SegmenterBuilder::new().language(&code)?.build()?;
+            segmenter.rules().try_for_each(Rule::check_examples)?;
+        }
+        Ok(())
+    }
+}
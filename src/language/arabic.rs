@@ -0,0 +1,78 @@
+use crate::{
+    AbbreviationKind, AbbreviationPack, Language, Rule, SegmenterBuilder, UppercaseLetters,
+};
+
+/// Arabic, like `ar` of pySBD. On top of the rules of English, it ends a sentence at "؟" and at
+/// the Arabic full stop "۔", and recognizes Arabic abbreviations like "د." or "ص.ب.". The
+/// Arabic comma "،" and semicolon "؛" never end a sentence.
+///
+/// Arabic script has no capital letters, so any Arabic letter can start a sentence after a
+/// period, like an uppercase letter of Latin script.
+///
+/// ```rust
+/// use pragmatic_segmenter::Segmenter;
+///
+/// let segmenter = Segmenter::builder().language("ar")?.build()?;
+/// let result: Vec<_> = segmenter
+///     .segment("قال د. أحمد إن الاجتماع انتهى. هل حضرت؟ نعم، حضرت.")
+///     .collect();
+/// assert_eq!(
+///     result,
+///     vec!["قال د. أحمد إن الاجتماع انتهى. ", "هل حضرت؟ ", "نعم، حضرت."]
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Arabic;
+
+impl Language for Arabic {
+    fn code(&self) -> &str {
+        "ar"
+    }
+
+    fn configure(&self, builder: SegmenterBuilder) -> SegmenterBuilder {
+        let pack = AbbreviationPack::from_groups(
+            "ar",
+            &[
+                (NUMBER_ABBREVIATIONS, AbbreviationKind::Number),
+                (ABBREVIATIONS, AbbreviationKind::Plain),
+            ],
+        );
+        builder
+            .uppercase_letters(UppercaseLetters::Custom(SENTENCE_STARTS.into()))
+            .abbreviations(AbbreviationPack::honorifics("ar").unwrap()) // Must exist
+            .abbreviations(pack)
+            .terminal_punctuation('؟')
+            .terminal_punctuation('۔')
+            .rule(closing_guillemet_rule())
+    }
+}
+
+/// Uppercase letters of other scripts, and every letter of Arabic script.
+const SENTENCE_STARTS: &str = r"[\p{Lu}\p{Lt}\p{Arabic}&&\p{L}]";
+
+/// Abbreviations which come before a number, like "ص. 12" for a page or "ص.ب. 340" for a post
+/// office box.
+const NUMBER_ABBREVIATIONS: &[&str] = &["ص", "ج", "ص.ب", "ت", "ه.ت"];
+
+/// Abbreviations which may end a sentence, like "إلخ." or "هـ." after a year. Arabic script has
+/// no lowercase letters, so the period after one of these ends a sentence before an Arabic word.
+/// A single letter followed by a period, like "م." after a year, never does, since it is taken
+/// for an initial.
+#[rustfmt::skip]
+const ABBREVIATIONS: &[&str] = &[
+    "إلخ", "الخ", "هـ", "ق.م", "ج.م.ع", "ش.م.م", "ش.م.ع", "كم", "كغ", "سم", "ملم",
+];
+
+/// A sentence which ends inside guillemets, like "«سأعود غداً.» ثم".
+fn closing_guillemet_rule() -> Rule {
+    Rule::builtin(
+        "ArabicClosingGuillemetRule",
+        r"(?<=[.!?؟…])»(?=\s+[\p{Lu}\p{Arabic}])",
+        "»\r",
+    )
+    .with_example("«سأعود غداً.» ثم", "«سأعود غداً.»\r ثم")
+    .with_example("«هل انتهيت؟» قلت", "«هل انتهيت؟»\r قلت")
+    .with_example("«نعم» قال", "«نعم» قال")
+}
+
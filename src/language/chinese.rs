@@ -0,0 +1,85 @@
+use crate::{Language, Rule, SegmenterBuilder, UppercaseLetters};
+
+/// Chinese, like `zh` of pySBD. On top of the rules of English, it ends a sentence at the
+/// full-width "；" and at "…" as well as at "。", "！" and "？", and never splits inside the
+/// full-width quotation marks 「…」 and 『…』, the parentheses （…） and the title marks 《…》.
+///
+/// Chinese has no capital letters and no spaces between sentences, so a sentence which ends
+/// inside quotation marks or parentheses, like 「我明天来。」, ends right after them, whatever
+/// comes next.
+///
+/// ```rust
+/// use pragmatic_segmenter::Segmenter;
+///
+/// let segmenter = Segmenter::builder().language("zh")?.build()?;
+/// let result: Vec<_> = segmenter
+///     .segment("他说：「我明天来。」然后就走了。我们去看《摔跤吧！爸爸》好吗？好！")
+///     .collect();
+/// assert_eq!(
+///     result,
+///     vec!["他说：「我明天来。」", "然后就走了。", "我们去看《摔跤吧！爸爸》好吗？", "好！"]
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Chinese;
+
+impl Language for Chinese {
+    fn code(&self) -> &str {
+        "zh"
+    }
+
+    fn configure(&self, builder: SegmenterBuilder) -> SegmenterBuilder {
+        let builder = builder
+            .uppercase_letters(UppercaseLetters::Custom(SENTENCE_STARTS.into()))
+            .terminal_punctuation('；')
+            .terminal_punctuation('…')
+            .enclosing_pair("「", "」")
+            .enclosing_pair("『", "』")
+            .enclosing_pair("（", "）")
+            .enclosing_pair("《", "》");
+        double_punctuation(builder).rule(closing_quotation_rule())
+    }
+}
+
+/// Rules of full-width punctuations which come in a row like "？！", shared with
+/// [`Japanese`](super::Japanese).
+pub(super) fn double_punctuation(builder: SegmenterBuilder) -> SegmenterBuilder {
+    builder
+        .rule(double_question_mark_rule())
+        .rule(double_exclamation_mark_rule())
+}
+
+/// Uppercase letters of other scripts, and every Han character.
+const SENTENCE_STARTS: &str = r"[\p{Lu}\p{Lt}\p{Han}]";
+
+/// A full-width question mark followed by another terminal, like "什么？！", which doesn't end
+/// the sentence by itself.
+fn double_question_mark_rule() -> Rule {
+    Rule::builtin("FullWidthDoubleQuestionMarkRule", r"？(?=[！？])", "&ᓸ&")
+        .with_example("什么？！你", "什么&ᓸ&！你")
+        .with_example("什么？你", "什么？你")
+}
+
+/// A full-width exclamation mark followed by another terminal, like "太好了！！".
+fn double_exclamation_mark_rule() -> Rule {
+    Rule::builtin("FullWidthDoubleExclamationMarkRule", r"！(?=[！？])", "&ᓳ&")
+        .with_example("太好了！！我", "太好了&ᓳ&！我")
+        .with_example("好！我", "好！我")
+}
+
+/// A sentence which ends inside quotation marks or parentheses, like "「我明天来。」然后". Title
+/// marks like "《摔跤吧！爸爸》" are names rather than sentences, so they never end one.
+fn closing_quotation_rule() -> Rule {
+    // NOTE: 닫는 문장부호가 두 개까지 겹칠 수 있다. 예: 「他说『好。』」
+    Rule::builtin(
+        "ChineseClosingQuotationRule",
+        r"(?<=[。！？；…][」』）”]|[。！？；…][」』）”]{2})(?![」』）”]|\s*\z)",
+        "\r",
+    )
+    .with_example("「我明天来。」然后", "「我明天来。」\r然后")
+    .with_example("「他说『好。』」我", "「他说『好。』」\r我")
+    .with_example("「我明天来」然后", "「我明天来」然后")
+    .with_example("「好。」", "「好。」")
+}
+
@@ -0,0 +1,127 @@
+use crate::{
+    AbbreviationKind, AbbreviationPack, Language, Rule, SegmenterBuilder, UppercaseLetters,
+};
+
+/// French, like `fr` of pySBD. On top of the rules of English, it recognizes French
+/// abbreviations like "Mme", "p. ex." or "av. J.-C.", any uppercase letter of Unicode like "É",
+/// and the spaces which French typography puts inside guillemets and before "?", "!", ":" and
+/// ";", including the non-breaking ones: a sentence ends after "« Non. »" as well as after
+/// "«Non.»".
+///
+/// ```rust
+/// use pragmatic_segmenter::Segmenter;
+///
+/// let segmenter = Segmenter::builder().language("fr")?.build()?;
+/// let result: Vec<_> = segmenter
+///     .segment("Elle a répondu\u{a0}: «\u{a0}Non.\u{a0}» Mme Roy, p. ex., est partie. Et toi\u{202f}?")
+///     .collect();
+/// assert_eq!(
+///     result,
+///     vec![
+///         "Elle a répondu\u{a0}: «\u{a0}Non.\u{a0}» ",
+///         "Mme Roy, p. ex., est partie. ",
+///         "Et toi\u{202f}?"
+///     ]
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct French;
+
+impl Language for French {
+    fn code(&self) -> &str {
+        "fr"
+    }
+
+    fn configure(&self, builder: SegmenterBuilder) -> SegmenterBuilder {
+        let pack = AbbreviationPack::from_groups(
+            "fr",
+            &[
+                (NUMBER_ABBREVIATIONS, AbbreviationKind::Number),
+                (PREPOSITIVE_ABBREVIATIONS, AbbreviationKind::Prepositive),
+                (ABBREVIATIONS, AbbreviationKind::Plain),
+            ],
+        );
+        builder
+            .uppercase_letters(UppercaseLetters::Unicode)
+            .abbreviations(AbbreviationPack::honorifics("fr").unwrap()) // Must exist
+            .abbreviations(pack)
+            .rule(par_exemple_rule())
+            .rule(hyphenated_abbreviation_rule())
+            .rule(elided_number_abbreviation_rule())
+            .rule(closing_guillemet_rule())
+    }
+}
+
+/// Abbreviations which come before a number, like "p. 12" or "chap. 3".
+#[rustfmt::skip]
+const NUMBER_ABBREVIATIONS: &[&str] = &[
+    "art", "chap", "env", "fig", "no", "nos", "p", "pp", "t", "vol", "janv", "févr", "avr",
+    "juil", "sept", "oct", "nov", "déc",
+];
+
+/// Abbreviations which always come before a name or a word, on top of
+/// [`AbbreviationPack::honorifics()`]. "av." is "avenue" or "avant", as in "av. J.-C.".
+#[rustfmt::skip]
+const PREPOSITIVE_ABBREVIATIONS: &[&str] = &[
+    "av", "bd", "boul", "ex", "éd", "vve", "gal", "cdt", "cne", "lt", "col", "sr", "sgt",
+];
+
+#[rustfmt::skip]
+const ABBREVIATIONS: &[&str] = &[
+    "apr", "j.-c", "c.-à-d", "cf", "coll", "dir", "etc", "ibid", "id", "max", "min", "op",
+    "p.ex", "préf", "sq", "suiv", "trad", "lun", "mar", "mer", "jeu", "ven", "sam", "dim",
+];
+
+/// "p. ex." with a space, whose periods are followed by a lowercase word or a comma.
+fn par_exemple_rule() -> Rule {
+    Rule::builtin(
+        "FrenchParExempleRule",
+        r"(?<=\b[pP])\.(\sex)[.∯](?=[,;:\s])",
+        "∯\\1∯",
+    )
+    .with_example("fruits, p. ex. les", "fruits, p∯ ex∯ les")
+    .with_example("fruits, p. ex., les", "fruits, p∯ ex∯, les")
+}
+
+/// Inner periods of abbreviations with a hyphen, like "J.-C." or "c.-à-d.".
+fn hyphenated_abbreviation_rule() -> Rule {
+    Rule::builtin(
+        "FrenchHyphenatedAbbreviationRule",
+        r"(?<=\b\p{L})\.(?=-\p{L})",
+        "∯",
+    )
+    .with_example("av. J.-C. à", "av. J∯-C. à")
+    .with_example("c.-à-d. la", "c∯-à-d. la")
+}
+
+/// Abbreviations of [`NUMBER_ABBREVIATIONS`] after an elided article, like "l'art. 5", which
+/// the abbreviation rules miss since they expect a space before the abbreviation.
+fn elided_number_abbreviation_rule() -> Rule {
+    Rule::new(
+        "FrenchElidedNumberAbbreviationRule",
+        &format!(
+            r"(?i)(\b[ldLD]['’](?:{}))\.(?=\s\d)",
+            NUMBER_ABBREVIATIONS.join("|")
+        ),
+        "\\1∯",
+    )
+    .unwrap() // NOTE: NUMBER_ABBREVIATIONS는 상수이므로 실패하지 않는다.
+    .with_example("Selon l'art. 5 de", "Selon l'art∯ 5 de")
+    .with_example("d’après l’art. 5", "d’après l’art∯ 5")
+    .with_example("l'art. Puis", "l'art. Puis")
+}
+
+/// A sentence which ends inside guillemets, with or without a space before the closing one.
+/// pySBD only knows the closing quotation marks which come right after the punctuation.
+fn closing_guillemet_rule() -> Rule {
+    Rule::builtin(
+        "FrenchClosingGuillemetRule",
+        "(?<=[.!?…])([ \u{a0}\u{202f}]?»)(?=[ \u{a0}\u{202f}]+\\p{Lu})",
+        "\\1\r",
+    )
+    .with_example("« Non. » Puis", "« Non. »\r Puis")
+    .with_example("«Non.» Puis", "«Non.»\r Puis")
+    .with_example("« Venez ! » cria-t-il", "« Venez ! » cria-t-il")
+}
+
@@ -0,0 +1,153 @@
+use crate::{
+    AbbreviationKind, AbbreviationPack, Language, Rule, SegmenterBuilder, UppercaseLetters,
+};
+
+/// German, like `de` of pySBD. On top of the rules of English, it recognizes German
+/// abbreviations like "z. B.", "bzw." or "Nr. 5", ordinal numbers like "am 1. Januar" or "das
+/// 2. Mal", quotations like „…“ and »…«, and any uppercase letter of Unicode like "Ä".
+///
+/// Like pySBD, a period after a number of one or two digits never ends a sentence, since it is
+/// almost always an ordinal number.
+///
+/// ```rust
+/// use pragmatic_segmenter::Segmenter;
+///
+/// let segmenter = Segmenter::builder().language("de")?.build()?;
+/// let result: Vec<_> = segmenter
+///     .segment("Wir treffen uns am 1. Januar, z. B. um 3 Uhr. Sie sagte: „Gut.“ Bis dann!")
+///     .collect();
+/// assert_eq!(
+///     result,
+///     vec!["Wir treffen uns am 1. Januar, z. B. um 3 Uhr. ", "Sie sagte: „Gut.“ ", "Bis dann!"]
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct German;
+
+impl Language for German {
+    fn code(&self) -> &str {
+        "de"
+    }
+
+    fn configure(&self, builder: SegmenterBuilder) -> SegmenterBuilder {
+        let pack = AbbreviationPack::from_groups(
+            "de",
+            &[
+                (NUMBER_ABBREVIATIONS, AbbreviationKind::Number),
+                (MONTH_ABBREVIATIONS, AbbreviationKind::Number),
+                (PREPOSITIVE_ABBREVIATIONS, AbbreviationKind::Prepositive),
+                (ABBREVIATIONS, AbbreviationKind::Plain),
+            ],
+        );
+        builder
+            .uppercase_letters(UppercaseLetters::Unicode)
+            .abbreviations(AbbreviationPack::honorifics("de").unwrap()) // Must exist
+            .abbreviations(pack)
+            .enclosing_pair("„", "“")
+            .enclosing_pair("»", "«")
+            .rule(number_period_space_rule())
+            .rule(negative_number_period_space_rule())
+            .rule(date_rule())
+            .rule(spaced_abbreviation_rule())
+            .rule(street_number_rule())
+    }
+}
+
+/// Abbreviations which come before a number, like "Nr. 5" or "Abs. 1".
+#[rustfmt::skip]
+const NUMBER_ABBREVIATIONS: &[&str] = &[
+    "art", "abs", "bd", "bde", "ca", "kap", "nr", "nrn", "s", "ziff", "tel", "lfd", "hnr",
+];
+
+#[rustfmt::skip]
+const MONTH_ABBREVIATIONS: &[&str] = &[
+    "jan", "feb", "febr", "mär", "apr", "jun", "jul", "aug", "sep", "sept", "okt", "nov", "dez",
+];
+
+/// Abbreviations which are hardly ever at the end of a sentence, so the period stays even
+/// before a noun, which starts with an uppercase letter in German: "3 Mio. Euro".
+#[rustfmt::skip]
+const PREPOSITIVE_ABBREVIATIONS: &[&str] = &[
+    "z.b", "bzw", "vgl", "sog", "bspw", "d.h", "inkl", "exkl", "zzgl", "abzgl", "bzgl", "insb",
+    "mio", "mrd", "tsd", "gem", "lt", "dipl.-ing", "i.a", "i.v",
+];
+
+#[rustfmt::skip]
+const ABBREVIATIONS: &[&str] = &[
+    "a.d", "allg", "anm", "bes", "betr", "bsp", "chr", "dgl", "dt", "ebd", "einschl", "engl",
+    "entspr", "erg", "etc", "evtl", "ff", "frz", "geb", "gegr", "gest", "ggf", "hrsg", "i.d.r",
+    "jh", "jhd", "jhdt", "max", "min", "mind", "mo", "di", "mi", "do", "sa", "so", "n.chr",
+    "o.ä", "o.g", "röm", "s.o", "s.u", "std", "str", "u.a", "u.ä", "u.u", "u.v.m", "urspr",
+    "usf", "usw", "v.a", "v.chr", "verh", "zit", "z.t",
+];
+
+const MONTHS: &[&str] = &[
+    "Januar",
+    "Februar",
+    "März",
+    "April",
+    "Mai",
+    "Juni",
+    "Juli",
+    "August",
+    "September",
+    "Oktober",
+    "November",
+    "Dezember",
+];
+
+/// Ordinal number of one or two digits, like "am 3. Mai" or "das 2. Mal".
+fn number_period_space_rule() -> Rule {
+    // NOTE: pySBD의 Deutsch.Numbers.NUMBER_PERIOD_SPACE_RULE
+    Rule::builtin(
+        "GermanNumberPeriodSpaceRule",
+        r"(?<=\s[0-9]|\s[1-9][0-9])\.(?=\s)",
+        "∯",
+    )
+    .with_example("am 3. Mai", "am 3∯ Mai")
+    .with_example("das 12. Mal", "das 12∯ Mal")
+    .with_example("im Jahr 2014. Dann", "im Jahr 2014. Dann")
+}
+
+fn negative_number_period_space_rule() -> Rule {
+    // NOTE: pySBD의 Deutsch.Numbers.NEGATIVE_NUMBER_PERIOD_SPACE_RULE
+    Rule::builtin(
+        "GermanNegativeNumberPeriodSpaceRule",
+        r"(?<=-[0-9]|-[1-9][0-9])\.(?=\s)",
+        "∯",
+    )
+    .with_example("bei -5. Es", "bei -5∯ Es")
+}
+
+/// A number followed by a month, like "1.Januar" or "1. Januar" at the start of a line.
+fn date_rule() -> Rule {
+    // NOTE: pySBD의 Deutsch.AbbreviationReplacer.replace_period_in_deutsch_dates()
+    Rule::new(
+        "GermanDateRule",
+        &format!(r"(?<=\d)\.(?=\s*(?:{}))", MONTHS.join("|")),
+        "∯",
+    )
+    .unwrap() // NOTE: MONTHS는 상수이므로 실패하지 않는다.
+    .with_example("1. Januar", "1∯ Januar")
+    .with_example("2014. Dann", "2014. Dann")
+}
+
+/// Abbreviations of single letters separated by a space, like "z. B." or "u. a.".
+fn spaced_abbreviation_rule() -> Rule {
+    Rule::builtin(
+        "GermanSpacedAbbreviationRule",
+        r"(?<=\b[a-zäöü])\.(?=\s[A-Za-zÄÖÜäöü][.∯])",
+        "∯",
+    )
+    .with_example("z. B. ist", "z∯ B. ist")
+    .with_example("i. d. R. nicht", "i∯ d∯ R. nicht")
+    .with_example("Plan B. Dann", "Plan B. Dann")
+}
+
+/// Abbreviated street names before a house number, like "Hauptstr. 12".
+fn street_number_rule() -> Rule {
+    Rule::builtin("GermanStreetNumberRule", r"(?<=str)\.(?=\s\d)", "∯")
+        .with_example("Hauptstr. 12", "Hauptstr∯ 12")
+}
+
@@ -0,0 +1,86 @@
+use crate::{
+    AbbreviationKind, AbbreviationPack, Language, Rule, SegmenterBuilder, UppercaseLetters,
+};
+
+/// Hindi, like `hi` of pySBD. On top of the rules of English, it ends a sentence at the danda
+/// "।" and the double danda "॥", and recognizes Hindi abbreviations like "डॉ." or "पृ.". Unlike
+/// pySBD, a vertical bar "|" typed for a danda doesn't end a sentence, since it is much more
+/// common in tables and menus.
+///
+/// Devanagari has no capital letters, so any Devanagari letter can start a sentence after a
+/// period, like an uppercase letter of Latin script.
+///
+/// ```rust
+/// use pragmatic_segmenter::Segmenter;
+///
+/// let segmenter = Segmenter::builder().language("hi")?.build()?;
+/// let result: Vec<_> = segmenter
+///     .segment("डॉ. शर्मा ने मरीज़ को देखा। क्या वह ठीक है? हाँ, वह ठीक है।")
+///     .collect();
+/// assert_eq!(
+///     result,
+///     vec!["डॉ. शर्मा ने मरीज़ को देखा। ", "क्या वह ठीक है? ", "हाँ, वह ठीक है।"]
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Hindi;
+
+impl Language for Hindi {
+    fn code(&self) -> &str {
+        "hi"
+    }
+
+    fn configure(&self, builder: SegmenterBuilder) -> SegmenterBuilder {
+        let pack = AbbreviationPack::from_groups(
+            "hi",
+            &[
+                (NUMBER_ABBREVIATIONS, AbbreviationKind::Number),
+                (PREPOSITIVE_ABBREVIATIONS, AbbreviationKind::Prepositive),
+                (ABBREVIATIONS, AbbreviationKind::Plain),
+            ],
+        );
+        devanagari(builder)
+            .abbreviations(AbbreviationPack::honorifics("hi").unwrap()) // Must exist
+            .abbreviations(pack)
+            .rule(postposition_rule())
+    }
+}
+
+/// Letters and terminal punctuations of Devanagari, shared with [`Marathi`](super::Marathi).
+pub(super) fn devanagari(builder: SegmenterBuilder) -> SegmenterBuilder {
+    builder
+        .uppercase_letters(UppercaseLetters::Custom(SENTENCE_STARTS.into()))
+        .terminal_punctuation('।')
+        .terminal_punctuation('॥')
+}
+
+/// Uppercase letters of other scripts, and every letter of Devanagari.
+const SENTENCE_STARTS: &str = r"[\p{Lu}\p{Lt}\p{Devanagari}&&\p{L}]";
+
+/// Abbreviations which come before a number, like "पृ. 45" for a page, "रु. 500" for rupees,
+/// "सं. 2080" for a year of the Vikram Samvat or "क्र. 3" for a number.
+const NUMBER_ABBREVIATIONS: &[&str] = &["पृ", "रु", "सं", "क्र"];
+
+/// "प्रा." of "प्रा. लि.", a private limited company.
+const PREPOSITIVE_ABBREVIATIONS: &[&str] = &["प्रा"];
+
+/// Abbreviations which may end a sentence, like "ई.पू." for before Christ and "लि." for a
+/// limited company. A single letter followed by a period, like "ई." for a year of the common
+/// era, never ends a sentence, since it is taken for an initial.
+const ABBREVIATIONS: &[&str] = &["ई.पू", "लि"];
+
+/// "ई.पू." or "लि." followed by a postposition or a verb, like "268 ई.पू. में". The verb comes
+/// last in Hindi, so they are usually followed by the rest of their sentence.
+fn postposition_rule() -> Rule {
+    // NOTE: 규칙이 적용될 때 약어 안의 마침표는 이미 ∯나 ∮로 바뀌어있을 수 있다.
+    Rule::builtin(
+        "HindiPostpositionRule",
+        r"(?<=\bई[.∯∮]पू|\bलि)\.(?=\s\p{Devanagari})",
+        "∯",
+    )
+    .with_example("268 ई.पू. में", "268 ई.पू∯ में")
+    .with_example("टाटा प्रा∯ लि. में", "टाटा प्रा∯ लि∯ में")
+    .with_example("268 ई.पू. Then", "268 ई.पू. Then")
+}
+
@@ -0,0 +1,72 @@
+use crate::{Language, Rule, SegmenterBuilder, UppercaseLetters};
+
+use super::chinese::double_punctuation;
+
+/// Japanese, like `ja` of pySBD. On top of the rules of English, it never splits inside the
+/// quotation marks 「…」 and 『…』 or the full-width parentheses （…）, and keeps full-width
+/// decimal points like "３．２９". Japanese has no spaces between sentences, so "。", "？" and
+/// "！" end a sentence whatever comes next.
+///
+/// Unlike [`Chinese`](super::Chinese), a quotation is usually followed by a particle like
+/// 「…」と言った, so a sentence which ends inside quotation marks doesn't end the sentence
+/// around it, unless another quotation follows right after it like in a dialogue.
+///
+/// ```rust
+/// use pragmatic_segmenter::Segmenter;
+///
+/// let segmenter = Segmenter::builder().language("ja")?.build()?;
+/// let result: Vec<_> = segmenter
+///     .segment("彼は「行きます。また明日。」と言った。「はい。」「いいえ。」")
+///     .collect();
+/// assert_eq!(
+///     result,
+///     vec!["彼は「行きます。また明日。」と言った。", "「はい。」", "「いいえ。」"]
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Japanese;
+
+impl Language for Japanese {
+    fn code(&self) -> &str {
+        "ja"
+    }
+
+    fn configure(&self, builder: SegmenterBuilder) -> SegmenterBuilder {
+        let builder = builder
+            .uppercase_letters(UppercaseLetters::Custom(SENTENCE_STARTS.into()))
+            .enclosing_pair("「", "」")
+            .enclosing_pair("『", "』")
+            .enclosing_pair("（", "）");
+        double_punctuation(builder)
+            .rule(full_width_decimal_rule())
+            .rule(dialogue_rule())
+    }
+}
+
+/// Uppercase letters of other scripts, and every letter of Han, Hiragana and Katakana.
+const SENTENCE_STARTS: &str = r"[\p{Lu}\p{Lt}\p{Han}\p{Hiragana}\p{Katakana}]";
+
+/// A full-width period between digits, like "３．２９％".
+fn full_width_decimal_rule() -> Rule {
+    Rule::builtin(
+        "JapaneseFullWidthDecimalRule",
+        r"(?<=[0-9０-９])．(?=[0-9０-９])",
+        "&ᓱ&",
+    )
+    .with_example("３．２９％", "３&ᓱ&２９％")
+    .with_example("第３．次", "第３．次")
+}
+
+/// A quotation which ends a sentence followed right after by another one, like
+/// "「はい。」「いいえ。」" in a dialogue.
+fn dialogue_rule() -> Rule {
+    Rule::builtin(
+        "JapaneseDialogueRule",
+        r"(?<=[。！？][」』])(?=[「『])",
+        "\r",
+    )
+    .with_example("「はい。」「いいえ。」", "「はい。」\r「いいえ。」")
+    .with_example("「はい」「いいえ」", "「はい」「いいえ」")
+}
+
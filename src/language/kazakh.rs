@@ -0,0 +1,110 @@
+use crate::{
+    AbbreviationKind, AbbreviationPack, Language, Rule, SegmenterBuilder, UppercaseLetters,
+};
+
+/// Kazakh, like `kk` of pySBD. On top of the rules of English, it recognizes Kazakh
+/// abbreviations like "ж." for a year, "ғ." for a century or "т.б." for "and so on", Cyrillic
+/// letters in abbreviations and acronyms, and quotations in « … » and „ … “.
+///
+/// ```rust
+/// use pragmatic_segmenter::Segmenter;
+///
+/// let segmenter = Segmenter::builder().language("kk")?.build()?;
+/// let result: Vec<_> = segmenter
+///     .segment("Абай 1845 ж. туған. Ол ақын, ойшыл, т.б. болған. «Қара сөз» кітабын оқыдың ба?")
+///     .collect();
+/// assert_eq!(
+///     result,
+///     vec![
+///         "Абай 1845 ж. туған. ",
+///         "Ол ақын, ойшыл, т.б. болған. ",
+///         "«Қара сөз» кітабын оқыдың ба?"
+///     ]
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Kazakh;
+
+impl Language for Kazakh {
+    fn code(&self) -> &str {
+        "kk"
+    }
+
+    fn configure(&self, builder: SegmenterBuilder) -> SegmenterBuilder {
+        let pack = AbbreviationPack::from_groups(
+            "kk",
+            &[
+                (NUMBER_ABBREVIATIONS, AbbreviationKind::Number),
+                (PREPOSITIVE_ABBREVIATIONS, AbbreviationKind::Prepositive),
+                (ABBREVIATIONS, AbbreviationKind::Plain),
+            ],
+        );
+        builder
+            .uppercase_letters(UppercaseLetters::Unicode)
+            .abbreviations(AbbreviationPack::honorifics("kk").unwrap()) // Must exist
+            .abbreviations(pack)
+            .enclosing_pair("„", "“")
+            .rule(spaced_abbreviation_rule())
+            .rule(final_abbreviation_rule())
+            .rule(closing_guillemet_rule())
+    }
+}
+
+/// Abbreviations which come before a number in captions and references, like "сур. 3" for a
+/// figure, "кест. 2" for a table or "тарм. 5" for a section.
+const NUMBER_ABBREVIATIONS: &[&str] = &["тарм", "сур", "кест"];
+
+/// Abbreviations which hardly ever end a sentence, like "мыс." for "for example", and languages
+/// like "ағыл." for English.
+const PREPOSITIVE_ABBREVIATIONS: &[&str] = &["мыс", "қаз", "ағыл", "орыс", "лат", "неміс"];
+
+/// Abbreviations which may end a sentence, like "т.б." and "т.с.с." for "and so on", "б.з.б."
+/// and "б.з." for before and of the common era, years and centuries after a number like
+/// "1845 ж." or "XIX ғ.", pages and volumes after a number like "45 б." or "2 т.", places after
+/// a name like "Алматы қ." or "Абай көш.", and units.
+#[rustfmt::skip]
+const ABBREVIATIONS: &[&str] = &[
+    "т.б", "т.с.с", "б.з.б", "б.з", "ж", "жж", "ғ", "ғғ", "б", "бб", "т", "қ", "обл", "ауд",
+    "көш", "даң", "млн", "млрд", "трлн", "тг", "сағ", "мин", "сек", "км", "кг", "см", "мм",
+    "т.т",
+];
+
+/// Abbreviations of single letters separated by a space, like "т. б." or "т. с. с.". The last
+/// period is kept only before a lowercase letter.
+fn spaced_abbreviation_rule() -> Rule {
+    Rule::builtin(
+        "KazakhSpacedAbbreviationRule",
+        r"(?<=\b\p{Cyrillic})\.(?=\s\p{Ll}[.∯])|(?<=\b\p{Cyrillic}[.∯]\s\p{Ll})\.(?=\s\p{Ll})",
+        "∯",
+    )
+    .with_example("т. б. болған", "т∯ б∯ болған")
+    .with_example("және т. б. Содан", "және т∯ б. Содан")
+    .with_example("Пункт Ә. Содан", "Пункт Ә. Содан")
+}
+
+/// "т.б.", "т.с.с.", "б.з.б." or "б.з." before a new sentence, like "алма, алмұрт т.б. Ол".
+/// Other abbreviations with several periods never end a sentence.
+fn final_abbreviation_rule() -> Rule {
+    // NOTE: 여러 마침표로 된 약어의 마지막 마침표는 규칙이 적용될 때 이미 ∯로 바뀌어있다.
+    Rule::builtin(
+        "KazakhFinalAbbreviationRule",
+        r"(?<=\bт[.∯]б|\bт[.∯]с[.∯]с|\bб[.∯]з[.∯]б|\bб[.∯]з)∯(?=\s\p{Lu})",
+        ".",
+    )
+    .with_example("алмұрт т∯б∯ Ол", "алмұрт т∯б. Ол")
+    .with_example("330 ж. б∯з∯б∯ Содан", "330 ж. б∯з∯б. Содан")
+    .with_example("т∯б∯ болған", "т∯б∯ болған")
+}
+
+/// A sentence which ends inside guillemets, like "«Сен келесің бе?» Ол".
+fn closing_guillemet_rule() -> Rule {
+    Rule::builtin(
+        "KazakhClosingGuillemetRule",
+        r"(?<=[.!?…])»(?=\s+\p{Lu})",
+        "»\r",
+    )
+    .with_example("«Сен келесің бе?» Ол", "«Сен келесің бе?»\r Ол")
+    .with_example("«Кет!» деді ол", "«Кет!» деді ол")
+}
+
@@ -0,0 +1,52 @@
+use crate::{AbbreviationKind, AbbreviationPack, Language, SegmenterBuilder};
+
+use super::hindi::devanagari;
+
+/// Marathi, like `mr` of pySBD. On top of the rules of English, it ends a sentence at the danda
+/// "।" and the double danda "॥" like [`Hindi`](super::Hindi), though most Marathi text ends its
+/// sentences with a period. It recognizes Marathi abbreviations like "प्रा." or "इ.स.", and
+/// places of an address like "ता. हवेली".
+///
+/// ```rust
+/// use pragmatic_segmenter::Segmenter;
+///
+/// let segmenter = Segmenter::builder().language("mr")?.build()?;
+/// let result: Vec<_> = segmenter
+///     .segment("शिवाजी महाराजांचा जन्म इ.स. १६३० मध्ये झाला. तुला माहीत आहे का? हो.")
+///     .collect();
+/// assert_eq!(
+///     result,
+///     vec!["शिवाजी महाराजांचा जन्म इ.स. १६३० मध्ये झाला. ", "तुला माहीत आहे का? ", "हो."]
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Marathi;
+
+impl Language for Marathi {
+    fn code(&self) -> &str {
+        "mr"
+    }
+
+    fn configure(&self, builder: SegmenterBuilder) -> SegmenterBuilder {
+        let pack = AbbreviationPack::from_groups(
+            "mr",
+            &[
+                (NUMBER_ABBREVIATIONS, AbbreviationKind::Number),
+                (PREPOSITIVE_ABBREVIATIONS, AbbreviationKind::Prepositive),
+            ],
+        );
+        devanagari(builder)
+            .abbreviations(AbbreviationPack::honorifics("mr").unwrap()) // Must exist
+            .abbreviations(pack)
+    }
+}
+
+/// Abbreviations which come before a number, like "पृ. ४५" for a page, "रु. २५०" for rupees,
+/// "क्र. ३" for a number, and "इ.स. १६३०" and "इ.स.पू. ३००" for years of the common era and
+/// before it.
+const NUMBER_ABBREVIATIONS: &[&str] = &["पृ", "रु", "क्र", "इ.स", "इ.स.पू"];
+
+/// Parts of an address: "रा." for a village or a town, "ता." for a taluka and "जि." for a
+/// district.
+const PREPOSITIVE_ABBREVIATIONS: &[&str] = &["रा", "ता", "जि"];
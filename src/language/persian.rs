@@ -0,0 +1,92 @@
+use crate::{
+    AbbreviationKind, AbbreviationPack, Language, Rule, SegmenterBuilder, UppercaseLetters,
+};
+
+/// Persian (Farsi), like `fa` of pySBD. On top of the rules of English, it ends a sentence at
+/// "؟", and recognizes Persian abbreviations like "ه.ش." for a year of the solar hijri calendar,
+/// "ر.ک." for a reference or "ص." for a page. The Arabic comma "،" and semicolon "؛" never end a
+/// sentence.
+///
+/// Like Arabic, Persian script has no capital letters, so any Arabic letter can start a sentence
+/// after a period.
+///
+/// ```rust
+/// use pragmatic_segmenter::Segmenter;
+///
+/// let segmenter = Segmenter::builder().language("fa")?.build()?;
+/// let result: Vec<_> = segmenter
+///     .segment("این کتاب در سال ۱۳۹۸ ه.ش. منتشر شد. آیا آن را خواندی؟ بله، خواندم.")
+///     .collect();
+/// assert_eq!(
+///     result,
+///     vec!["این کتاب در سال ۱۳۹۸ ه.ش. منتشر شد. ", "آیا آن را خواندی؟ ", "بله، خواندم."]
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Persian;
+
+impl Language for Persian {
+    fn code(&self) -> &str {
+        "fa"
+    }
+
+    fn configure(&self, builder: SegmenterBuilder) -> SegmenterBuilder {
+        let pack = AbbreviationPack::from_groups(
+            "fa",
+            &[
+                (NUMBER_ABBREVIATIONS, AbbreviationKind::Number),
+                (PREPOSITIVE_ABBREVIATIONS, AbbreviationKind::Prepositive),
+                (ABBREVIATIONS, AbbreviationKind::Plain),
+            ],
+        );
+        builder
+            .uppercase_letters(UppercaseLetters::Custom(SENTENCE_STARTS.into()))
+            .abbreviations(pack)
+            .terminal_punctuation('؟')
+            .rule(calendar_rule())
+            .rule(closing_guillemet_rule())
+    }
+}
+
+/// Uppercase letters of other scripts, and every letter of Arabic script.
+const SENTENCE_STARTS: &str = r"[\p{Lu}\p{Lt}\p{Arabic}&&\p{L}]";
+
+/// Abbreviations which come before a number, like "ص. ۴۵" for a page, "صص. ۱۰-۲۰" for pages,
+/// "ج. ۲" for a volume or "ش. ۱۲۳" for a number.
+const NUMBER_ABBREVIATIONS: &[&str] = &["ص", "صص", "ج", "ش"];
+
+/// "ر.ک." and "ن.ک.", "see", which always come before what they refer to.
+const PREPOSITIVE_ABBREVIATIONS: &[&str] = &["ر.ک", "ن.ک"];
+
+/// Abbreviations which may end a sentence, like "الخ.", and the calendars after a year: "ه.ش."
+/// and "هـ.ش." for the solar hijri, "ه.ق." and "هـ.ق." for the lunar hijri, and "ق.م." for
+/// before Christ.
+const ABBREVIATIONS: &[&str] = &["ه.ش", "هـ.ش", "ه.ق", "هـ.ق", "ق.م", "الخ"];
+
+/// A calendar after a year, like "۱۳۹۸ ه.ش. منتشر شد". The verb comes last in Persian, so a
+/// date is usually followed by the rest of its sentence rather than by a new one.
+fn calendar_rule() -> Rule {
+    // NOTE: 규칙이 적용될 때 약어 안의 마침표는 이미 ∯나 ∮로 바뀌어있을 수 있다.
+    Rule::builtin(
+        "PersianCalendarRule",
+        r"(?<=\bه[.∯∮][شق]|\bهـ[.∯∮][شق]|\bق[.∯∮]م)\.(?=\s\p{Arabic})",
+        "∯",
+    )
+    .with_example("۴۱۱ هـ.ق. شاهنامه", "۴۱۱ هـ.ق∯ شاهنامه")
+    .with_example("ششم ق.م. زندگی", "ششم ق.م∯ زندگی")
+    .with_example("نان الخ. سپس", "نان الخ. سپس")
+}
+
+/// A sentence which ends inside guillemets, like "«فردا برمی‌گردم.» سپس".
+fn closing_guillemet_rule() -> Rule {
+    Rule::builtin(
+        "PersianClosingGuillemetRule",
+        r"(?<=[.!?؟…])»(?=\s+[\p{Lu}\p{Arabic}])",
+        "»\r",
+    )
+    .with_example("«فردا برمی‌گردم.» سپس", "«فردا برمی‌گردم.»\r سپس")
+    .with_example("«کجا بودی؟» گفتم", "«کجا بودی؟»\r گفتم")
+    .with_example("«بله» گفت", "«بله» گفت")
+}
+
@@ -0,0 +1,67 @@
+use crate::{AbbreviationKind, AbbreviationPack, Language, SegmenterBuilder, UppercaseLetters};
+
+/// Polish, like `pl` of pySBD. On top of the rules of English, it recognizes Polish
+/// abbreviations like "np.", "itd.", "prof." or "ul.", amounts like "10 tys. zł", years like
+/// "w 2020 r.", and any uppercase letter of Unicode like "Ł" or "Ś".
+///
+/// ```rust
+/// use pragmatic_segmenter::Segmenter;
+///
+/// let segmenter = Segmenter::builder().language("pl")?.build()?;
+/// let result: Vec<_> = segmenter
+///     .segment("Prof. Nowak mieszka przy ul. Długiej, np. Kowalski też. Płacą 10 tys. zł. Tak.")
+///     .collect();
+/// assert_eq!(
+///     result,
+///     vec![
+///         "Prof. Nowak mieszka przy ul. Długiej, np. Kowalski też. ",
+///         "Płacą 10 tys. zł. ",
+///         "Tak."
+///     ]
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Polish;
+
+impl Language for Polish {
+    fn code(&self) -> &str {
+        "pl"
+    }
+
+    fn configure(&self, builder: SegmenterBuilder) -> SegmenterBuilder {
+        let pack = AbbreviationPack::from_groups(
+            "pl",
+            &[
+                (NUMBER_ABBREVIATIONS, AbbreviationKind::Number),
+                (PREPOSITIVE_ABBREVIATIONS, AbbreviationKind::Prepositive),
+                (ABBREVIATIONS, AbbreviationKind::Plain),
+            ],
+        );
+        builder
+            .uppercase_letters(UppercaseLetters::Unicode)
+            .abbreviations(AbbreviationPack::honorifics("pl").unwrap()) // Must exist
+            .abbreviations(pack)
+    }
+}
+
+/// Abbreviations which come before a number, like "nr 5", "s. 12" or "ok. 30".
+#[rustfmt::skip]
+const NUMBER_ABBREVIATIONS: &[&str] = &[
+    "nr", "s", "str", "poz", "ust", "pkt", "art", "par", "rozdz", "t", "tab", "rys", "ryc",
+    "godz", "ok", "tel", "cz", "vol",
+];
+
+/// Abbreviations which hardly ever end a sentence, so the period stays even before a name:
+/// "np. Kowalski", "ul. Długa".
+#[rustfmt::skip]
+const PREPOSITIVE_ABBREVIATIONS: &[&str] = &[
+    "np", "tzn", "tj", "tzw", "m.in", "wg", "zob", "por", "ul", "al", "pl", "os", "im", "woj",
+    "pow", "gm", "ang", "łac", "niem",
+];
+
+#[rustfmt::skip]
+const ABBREVIATIONS: &[&str] = &[
+    "itd", "itp", "jw", "ww", "r", "w", "br", "ub", "tys", "mln", "mld", "min", "sek", "wyd",
+    "red", "oprac", "tłum", "pn", "wt", "śr", "czw", "pt", "sob", "niedz",
+];
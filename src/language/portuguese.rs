@@ -0,0 +1,96 @@
+use crate::{
+    AbbreviationKind, AbbreviationPack, Language, Rule, SegmenterBuilder, UppercaseLetters,
+};
+
+/// Portuguese, both of Brazil and of Portugal. On top of the rules of English, it recognizes
+/// Portuguese abbreviations like "Sr.", "Dra.", "pág. 5", "Av." or "Ltda.", any uppercase letter
+/// of Unicode like "Á" or "É", and ordinals like "1º." or "2.ª" and "n.º" for a number.
+///
+/// ```rust
+/// use pragmatic_segmenter::Segmenter;
+///
+/// let segmenter = Segmenter::builder().language("pt")?.build()?;
+/// let result: Vec<_> = segmenter
+///     .segment("A Dra. Ana mora na Av. Paulista. Ficou em 1º. lugar, veja a pág. 5. É a Lei n.º 8.666.")
+///     .collect();
+/// assert_eq!(
+///     result,
+///     vec![
+///         "A Dra. Ana mora na Av. Paulista. ",
+///         "Ficou em 1º. lugar, veja a pág. 5. ",
+///         "É a Lei n.º 8.666."
+///     ]
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Portuguese;
+
+impl Language for Portuguese {
+    fn code(&self) -> &str {
+        "pt"
+    }
+
+    fn configure(&self, builder: SegmenterBuilder) -> SegmenterBuilder {
+        let pack = AbbreviationPack::from_groups(
+            "pt",
+            &[
+                (NUMBER_ABBREVIATIONS, AbbreviationKind::Number),
+                (PREPOSITIVE_ABBREVIATIONS, AbbreviationKind::Prepositive),
+                (ABBREVIATIONS, AbbreviationKind::Plain),
+            ],
+        );
+        builder
+            .uppercase_letters(UppercaseLetters::Unicode)
+            .abbreviations(AbbreviationPack::honorifics("pt").unwrap()) // Must exist
+            .abbreviations(pack)
+            .rule(numero_rule())
+            .rule(ordinal_rule())
+    }
+}
+
+/// Abbreviations which come before a number, like "pág. 5", "art. 14" or "fls. 20".
+#[rustfmt::skip]
+const NUMBER_ABBREVIATIONS: &[&str] = &[
+    "pág", "págs", "pag", "p", "pp", "cap", "caps", "art", "arts", "vol", "vols", "núm", "nº",
+    "fl", "fls", "inc", "fig", "figs", "tel", "ed",
+];
+
+/// Abbreviations which always come before a name, on top of
+/// [`AbbreviationPack::honorifics()`], like "Av. Paulista", "Sto. Antônio" or "V. Exa.". "séc."
+/// is "século", which comes before a Roman numeral.
+#[rustfmt::skip]
+const PREPOSITIVE_ABBREVIATIONS: &[&str] = &[
+    "av", "r", "pça", "rod", "sto", "sta", "pe", "fr", "gen", "cel", "ten", "sgt", "des", "exa",
+    "exas", "sa", "ilmo", "ilma", "séc",
+];
+
+#[rustfmt::skip]
+const ABBREVIATIONS: &[&str] = &[
+    "a.c", "d.c", "adm", "aprox", "cia", "depto", "etc", "ex", "hab", "ltda", "obs", "p.ex",
+    "s.a", "tb", "jan", "fev", "mar", "abr", "mai", "jun", "jul", "ago", "set", "out", "nov",
+    "dez",
+];
+
+/// "n.º" of a number, like "Lei n.º 8.666". The period is followed by "º" rather than a space,
+/// so the abbreviation can't be found as a word.
+fn numero_rule() -> Rule {
+    Rule::builtin("PortugueseNumeroRule", r"(?<=\b[nN])\.(?=º)", "∯")
+        .with_example("Lei n.º 8", "Lei n∯º 8")
+        .with_example("N.º 3", "N∯º 3")
+        .with_example("o 3.º andar", "o 3.º andar")
+}
+
+/// An ordinal followed by a period and a lowercase word, like "1º. lugar" or "2ª. edição".
+/// Before an uppercase letter, the period ends a sentence: "Chegou em 1º. Ela".
+fn ordinal_rule() -> Rule {
+    Rule::builtin(
+        "PortugueseOrdinalRule",
+        r"(?<=[0-9][ºª])\.(?=\s\p{Ll})",
+        "∯",
+    )
+    .with_example("em 1º. lugar", "em 1º∯ lugar")
+    .with_example("a 2ª. edição", "a 2ª∯ edição")
+    .with_example("em 1º. Ela", "em 1º. Ela")
+}
+
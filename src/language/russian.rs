@@ -0,0 +1,120 @@
+use crate::{
+    AbbreviationKind, AbbreviationPack, Language, Rule, SegmenterBuilder, UppercaseLetters,
+};
+
+/// Russian, like `ru` of pySBD. On top of the rules of English, it recognizes Russian
+/// abbreviations like "т.е.", "т. д.", "гг." or "руб.", city names like "г. Москва",
+/// quotations like „…“, and Cyrillic letters in abbreviations and acronyms.
+///
+/// ```rust
+/// use pragmatic_segmenter::Segmenter;
+///
+/// let segmenter = Segmenter::builder().language("ru")?.build()?;
+/// let result: Vec<_> = segmenter
+///     .segment("Он живёт в г. Москва, т.е. в столице. Цена 100 руб. за штуку. В 1990-х гг. всё было иначе.")
+///     .collect();
+/// assert_eq!(
+///     result,
+///     vec![
+///         "Он живёт в г. Москва, т.е. в столице. ",
+///         "Цена 100 руб. за штуку. ",
+///         "В 1990-х гг. всё было иначе."
+///     ]
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Russian;
+
+impl Language for Russian {
+    fn code(&self) -> &str {
+        "ru"
+    }
+
+    fn configure(&self, builder: SegmenterBuilder) -> SegmenterBuilder {
+        let pack = AbbreviationPack::from_groups(
+            "ru",
+            &[
+                (NUMBER_ABBREVIATIONS, AbbreviationKind::Number),
+                (PREPOSITIVE_ABBREVIATIONS, AbbreviationKind::Prepositive),
+                (ABBREVIATIONS, AbbreviationKind::Plain),
+            ],
+        );
+        builder
+            .uppercase_letters(UppercaseLetters::Unicode)
+            .abbreviations(AbbreviationPack::honorifics("ru").unwrap()) // Must exist
+            .abbreviations(pack)
+            .enclosing_pair("„", "“")
+            .rule(city_rule())
+            .rule(spaced_abbreviation_rule())
+            .rule(era_rule())
+            .rule(closing_guillemet_rule())
+    }
+}
+
+/// Abbreviations which come before a number, like "с. 12", "рис. 3" or "д. 5".
+#[rustfmt::skip]
+const NUMBER_ABBREVIATIONS: &[&str] = &[
+    "с", "стр", "т", "п", "ст", "гл", "рис", "табл", "д", "кв", "корп", "тел", "ч",
+];
+
+/// Abbreviations which hardly ever end a sentence, so the period stays even before a name:
+/// "ул. Ленина", "им. Пушкина".
+#[rustfmt::skip]
+const PREPOSITIVE_ABBREVIATIONS: &[&str] = &[
+    "ул", "просп", "пр-т", "пл", "пер", "наб", "обл", "им", "напр", "ср", "см", "англ", "лат",
+    "нем", "франц",
+];
+
+#[rustfmt::skip]
+const ABBREVIATIONS: &[&str] = &[
+    "т.е", "т.д", "т.п", "т.к", "т.н", "т.о", "н.э", "и.о", "г", "гг", "в", "вв",
+    "руб", "коп", "тыс", "млн", "млрд", "трлн", "др", "пр", "проч", "мин", "сек", "кг", "км",
+    "изд", "ред", "сокр", "пн", "вт", "чт", "пт", "сб", "вс",
+];
+
+/// "г." of a city before its name, like "г. Москва". After a number, "г." is a year, which may
+/// end a sentence.
+fn city_rule() -> Rule {
+    Rule::builtin(
+        "RussianCityRule",
+        r"(?<![0-9]\sг|[0-9]г)(?<=\bг)\.(?=\s\p{Lu})",
+        "∯",
+    )
+    .with_example("в г. Москва", "в г∯ Москва")
+    .with_example("в 2020 г. Потом", "в 2020 г. Потом")
+}
+
+/// Abbreviations of single letters separated by a space, like "т. е." or "т. д.". The last period
+/// is kept only before a lowercase letter.
+fn spaced_abbreviation_rule() -> Rule {
+    Rule::builtin(
+        "RussianSpacedAbbreviationRule",
+        r"(?<=\b[а-яёА-ЯЁ])\.(?=\s[а-яё][.∯])|(?<=\b[а-яёА-ЯЁ][.∯]\s[а-яё])\.(?=\s\p{Ll})",
+        "∯",
+    )
+    .with_example("т. е. в", "т∯ е∯ в")
+    .with_example("и т. д. Потом", "и т∯ д. Потом")
+    .with_example("Т. е. это", "Т∯ е∯ это")
+    .with_example("Пункт Б. Далее", "Пункт Б. Далее")
+}
+
+/// "н.э." of a year before a new sentence, like "в V в. до н.э. Так". Other abbreviations with
+/// several periods never end a sentence.
+fn era_rule() -> Rule {
+    Rule::builtin("RussianEraRule", r"(?<=\bн[.∯]э)∯(?=\s\p{Lu})", ".")
+        .with_example("до н∯э∯ Так", "до н∯э. Так")
+        .with_example("до н∯э∯ в", "до н∯э∯ в")
+}
+
+/// A sentence which ends inside guillemets, like "«Ты придёшь?» Он".
+fn closing_guillemet_rule() -> Rule {
+    Rule::builtin(
+        "RussianClosingGuillemetRule",
+        r"(?<=[.!?…])»(?=\s+\p{Lu})",
+        "»\r",
+    )
+    .with_example("«Ты придёшь?» Он", "«Ты придёшь?»\r Он")
+    .with_example("«Иди!» сказал он", "«Иди!» сказал он")
+}
+
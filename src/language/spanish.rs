@@ -0,0 +1,93 @@
+use crate::{
+    AbbreviationKind, AbbreviationPack, Language, Rule, SegmenterBuilder, UppercaseLetters,
+};
+
+/// Spanish, like `es` of pySBD. On top of the rules of English, it recognizes Spanish
+/// abbreviations like "Sra.", "pág. 5" or "Avda.", any uppercase letter of Unicode like "Á" or
+/// "Ñ", and questions and exclamations which continue the sentence, like "¿Vienes?, preguntó".
+/// Inverted marks like "¿" and "¡" start a new sentence after a period, even after an
+/// abbreviation.
+///
+/// ```rust
+/// use pragmatic_segmenter::Segmenter;
+///
+/// let segmenter = Segmenter::builder().language("es")?.build()?;
+/// let result: Vec<_> = segmenter
+///     .segment("¿Vienes?, preguntó la Sra. Ruiz. Consulte la pág. 5. ¡Ánimo!")
+///     .collect();
+/// assert_eq!(
+///     result,
+///     vec!["¿Vienes?, preguntó la Sra. Ruiz. ", "Consulte la pág. 5. ", "¡Ánimo!"]
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Spanish;
+
+impl Language for Spanish {
+    fn code(&self) -> &str {
+        "es"
+    }
+
+    fn configure(&self, builder: SegmenterBuilder) -> SegmenterBuilder {
+        let pack = AbbreviationPack::from_groups(
+            "es",
+            &[
+                (NUMBER_ABBREVIATIONS, AbbreviationKind::Number),
+                (PREPOSITIVE_ABBREVIATIONS, AbbreviationKind::Prepositive),
+                (ABBREVIATIONS, AbbreviationKind::Plain),
+            ],
+        );
+        builder
+            .uppercase_letters(UppercaseLetters::Unicode)
+            .abbreviations(AbbreviationPack::honorifics("es").unwrap()) // Must exist
+            .abbreviations(pack)
+            .rule(question_mark_mid_sentence_rule())
+            .rule(exclamation_mark_mid_sentence_rule())
+    }
+}
+
+/// Abbreviations which come before a number, like "pág. 5" or "art. 14".
+#[rustfmt::skip]
+const NUMBER_ABBREVIATIONS: &[&str] = &[
+    "pág", "págs", "pag", "cap", "caps", "art", "arts", "núm", "núms", "vol", "vols", "tel",
+    "fig", "figs", "ej", "apdo", "c.p",
+];
+
+/// Abbreviations which always come before a name, on top of
+/// [`AbbreviationPack::honorifics()`]. "s." is "siglo", which comes before a Roman numeral.
+#[rustfmt::skip]
+const PREPOSITIVE_ABBREVIATIONS: &[&str] = &[
+    "av", "avda", "gral", "mtro", "mtra", "pbro", "fr", "sor", "dn", "s", "vd", "vds",
+];
+
+#[rustfmt::skip]
+const ABBREVIATIONS: &[&str] = &[
+    "a.c", "a.m", "abr", "adj", "admón", "ago", "aprox", "atte", "cía", "cta", "d.c", "dcha",
+    "depto", "dic", "dpto", "ee.uu", "ene", "esq", "etc", "excmos", "feb", "fdo", "hnos", "ib",
+    "ibíd", "id", "izq", "izqda", "jue", "lun", "mar", "mié", "nov", "oct", "p.ej", "p.m",
+    "párr", "pdte", "pdta", "prov", "pza", "q.e.p.d", "rte", "s.a", "s.l", "sáb", "sep", "sept",
+    "sig", "sigs", "srs", "ss", "trad", "univ", "vie", "vid",
+];
+
+/// A question which ends with "?" continues the sentence before a comma, a semicolon or a
+/// lowercase word, since it starts with "¿" anyway: "¿Vienes?, preguntó".
+fn question_mark_mid_sentence_rule() -> Rule {
+    Rule::builtin(
+        "SpanishQuestionMarkMidSentenceRule",
+        r"\?(?=[,;]|\s\p{Ll})",
+        "&ᓷ&",
+    )
+    .with_example("¿Vienes?, preguntó", "¿Vienes&ᓷ&, preguntó")
+    .with_example("¿Qué? dijo", "¿Qué&ᓷ& dijo")
+    .with_example("¿Qué? Nada", "¿Qué? Nada")
+}
+
+/// Same as [`question_mark_mid_sentence_rule()`] for "!". pySBD already keeps "!" before a
+/// lowercase word.
+fn exclamation_mark_mid_sentence_rule() -> Rule {
+    Rule::builtin("SpanishExclamationMarkMidSentenceRule", r"!(?=[,;])", "&ᓴ&")
+        .with_example("¡Hola!, dijo", "¡Hola&ᓴ&, dijo")
+        .with_example("¡Hola! Dijo", "¡Hola! Dijo")
+}
+
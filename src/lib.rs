@@ -12,26 +12,160 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 //!
+//! ### Stability
+//!
+//! Segmentation is deterministic: the same input always produces the same output for a given
+//! version of this crate and the same options. Pipelines which cache segmentations can store
+//! [`Segmenter::fingerprint()`] along with the results, and re-process documents only when the
+//! fingerprint changes after an upgrade or a configuration change. Custom code like a
+//! [`BoundaryScorer`] is only partly covered, see [`Segmenter::fingerprint()`] for the limits.
+//!
 //! [pySBD]: https://github.com/nipunsadvilkar/pySBD
 //! [pragmatic_segmenter]: https://github.com/diasks2/pragmatic_segmenter
 //! [Documentations]: https://docs.rs/pragmatic-segmenter
 
-mod abbreviation_replacer;
-mod list_item_replacer;
-mod rule;
-mod util;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+/// Declare items which need std, that is, everything but [`lite`] and [`fallback`].
+macro_rules! std_only {
+    ($($item:item)*) => {
+        $(
+            #[cfg(feature = "std")]
+            $item
+        )*
+    };
+}
+
+#[cfg(feature = "lite")]
+pub mod fallback;
+#[cfg(feature = "lite")]
+pub mod lite;
+
+std_only! {
+    pub mod batch;
+    #[cfg(feature = "bench")]
+    pub mod bench;
+    pub mod capitalize;
+    pub mod channel;
+    pub mod compat;
+    pub mod compression;
+    pub mod conformance;
+    pub mod diagnostics;
+    pub mod diff;
+    pub mod doc_type;
+    pub mod ids;
+    pub mod join;
+    pub mod jsonl;
+    pub mod minimize;
+    #[cfg(feature = "model")]
+    pub mod model;
+    pub mod pairs;
+    pub mod parallel;
+    pub mod snippet;
+    pub mod trace;
+    pub mod training;
+    pub mod tts;
+
+    mod abbreviation_pack;
+    mod abbreviation_replacer;
+    mod all_caps;
+    mod between_punctuation;
+    mod builder;
+    mod clause;
+    mod colon_list;
+    mod dateline;
+    mod language;
+    mod list_item_replacer;
+    mod merge_rule;
+    mod mojibake;
+    mod preset;
+    mod quality;
+    mod quotation;
+    mod rerank;
+    mod rule;
+    mod segment;
+    mod speech;
+    mod structure;
+    mod transcript;
+    mod util;
+    mod whitespace;
+
+    use std::borrow::Cow;
+    use std::cell::RefCell;
+    use std::error::Error;
+    use std::iter::Iterator;
+    use std::mem;
+    use std::str::Utf8Error;
+    use std::sync::Arc;
+
+    use onig::{Captures, Regex};
+
+    use between_punctuation::BetweenPunctuation;
+    use colon_list::ColonLists;
+    use dateline::Datelines;
+    use mojibake::RepairedMojibake;
+    use rerank::MergeScorer;
+    use speech::SpeechRules;
+    use trace::{Stage, Step, Tracer};
+    use transcript::{Turn, TurnParser};
+    use util::{fnv1a, insert_all, re};
+    use whitespace::CollapsedWhitespace;
+
+    pub use abbreviation_pack::{
+        AbbreviationConflict, AbbreviationKind, AbbreviationPack, InvalidAbbreviation,
+    };
+    pub use abbreviation_replacer::{
+        AbbreviationCategory, AbbreviationOccurrence, AbbreviationReplacer,
+    };
+    pub use builder::{
+        AcronymBoundary, NumberedReferences, NumeralConflict, NumericExpressions, Overlong,
+        PostProcess, SegmenterBuilder, SpeechRecognition, UppercaseLetters,
+    };
+    pub use language::{
+        languages, lookup, register, Arabic, Chinese, English, French, German, Hindi, Japanese,
+        Kazakh, Language, Marathi, Persian, Polish, Portuguese, Russian, Spanish,
+        UnknownLanguage,
+    };
+    pub use list_item_replacer::ListItemReplacer;
+    pub use merge_rule::MergeRule;
+    pub use preset::Preset;
+    pub use quality::Quality;
+    pub use quotation::Quotation;
+    pub use rerank::{BoundaryCandidate, BoundaryScorer};
+    pub use rule::{Rule, RuleMismatch, RuleSet, UnknownRule};
+    pub use segment::{Citation, CoverageError, Kind, Segment};
+    pub use structure::{DocStructure, List, ListKind};
+}
 
-use std::borrow::Cow;
-use std::error::Error;
-use std::iter::Iterator;
+/// Revision of the built-in rules. It is bumped whenever a release changes the output of
+/// [`Segmenter`] for any input, and is part of [`Segmenter::fingerprint()`].
+#[cfg(feature = "std")]
+pub const VERSION_TAG: &str = "3";
 
-use onig::{Captures, Regex};
+// ExclamationWords.EXCLAMATION_WORDS
+#[cfg(feature = "std")]
+#[rustfmt::skip]
+const EXCLAMATION_WORDS: &[&str] = &[
+    "!Xũ", "!Kung", "ǃʼOǃKung", "!Xuun", "!Kung-Ekoka", "ǃHu", "ǃKhung", "ǃKu", "ǃung", "ǃXo",
+    "ǃXû", "ǃXung", "ǃXũ", "!Xun", "Yahoo!", "Y!J", "Yum!",
+];
 
-use abbreviation_replacer::AbbreviationReplacer;
-use list_item_replacer::ListItemReplacer;
-use rule::Rule;
-use util::re;
+// Common.Numbers.FileFormatRule의 확장자들. 정규표현식 조각이다.
+#[cfg(feature = "std")]
+#[rustfmt::skip]
+const FILE_EXTENSIONS: &[&str] = &[
+    "jpe?g", "png", "gif", "tiff?", "pdf", "ps", "docx?", "xlsx?", "svg", "bmp", "tga", "exif",
+    "odt", "html?", "txt", "rtf", "bat", "sxw", "xml", "zip", "exe", "msi", "blend", "wmv",
+    "mp[34]", "pptx?", "flac", "rb", "cpp", "cs", "js",
+];
 
+/// Length in bytes of the first prefix which [`Segmenter::first_sentences()`] segments.
+#[cfg(feature = "std")]
+const FIRST_SENTENCES_CHUNK: usize = 4096;
+
+#[cfg(feature = "std")]
 const PUNCTUATIONS: [char; 7] = ['。', '．', '.', '！', '!', '?', '？'];
 
 /// Segmenter type. It stores the compilation results of regular expressions used internally by
@@ -45,47 +179,68 @@ const PUNCTUATIONS: [char; 7] = ['。', '．', '.', '！', '!', '?', '？'];
 /// assert_eq!(result, vec!["Hi Mr. Kim. ", "Let's meet at 3 P.M."]);
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
+#[cfg(feature = "std")]
 pub struct Segmenter {
     list_item_replacer: ListItemReplacer,
     abbreviation_replacer: AbbreviationReplacer,
 
-    number_rules: [Rule; 5],
+    salutation: Option<Rule>,
+    number_rules: RuleSet,
     continuous_punctuation_regex: Regex,
-    numbered_reference: Rule,
+    numbered_reference: Option<Rule>,
     abbreviation_with_multiple_periods_and_email_regex: regex::Regex,
-    misc_rules: [Rule; 2],
+    misc_rules: RuleSet,
 
     parens_between_double_quotes_regex: Regex,
     parens_between_double_quotes_0: Rule,
     parens_between_double_quotes_1: Rule,
 
-    ellipsis_rules: [Rule; 5],
+    ellipsis_rules: RuleSet,
 
     exclamation_regex: Regex,
-    sub_escaped_regex_reserved_characters: [Rule; 5],
+    sub_escaped_regex_reserved_characters: RuleSet,
 
     word_with_leading_apostrophe: Regex,
     trailing_apostrophe: Regex,
     between_single_quotes_regex: Regex,
     between_single_quote_slanted_regex: Regex,
-    between_double_quotes_regex_2: Regex,
-    between_square_brackets_regex_2: Regex,
-    between_parens_regex_2: Regex,
-    between_quote_arrow_regex_2: Regex,
-    between_em_dashes_regex_2: Regex,
-    between_quote_slanted_regex_2: Regex,
+    between_punctuation: BetweenPunctuation,
+    quotation_marks: BetweenPunctuation,
 
     double_punctuation: Regex,
-    question_mark_in_quotation_and_exclamation_point_rules: [Rule; 4],
+    question_mark_in_quotation_and_exclamation_point_rules: RuleSet,
 
     replace_parens: Rule,
 
     sentence_boundary_regex: Regex,
+    /// [`PUNCTUATIONS`] and the terminal punctuations added to the builder
+    punctuations: Vec<char>,
     post_process_regex: Regex,
     quotation_at_end_of_sentence_regex: Regex,
     split_space_quotation_at_end_of_sentence_regex: Regex,
+
+    protected_regions: Vec<Regex>,
+    merge_unbalanced_quotes: bool,
+    multiline_brackets: Option<BetweenPunctuation>,
+    collapse_whitespace: bool,
+    fold_all_caps: bool,
+    repair_mojibake: bool,
+    literal_newlines: bool,
+    speech: Option<SpeechRules>,
+    datelines: Option<Datelines>,
+    colon_lists: Option<ColonLists>,
+    join_wrapped_lines: bool,
+    turn_parser: Option<TurnParser>,
+    post_process: PostProcess,
+    max_length: Option<(usize, Overlong)>,
+    boundary_scorer: Option<Arc<dyn BoundaryScorer>>,
+    merge_scorer: Option<MergeScorer>,
+    merge_rules: Vec<MergeRule>,
+
+    fingerprint: u64,
 }
 
+#[cfg(feature = "std")]
 impl Segmenter {
     /// Create a new Segmenter instance. The regular expressions used internally by
     /// pragmatic-segmenter are compiled here.
@@ -97,98 +252,240 @@ impl Segmenter {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn new() -> Result<Self, Box<dyn Error>> {
-        Ok(Segmenter {
-            list_item_replacer: ListItemReplacer::new()?,
-            abbreviation_replacer: AbbreviationReplacer::new()?,
+        SegmenterBuilder::new().build()
+    }
 
-            number_rules: [
-                // PeriodBeforeNumberRule
+    /// Create a [`SegmenterBuilder`] to configure the behavior of the segmenter.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::builder().lowercase_sentence_starts(true).build()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn builder() -> SegmenterBuilder {
+        SegmenterBuilder::new()
+    }
+
+    pub(crate) fn with_builder(builder: &SegmenterBuilder) -> Result<Self, Box<dyn Error>> {
+        // 다음 문장의 첫 글자로 허용되는 문자들. pySBD는 대문자만 허용한다.
+        let start = builder.sentence_start_pattern();
+        let upper = builder.uppercase_pattern();
+        let terminals: String = builder
+            .terminal_punctuations
+            .iter()
+            .map(|&c| {
+                if c.is_ascii_punctuation() {
+                    format!(r"\{}", c)
+                } else {
+                    c.to_string()
+                }
+            })
+            .collect();
+        // 추가된 문장부호가 "……"처럼 연달아 오거나, 뒤에 닫는 따옴표나 괄호가 오면 같은 문장에
+        // 붙인다.
+        let added_terminal = if terminals.is_empty() {
+            String::new()
+        } else {
+            format!(r#"|[{}]+[\"\'”’»›)\]]*"#, terminals)
+        };
+        let lower = builder.lowercase_pattern();
+
+        let mut question_mark_in_quotation_and_exclamation_point_rules = vec![
+            // Example: https://rubular.com/r/aXPUGm6fQh
+            Rule::new("QuestionMarkInQuotationRule", r#"\?(?=(\'|\"))"#, "&ᓷ&")?,
+            // Example: https://rubular.com/r/XS1XXFRfM2
+            Rule::new("InQuotationRule", r#"\!(?=(\'|\"))"#, "&ᓴ&")?,
+            // Example: https://rubular.com/r/sl57YI8LkA
+            Rule::new(
+                "BeforeCommaMidSentenceRule",
+                &format!(r"\!(?=\,\s{lower})", lower = lower),
+                "&ᓴ&",
+            )?,
+        ];
+        if !builder.lowercase_sentence_starts {
+            // Example: https://rubular.com/r/f9zTjmkIPb
+            question_mark_in_quotation_and_exclamation_point_rules.push(Rule::new(
+                "MidSentenceRule",
+                &format!(r"\!(?=\s{lower})", lower = lower),
+                "&ᓴ&",
+            )?);
+        }
+
+        let protected_regions = builder
+            .protected_patterns
+            .iter()
+            .map(|pattern| re(pattern))
+            .collect::<Result<_, _>>()?;
+
+        let mut misc_rules: RuleSet = builder.numeric_expressions.rules(upper)?.into();
+        let file_extensions: Vec<_> = FILE_EXTENSIONS
+            .iter()
+            .map(|&ext| Cow::Borrowed(ext))
+            .chain(
+                builder
+                    .file_extensions
+                    .iter()
+                    .map(|ext| Cow::Owned(regex::escape(ext))),
+            )
+            .collect();
+        misc_rules.push(
+            Rule::new(
+                "FileFormatRule",
+                &format!(
+                    r"(?<=\s)\.(?=({extensions})\s)",
+                    extensions = file_extensions.join("|")
+                ),
+                "∯",
+            )?
+            .with_example("Open the .docx file.", "Open the ∯docx file."),
+        );
+        misc_rules.extend(builder.rules.iter().cloned());
+
+        let mut segmenter = Segmenter {
+            list_item_replacer: ListItemReplacer::new(builder)?,
+            abbreviation_replacer: AbbreviationReplacer::new(builder)?,
+
+            // 약어의 마침표가 ∯로 바뀐 뒤에 적용되므로, 인사말 안에는 마침표가 없다.
+            salutation: if builder.salutations {
+                Some(
+                    Rule::new(
+                        "SalutationRule",
+                        concat!(
+                            r"\A(\s*(?:(?i:re|fwd?|subj|subject)[ \t]*:[^\r]*\r\s*)*",
+                            r"(?i:dear|hi|hello|hey|greetings|good (?:morning|afternoon|evening)",
+                            r"|to whom it may concern)\b[^\r,:;!?.]{0,60}[,:!])[ \t]+(?=\S)",
+                        ),
+                        r"\1\r",
+                    )?
+                    .with_example("Dear Dr∯ Kim, I came.", "Dear Dr∯ Kim,\rI came.")
+                    .with_example("RE: Hi\rHi all, we met.", "RE: Hi\rHi all,\rwe met.")
+                    .with_example("Hello world. It is me, Kim.", "Hello world. It is me, Kim."),
+                )
+            } else {
+                None
+            },
+            number_rules: vec![
                 // Example: https://rubular.com/r/oNyxBOqbyy
-                Rule::new(r"\.(?=\d)", "∯")?,
-                // NumberAfterPeriodBeforeLetterRule
+                Rule::new("PeriodBeforeNumberRule", r"\.(?=\d)", "∯")?
+                    .with_example("Pi is about 3.14.", "Pi is about 3∯14."),
                 // Example: https://rubular.com/r/EMk5MpiUzt
-                Rule::new(r"(?<=\d)\.(?=\S)", "∯")?,
-                // NewLineNumberPeriodSpaceLetterRule
+                Rule::new("NumberAfterPeriodBeforeLetterRule", r"(?<=\d)\.(?=\S)", "∯")?
+                    .with_example("See 3.a and 3.b.", "See 3∯a and 3∯b."),
                 // Example: https://rubular.com/r/rf4l1HjtjG
-                Rule::new(r"(?<=\r\d)\.(?=(\s\S)|\))", "∯")?,
-                // StartLineNumberPeriodRule
+                Rule::new(
+                    "NewLineNumberPeriodSpaceLetterRule",
+                    r"(?<=\r\d)\.(?=(\s\S)|\))",
+                    "∯",
+                )?
+                .with_example("Items:\r1. Apple", "Items:\r1∯ Apple"),
                 // Example: https://rubular.com/r/HPa4sdc6b9
-                Rule::new(r"(?<=^\d)\.(?=(\s\S)|\))", "∯")?,
-                // StartLineTwoDigitNumberPeriodRule
+                Rule::new("StartLineNumberPeriodRule", r"(?<=^\d)\.(?=(\s\S)|\))", "∯")?
+                    .with_example("1. Apple", "1∯ Apple"),
                 // Example: https://rubular.com/r/NuvWnKleFl
-                Rule::new(r"(?<=^\d\d)\.(?=(\s\S)|\))", "∯")?,
-            ],
+                Rule::new(
+                    "StartLineTwoDigitNumberPeriodRule",
+                    r"(?<=^\d\d)\.(?=(\s\S)|\))",
+                    "∯",
+                )?
+                .with_example("12. Apple", "12∯ Apple"),
+            ]
+            .into(),
 
             // Example: https://rubular.com/r/mQ8Es9bxtk
             continuous_punctuation_regex: re(r"(?<=\S)(!|\?){3,}(?=(\s|\Z|$))")?,
 
             // Example: https://rubular.com/r/UkumQaILKbkeyc
-            numbered_reference: Rule::new(
-                r"(?<=[^\d\s])(\.|∯)((\[(\d{1,3},?\s?-?\s?)*\b\d{1,3}\])+|((\d{1,3}\s?)?\d{1,3}))(\s)(?=[A-Z])",
-                r"∯\2\r\7",
-            )?,
+            //
+            // NOTE: pySBD는 `((\[(\d{1,3},?\s?-?\s?)*\b\d{1,3}\])+|((\d{1,3}\s?)?\d{1,3}))`를 쓰는데,
+            // 참조 양식을 설정할 수 있도록 NumberedReferences::pattern()에서 만든다.
+            numbered_reference: builder
+                .numbered_references
+                .pattern()
+                .map(|references| {
+                    Rule::new(
+                        "NumberedReferenceRule",
+                        &format!(
+                            r"(?<=[^\d\s])(\.|∯)({references})(\s)(?={start})",
+                            references = references,
+                            start = start
+                        ),
+                        r"∯\2\r\3",
+                    )
+                })
+                .transpose()?,
 
             // English.Abbreviation.WithMultiplePeriodsAndEmailRule,
             //
             // NOTE: pySBD와 루비 구현체가 다른 정규표현식을 쓴다. pySBD의 동작을 따라간다.
             //
             // Example: https://rubular.com/r/EUbZCNfgei
-            abbreviation_with_multiple_periods_and_email_regex: regex::Regex::new(
-                r"([a-zA-Z0-9_])(?:\.)([a-zA-Z0-9_])",
-            )?,
+            abbreviation_with_multiple_periods_and_email_regex: regex::Regex::new(&format!(
+                r"([{letter}0-9_])(?:\.)([{letter}0-9_])",
+                letter = builder.letter_class()
+            ))?,
 
-            misc_rules: [
-                // English.GeoLocationRule,
-                Rule::new(r"(?<=[a-zA-z]°)\.(?=\s*\d+)", "∯")?,
-                // English.FileFormatRule,
-                Rule::new(
-                    r"(?<=\s)\.(?=(jpe?g|png|gif|tiff?|pdf|ps|docx?|xlsx?|svg|bmp|tga|exif|odt|html?|txt|rtf|bat|sxw|xml|zip|exe|msi|blend|wmv|mp[34]|pptx?|flac|rb|cpp|cs|js)\s)",
-                    "∯",
-                )?,
-            ],
+            misc_rules,
 
             // Example: https://rubular.com/r/6flGnUMEVl
             parens_between_double_quotes_regex: re(r#"["\”]\s\(.*\)\s["\“]"#)?,
-            parens_between_double_quotes_0: Rule::new(r"\s(?=\()", "\r")?,
-            parens_between_double_quotes_1: Rule::new(r"(?<=\))\s", "\r")?,
+            parens_between_double_quotes_0: Rule::new(
+                "ParensBetweenDoubleQuotesOpenRule",
+                r"\s(?=\()",
+                "\r",
+            )?,
+            parens_between_double_quotes_1: Rule::new(
+                "ParensBetweenDoubleQuotesCloseRule",
+                r"(?<=\))\s",
+                "\r",
+            )?,
 
             // NOTE: 이부분은 pySBD 구현과 루비 구현이 동작이 다르다. pySBD의 동작을 따른다.
             // 이 부분을 고치게 되면 ReinsertEllipsisRules도 함께 고쳐야한다.
-            ellipsis_rules: [
-                // ThreeSpaceRule
+            ellipsis_rules: vec![
                 // Example: https://rubular.com/r/YBG1dIHTRu
-                Rule::new(r"(\s\.){3}\s", "♟♟♟♟♟♟♟")?,
-                // FourSpaceRule
+                Rule::new("ThreeSpaceRule", r"(\s\.){3}\s", "♟♟♟♟♟♟♟")?
+                    .with_example("Wait . . . what", "Wait♟♟♟♟♟♟♟what"),
                 // Example: https://rubular.com/r/2VvZ8wRbd8
-                Rule::new(r"(?<=[a-z])(\.\s){3}\.($|\\n)", "♝♝♝♝♝♝♝")?,
-                // FourConsecutiveRule
+                Rule::new("FourSpaceRule", r"(?<=[a-z])(\.\s){3}\.($|\\n)", "♝♝♝♝♝♝♝")?
+                    .with_example("It ended. . . .", "It ended♝♝♝♝♝♝♝"),
                 // Example: https://rubular.com/r/Hdqpd90owl
-                Rule::new(r"(?<=\S)\.{3}(?=\.\s[A-Z])", "ƪƪƪ")?,
-                // ThreeConsecutiveRule
+                Rule::new(
+                    "FourConsecutiveRule",
+                    &format!(r"(?<=\S)\.{{3}}(?=\.\s{upper})", upper = upper),
+                    "ƪƪƪ",
+                )?
+                .with_example("Wait.... Then", "Waitƪƪƪ. Then"),
                 // Example: https://rubular.com/r/i60hCK81fz
-                Rule::new(r"\.\.\.(?=\s+[A-Z])", "☏☏.")?,
-                // OtherThreePeriodRule
-                Rule::new(r"\.\.\.", "ƪƪƪ")?,
-            ],
+                Rule::new(
+                    "ThreeConsecutiveRule",
+                    &format!(r"\.\.\.(?=\s+{upper})", upper = upper),
+                    "☏☏.",
+                )?
+                .with_example("Wait... Then", "Wait☏☏. Then"),
+                Rule::new("OtherThreePeriodRule", r"\.\.\.", "ƪƪƪ")?
+                    .with_example("Wait... then", "Waitƪƪƪ then"),
+            ]
+            .into(),
 
-            exclamation_regex: re(
-                r"!Xũ|!Kung|ǃʼOǃKung|!Xuun|!Kung\-Ekoka|ǃHu|ǃKhung|ǃKu|ǃung|ǃXo|ǃXû|ǃXung|ǃXũ|!Xun|Yahoo!|Y!J|Yum!",
-            )?,
+            exclamation_regex: re(&EXCLAMATION_WORDS
+                .iter()
+                .copied()
+                .chain(builder.exclamation_words.iter().map(String::as_str))
+                .map(regex::escape)
+                .collect::<Vec<_>>()
+                .join("|"))?,
 
             // NOTE: pySBD에 구현 실수가 있어 루비 구현체와 동작이 전혀 다르지만, pySBD의 동작을
             // 따르기 위해 버그를 유지하겠다.
-            sub_escaped_regex_reserved_characters: [
-                // SubLeftParen
-                Rule::new(r"\\\(", "(")?,
-                // SubRightParen
-                Rule::new(r"\\\)", ")")?,
-                // SubLeftBracket
-                Rule::new(r"\\\[", "[")?,
-                // SubRightBracket
-                Rule::new(r"\\\]", "]")?,
-                // SubDash
-                Rule::new(r"\\\-", "-")?,
-            ],
+            sub_escaped_regex_reserved_characters: vec![
+                Rule::new("SubLeftParen", r"\\\(", "(")?,
+                Rule::new("SubRightParen", r"\\\)", ")")?,
+                Rule::new("SubLeftBracket", r"\\\[", "[")?,
+                Rule::new("SubRightBracket", r"\\\]", "]")?,
+                Rule::new("SubDash", r"\\\-", "-")?,
+            ]
+            .into(),
 
             // Example: https://rubular.com/r/mXf8cW025o
             word_with_leading_apostrophe: re(r"(?<=\s)'(?:[^']|'[a-zA-Z])*'\S")?,
@@ -200,58 +497,284 @@ impl Segmenter {
 
             between_single_quote_slanted_regex: re(r"(?<=\s)‘(?:[^’]|’[a-zA-Z])*’")?,
 
-            // Example: https://regex101.com/r/r6I1bW/1
-            //
-            // NOTE: pySBD에선 파이썬 regex의 기능 한계로 인해 원본인 루비 pragmatic_segmenter와
-            // 동작이 다른데, 우리는 Oniguruma regex engine을 쓰고있으므로 루비 구현을 재현할 수
-            // 있다. 그러나 pySBD와 동작을 맞추기 위해 의도적으로 pySBD 정규표현식을 사용한다.
-            //
-            // NOTE: Python regex와 Oniguruma regex는 named capture group과 backreference 문법이
-            // 다르다. 주의
-            //
-            // Reference: https://stackoverflow.com/a/13577411/13977061
-            between_double_quotes_regex_2: re(r#""(?=(?<tmp>[^\"\\]+|\\{2}|\\.)*)\k<tmp>""#)?,
-            between_square_brackets_regex_2: re(r#"\[(?=(?<tmp>[^\]\\]+|\\{2}|\\.)*)\k<tmp>\]"#)?,
-            between_parens_regex_2: re(r"\((?=(?<tmp>[^\(\)\\]+|\\{2}|\\.)*)\k<tmp>\)")?,
-            between_quote_arrow_regex_2: re(r"\«(?=(?<tmp>[^»\\]+|\\{2}|\\.)*)\k<tmp>\»")?,
-            between_em_dashes_regex_2: re(r"--(?=(?<tmp>[^--]*))\k<tmp>--")?,
-            between_quote_slanted_regex_2: re(r"\“(?=(?<tmp>[^”\\]+|\\{2}|\\.)*)\k<tmp>\”")?,
+            between_punctuation: BetweenPunctuation::new(
+                between_punctuation::DEFAULT_PAIRS
+                    .iter()
+                    .map(|&(open, close)| (open.to_string(), close.to_string()))
+                    .chain(builder.enclosing_pairs.iter().cloned()),
+            ),
+            quotation_marks: BetweenPunctuation::new(
+                quotation::QUOTATION_MARKS
+                    .iter()
+                    .map(|&(open, close)| (open.to_string(), close.to_string())),
+            ),
 
             double_punctuation: re(r"^(?:\?!|!\?|\?\?|!!)")?,
-            question_mark_in_quotation_and_exclamation_point_rules: [
-                // QuestionMarkInQuotationRule
-                // Example: https://rubular.com/r/aXPUGm6fQh
-                Rule::new(r#"\?(?=(\'|\"))"#, "&ᓷ&")?,
-                // InQuotationRule
-                // Example: https://rubular.com/r/XS1XXFRfM2
-                Rule::new(r#"\!(?=(\'|\"))"#, "&ᓴ&")?,
-                // BeforeCommaMidSentenceRule
-                // Example: https://rubular.com/r/sl57YI8LkA
-                Rule::new(r"\!(?=\,\s[a-z])", "&ᓴ&")?,
-                // MidSentenceRule
-                // Example: https://rubular.com/r/f9zTjmkIPb
-                Rule::new(r"\!(?=\s[a-z])", "&ᓴ&")?,
-            ],
+            question_mark_in_quotation_and_exclamation_point_rules:
+                question_mark_in_quotation_and_exclamation_point_rules.into(),
 
             // Example: https://rubular.com/r/GcnmQt4a3I
             replace_parens: Rule::new(
-                // ROMAN_NUMERALS_IN_PARENTHESES
-                r"\(((?=[mdclxvi])m*(c[md]|d?c*)(x[cl]|l?x*)(i[xv]|v?i*))\)(?=\s[A-Z])",
+                "RomanNumeralsInParenthesesRule",
+                &builder.numerals_in_parens_pattern(),
                 r"&✂&\1&⌬&",
             )?,
 
             // added special case: r"[。．.！!?].*" to handle intermittent dots, exclamation, etc.
-            sentence_boundary_regex: re(
-                r#"（(?:[^）])*）(?=\s?[A-Z])|「(?:[^」])*」(?=\s[A-Z])|\((?:[^\)]){2,}\)(?=\s[A-Z])|\'(?:[^\'])*[^,]\'(?=\s[A-Z])|\"(?:[^\"])*[^,]\"(?=\s[A-Z])|\“(?:[^\”])*[^,]\”(?=\s[A-Z])|[。．.！!?？].*|\S.*?[。．.！!?？ȸȹ☉☈☇☄]"#,
-            )?,
+            sentence_boundary_regex: re(&format!(
+                r#"（(?:[^）])*）(?=\s?{start})|「(?:[^」])*」(?=\s{start})|\((?:[^\)]){{2,}}\)(?=\s{start})|\'(?:[^\'])*[^,]\'(?=\s{start})|\"(?:[^\"])*[^,]\"(?=\s{start})|\“(?:[^\”])*[^,]\”(?=\s{start})|[。．.！!?？{terminals}].*|\S.*?(?:[。．.！!?？ȸȹ☉☈☇☄]{added_terminal})"#,
+                start = start,
+                terminals = terminals,
+                added_terminal = added_terminal,
+            ))?,
+            punctuations: PUNCTUATIONS
+                .iter()
+                .chain(&builder.terminal_punctuations)
+                .copied()
+                .collect(),
             post_process_regex: re(r"\A[a-zA-Z]*\Z")?,
             // Example: https://rubular.com/r/NqCqv372Ix
-            quotation_at_end_of_sentence_regex: re(r#"[!?\.-][\"\'“”]\s{1}[A-Z]"#)?,
+            quotation_at_end_of_sentence_regex: re(&format!(
+                r#"[!?\.-][\"\'“”]\s{{1}}{start}"#,
+                start = start
+            ))?,
             // Example: https://rubular.com/r/JMjlZHAT4g
-            split_space_quotation_at_end_of_sentence_regex: re(
-                r#"(?<=[!?\.-][\"\'“”])\s{1}(?=[A-Z])"#,
-            )?,
-        })
+            split_space_quotation_at_end_of_sentence_regex: re(&format!(
+                r#"(?<=[!?\.-][\"\'“”])\s{{1}}(?={start})"#,
+                start = start
+            ))?,
+
+            protected_regions,
+
+            merge_unbalanced_quotes: builder.merge_unbalanced_quotes,
+            multiline_brackets: if builder.multiline_brackets {
+                Some(BetweenPunctuation::new(
+                    between_punctuation::BRACKETS
+                        .iter()
+                        .map(|&(open, close)| (open.to_string(), close.to_string())),
+                ))
+            } else {
+                None
+            },
+            collapse_whitespace: builder.collapse_whitespace,
+            fold_all_caps: builder.fold_all_caps,
+            repair_mojibake: builder.repair_mojibake,
+            literal_newlines: builder.literal_newlines,
+            speech: match &builder.speech_recognition {
+                Some(config) => Some(SpeechRules::new(config)?),
+                None => None,
+            },
+            datelines: if builder.datelines {
+                Some(Datelines::new()?)
+            } else {
+                None
+            },
+            colon_lists: if builder.colon_lists {
+                Some(ColonLists::new()?)
+            } else {
+                None
+            },
+            join_wrapped_lines: builder.join_wrapped_lines,
+            turn_parser: if builder.transcript {
+                Some(TurnParser::new()?)
+            } else {
+                None
+            },
+            post_process: builder.post_process,
+            max_length: builder.max_length,
+            boundary_scorer: builder.boundary_scorer.clone(),
+            merge_scorer: builder.merge_scorer.clone(),
+            merge_rules: builder.merge_rules.clone(),
+
+            // NOTE: Debug 출력에 builder의 모든 옵션이 들어있으므로, 옵션이 추가되어도 따로
+            // 신경쓸 필요가 없다.
+            fingerprint: fnv1a(format!("{}\0{:?}", VERSION_TAG, builder).as_bytes()),
+        };
+
+        for name in &builder.disabled_rules {
+            let mut found = false;
+            for rule in segmenter.rules_mut().filter(|rule| rule.name() == name) {
+                rule.disable();
+                found = true;
+            }
+            if !found {
+                return Err(UnknownRule(name.clone()).into());
+            }
+        }
+        Ok(segmenter)
+    }
+
+    /// Fingerprint of the behavior of this segmenter, computed from [`VERSION_TAG`] and every
+    /// option of the [`SegmenterBuilder`]. Two segmenters with the same fingerprint produce the
+    /// same output, so it can be stored along with cached segmentations to detect when they are
+    /// out of date. The value is stable across platforms and processes.
+    ///
+    /// The options are hashed through their [`Debug`](std::fmt::Debug) output, so code which
+    /// is not an option can't be told apart:
+    ///
+    /// - A [`BoundaryScorer`] is known by its `Debug` output only, and a
+    ///   [`merge_scorer()`](SegmenterBuilder::merge_scorer) closure by its threshold only.
+    /// - A [`Language`] added with [`register()`] counts through the options it sets. A new
+    ///   version of it which sets the same options under the same code has the same
+    ///   fingerprint.
+    ///
+    /// Mix a version of such code into the stored fingerprint when it matters.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let default = Segmenter::new()?;
+    /// let lenient = Segmenter::builder().lowercase_sentence_starts(true).build()?;
+    /// assert_eq!(default.fingerprint(), Segmenter::new()?.fingerprint());
+    /// assert_ne!(default.fingerprint(), lenient.fingerprint());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
+    /// The list item rules of this segmenter, for putting list items on their own lines without
+    /// a full segmentation. See [`ListItemReplacer`].
+    pub fn list_item_replacer(&self) -> &ListItemReplacer {
+        &self.list_item_replacer
+    }
+
+    /// The abbreviation rules of this segmenter, for protecting the periods of abbreviations
+    /// without a full segmentation. See [`AbbreviationReplacer`].
+    pub fn abbreviation_replacer(&self) -> &AbbreviationReplacer {
+        &self.abbreviation_replacer
+    }
+
+    /// Byte offsets of the periods in `text` which are judged to be periods of abbreviations
+    /// rather than sentence boundaries, with the reason. Only the abbreviation rules are
+    /// consulted, so periods protected by other rules (numbers, lists, quotations) are not
+    /// included.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::{AbbreviationCategory, Segmenter};
+    ///
+    /// let segmenter = Segmenter::new()?;
+    /// let text = "Dr. Kim came at 5 p.m. to No. 5 Main St. today.";
+    /// assert_eq!(
+    ///     segmenter.abbreviation_periods(text),
+    ///     vec![
+    ///         (2, AbbreviationCategory::Prepositive),
+    ///         (19, AbbreviationCategory::MultiPeriod),
+    ///         (21, AbbreviationCategory::MultiPeriod),
+    ///         (28, AbbreviationCategory::Number),
+    ///         (39, AbbreviationCategory::Prepositive),
+    ///     ]
+    /// );
+    /// assert_eq!(segmenter.abbreviation_at(text, 2), Some(AbbreviationCategory::Prepositive));
+    /// assert_eq!(segmenter.abbreviation_at(text, 46), None);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn abbreviation_periods(&self, text: &str) -> Vec<(usize, AbbreviationCategory)> {
+        self.abbreviation_replacer.abbreviation_periods(text)
+    }
+
+    /// Category of the abbreviation whose period is at byte offset `period` of `text`, or `None`
+    /// if the period is not judged to be a period of an abbreviation. See
+    /// [`abbreviation_periods()`](Segmenter::abbreviation_periods).
+    pub fn abbreviation_at(&self, text: &str, period: usize) -> Option<AbbreviationCategory> {
+        self.abbreviation_periods(text)
+            .into_iter()
+            .find(|&(idx, _)| idx == period)
+            .map(|(_, category)| category)
+    }
+
+    /// Every known abbreviation followed by a period in `text`, built-in or added with
+    /// [`abbreviations()`](SegmenterBuilder::abbreviations), along with the reason why its period
+    /// was not taken for a sentence boundary. Useful to audit why a text was not split at a
+    /// specific period. Letters are compared case insensitively, in ASCII only.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::{AbbreviationCategory, AbbreviationKind, Segmenter};
+    ///
+    /// let segmenter = Segmenter::new()?;
+    /// let text = "Dr. Kim lives on Main St. He left at 5 p.m. with Dr.phil. Lee Jr.";
+    /// let occurrences: Vec<_> = segmenter
+    ///     .abbreviation_occurrences(text)
+    ///     .into_iter()
+    ///     .map(|occurrence| (occurrence.text, occurrence.kind, occurrence.category))
+    ///     .collect();
+    /// assert_eq!(
+    ///     occurrences,
+    ///     vec![
+    ///         ("Dr.", AbbreviationKind::Prepositive, Some(AbbreviationCategory::Prepositive)),
+    ///         ("St.", AbbreviationKind::Prepositive, Some(AbbreviationCategory::Prepositive)),
+    ///         ("Dr.phil.", AbbreviationKind::Plain, None),
+    ///         ("Jr.", AbbreviationKind::Plain, None),
+    ///     ]
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn abbreviation_occurrences<'a>(&self, text: &'a str) -> Vec<AbbreviationOccurrence<'a>> {
+        let periods = self.abbreviation_periods(text);
+        self.abbreviation_replacer
+            .occurrences(text)
+            .into_iter()
+            .map(|(range, kind)| {
+                let period = range.end - 1;
+                let category = periods
+                    .iter()
+                    .find(|&&(idx, _)| idx == period)
+                    .map(|&(_, category)| category);
+                AbbreviationOccurrence {
+                    text: &text[range.clone()],
+                    start: range.start,
+                    kind,
+                    category,
+                }
+            })
+            .collect()
+    }
+
+    /// Every enabled [`Rule`] of this segmenter, roughly in the order they are applied. Some
+    /// steps of the segmentation are not rules but plain code, so they are not listed here. See
+    /// [`disable_rule()`](SegmenterBuilder::disable_rule) to turn off one of these rules.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::new()?;
+    /// assert!(segmenter.rules().any(|rule| rule.name() == "PeriodBeforeNumberRule"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn rules(&self) -> impl Iterator<Item = &Rule> {
+        self.list_item_replacer
+            .rules()
+            .chain(self.abbreviation_replacer.rules())
+            .chain(&self.salutation)
+            .chain(&self.number_rules)
+            .chain(&self.numbered_reference)
+            .chain(&self.misc_rules)
+            .chain(Some(&self.parens_between_double_quotes_0))
+            .chain(Some(&self.parens_between_double_quotes_1))
+            .chain(&self.ellipsis_rules)
+            .chain(&self.sub_escaped_regex_reserved_characters)
+            .chain(&self.question_mark_in_quotation_and_exclamation_point_rules)
+            .chain(Some(&self.replace_parens))
+            .filter(|rule| rule.is_enabled())
+    }
+
+    fn rules_mut(&mut self) -> impl Iterator<Item = &mut Rule> {
+        self.list_item_replacer
+            .rules_mut()
+            .chain(self.abbreviation_replacer.rules_mut())
+            .chain(&mut self.salutation)
+            .chain(self.number_rules.iter_mut())
+            .chain(&mut self.numbered_reference)
+            .chain(self.misc_rules.iter_mut())
+            .chain(Some(&mut self.parens_between_double_quotes_0))
+            .chain(Some(&mut self.parens_between_double_quotes_1))
+            .chain(self.ellipsis_rules.iter_mut())
+            .chain(self.sub_escaped_regex_reserved_characters.iter_mut())
+            .chain(
+                self.question_mark_in_quotation_and_exclamation_point_rules
+                    .iter_mut(),
+            )
+            .chain(Some(&mut self.replace_parens))
     }
 
     /// Separate sentences from given input. Although it is a function that returns an Iterator,
@@ -270,18 +793,586 @@ impl Segmenter {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn segment<'a>(&'a self, original_input: &'a str) -> impl Iterator<Item = &'a str> {
+        self.segments(original_input).map(|segment| segment.text)
+    }
+
+    /// Same as [`segment()`](Segmenter::segment), but also tells which segments are items of an
+    /// enumeration.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::{Kind, Segmenter};
+    ///
+    /// let segmenter = Segmenter::new()?;
+    /// let kinds: Vec<_> = segmenter
+    ///     .segments("Choose one. a. Red b. Green c. Blue")
+    ///     .map(|segment| segment.kind)
+    ///     .collect();
+    /// assert_eq!(
+    ///     kinds,
+    ///     vec![
+    ///         Kind::Sentence,
+    ///         Kind::ListItem { marker: "a." },
+    ///         Kind::ListItem { marker: "b." },
+    ///         Kind::ListItem { marker: "c." },
+    ///     ]
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn segments<'a>(&'a self, original_input: &'a str) -> impl Iterator<Item = Segment<'a>> {
+        let turns = match &self.turn_parser {
+            Some(parser) => parser.turns(original_input),
+            None => vec![Turn::whole(original_input)],
+        };
+
+        // 발화마다 따로 문장을 나누고, 발화의 첫 문장에 발화 접두사를 붙인다.
+        let segments = turns.into_iter().flat_map(move |turn| {
+            let turn_start = turn.prefix.start;
+            let prefix = &original_input[turn.prefix];
+            let speaker = turn.speaker.map(|range| &original_input[range]);
+            let offset = turn.body.start;
+            let mut sentences = self
+                .sentences(&original_input[turn.body.clone()], Tracer::default())
+                .peekable();
+
+            let lone_prefix = if !prefix.is_empty() && sentences.peek().is_none() {
+                Some(Segment {
+                    text: &original_input[turn_start..turn.body.end],
+                    start: turn_start,
+                    kind: Kind::Turn { prefix },
+                    speaker,
+                    overlong: false,
+                })
+            } else {
+                None
+            };
+            let mut first = !prefix.is_empty();
+            lone_prefix
+                .into_iter()
+                .chain(sentences.map(move |mut segment| {
+                    segment.start += offset;
+                    segment.speaker = speaker;
+                    if first {
+                        first = false;
+                        let end = segment.start + segment.text.len();
+                        segment.text = &original_input[turn_start..end];
+                        segment.start = turn_start;
+                        segment.kind = Kind::Turn { prefix };
+                    }
+                    segment
+                }))
+        });
+        self.rerank(original_input, segments)
+            .flat_map(move |segment| self.limit_length(segment))
+    }
+
+    /// Decide the ambiguous periods of `original_input` again with the
+    /// [`boundary_scorer()`](SegmenterBuilder::boundary_scorer), and merge the segments with the
+    /// [`merge_scorer()`](SegmenterBuilder::merge_scorer) and the
+    /// [`merge_rule()`](SegmenterBuilder::merge_rule)s, if there are ones.
+    fn rerank<'a>(
+        &self,
+        original_input: &'a str,
+        segments: impl Iterator<Item = Segment<'a>>,
+    ) -> impl Iterator<Item = Segment<'a>> {
+        // NOTE: 채점기나 병합 규칙이 없으면 세그먼트를 모으지 않고 그대로 흘려보낸다.
+        let (segments, reranked) = if self.boundary_scorer.is_none()
+            && self.merge_scorer.is_none()
+            && self.merge_rules.is_empty()
+        {
+            (Some(segments), None)
+        } else {
+            let mut segments: Vec<_> = segments.collect();
+            if let Some(scorer) = &self.boundary_scorer {
+                let decisions: Vec<_> = rerank::candidates(self, original_input, &segments)
+                    .into_iter()
+                    .map(|candidate| {
+                        let split = scorer.is_boundary(original_input, &candidate);
+                        (candidate.next, split)
+                    })
+                    .collect();
+                segments = rerank::apply(original_input, segments, decisions);
+            }
+            if let Some(scorer) = &self.merge_scorer {
+                segments = rerank::merge(original_input, segments, |before, after| {
+                    scorer.merges(before, after)
+                });
+            }
+            if !self.merge_rules.is_empty() {
+                segments = rerank::merge(original_input, segments, |before, after| {
+                    self.merge_rules
+                        .iter()
+                        .any(|rule| rule.merges(before, after))
+                });
+            }
+            (None, Some(segments))
+        };
+        segments
+            .into_iter()
+            .flatten()
+            .chain(reranked.into_iter().flatten())
+    }
+
+    /// Split or flag `segment` if it is longer than
+    /// [`max_length()`](SegmenterBuilder::max_length).
+    fn limit_length<'a>(&self, mut segment: Segment<'a>) -> impl Iterator<Item = Segment<'a>> {
+        let mut rest = Vec::new();
+        match self.max_length {
+            Some((max, overlong)) if segment.text.chars().count() > max => match overlong {
+                Overlong::Flag => segment.overlong = true,
+                Overlong::Split => {
+                    let mut pieces = clause::split(segment.text, max).into_iter();
+                    // NOTE: 길이가 max보다 긴 문자열이므로 조각이 반드시 두개 이상 나온다.
+                    let first = pieces.next().unwrap();
+                    let mut start = segment.start + first.len();
+                    for text in pieces {
+                        rest.push(Segment {
+                            text,
+                            start,
+                            kind: Kind::Clause,
+                            ..segment
+                        });
+                        start += text.len();
+                    }
+                    segment.text = first;
+                    // NOTE: 표지나 접두사 안에서 잘렸다면 그것이 더 이상 text의 슬라이스가 아니다.
+                    let head = match segment.kind {
+                        Kind::ListItem { marker } => marker.len(),
+                        Kind::Turn { prefix } => prefix.len(),
+                        _ => 0,
+                    };
+                    if head > first.len() {
+                        segment.kind = Kind::Clause;
+                    }
+                }
+            },
+            _ => {}
+        }
+        std::iter::once(segment).chain(rest)
+    }
+
+    /// The first `n` segments of `text`, which are the same as `segments(text).take(n)`. Most
+    /// of the work of [`segments()`](Segmenter::segments) is done on the whole input before the
+    /// first segment is returned, so this segments only a prefix of `text` which ends at a blank
+    /// line, and doubles the prefix until it has enough segments. Generating snippets of large
+    /// articles costs only as much as their beginnings.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::new()?;
+    /// let text = "Hi Mr. Kim. Let's meet at 3 P.M.\n\nSee you there.";
+    /// let first: Vec<_> = segmenter
+    ///     .first_sentences(text, 2)
+    ///     .into_iter()
+    ///     .map(|segment| segment.text)
+    ///     .collect();
+    /// assert_eq!(first, vec!["Hi Mr. Kim. ", "Let's meet at 3 P.M.\n\n"]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn first_sentences<'a>(&'a self, text: &'a str, n: usize) -> Vec<Segment<'a>> {
+        if n == 0 {
+            return Vec::new();
+        }
+        // 문장은 빈 줄을 넘어가지 않으므로, 빈 줄에서 자른 앞부분만 나눠도 결과가 같다. 단, 앞부분의
+        // 마지막 문장은 뒤따르는 공백이 달라질 수 있으므로 쓰지 않는다.
+        let mut len = FIRST_SENTENCES_CHUNK;
+        loop {
+            let end = paragraph_end(text, len);
+            let mut segments: Vec<_> = self.segments(&text[..end]).take(n + 1).collect();
+            if segments.len() > n || end == text.len() {
+                segments.truncate(n);
+                return segments;
+            }
+            len = end * 2;
+        }
+    }
+
+    /// Same as [`segments()`](Segmenter::segments), but also checks that the segments cover every
+    /// non-whitespace character of `text` exactly once. A segment which can't be found in the
+    /// input after the rules are applied is dropped silently by `segments()`, so this turns such
+    /// a data loss into an error which lists the lost ranges, and points at the first one with
+    /// its byte offset and a short excerpt.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::new()?;
+    /// let segments = segmenter.checked_segments("Hi Mr. Kim. Bye.")?;
+    /// assert_eq!(segments.len(), 2);
+    ///
+    /// // "∯" is used as a placeholder of periods during the segmentation
+    /// let error = segmenter.checked_segments("It costs 5∯ now. Ok.").unwrap_err();
+    /// assert_eq!(error.lost, vec![0..19]);
+    /// assert_eq!(error.offset, 0);
+    /// assert_eq!(error.excerpt, "It costs 5∯ now. Ok.");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn checked_segments<'a>(
+        &'a self,
+        text: &'a str,
+    ) -> Result<Vec<Segment<'a>>, CoverageError> {
+        let segments: Vec<_> = self.segments(text).collect();
+        CoverageError::check(text, &segments)?;
+        Ok(segments)
+    }
+
+    /// Every rule which changed `text` while it was segmented like
+    /// [`segments()`](Segmenter::segments), in the order they were applied, with the stage of
+    /// the pipeline and the text right after the change. Built-in steps which are not rules, like
+    /// the search for known abbreviations, are not recorded, and neither are the merges and the
+    /// splits after the rules. See [`trace`].
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::new()?;
+    /// let rules: Vec<_> = segmenter
+    ///     .explain("It costs 3.5 dollars. Bye.")
+    ///     .into_iter()
+    ///     .map(|step| step.rule)
+    ///     .collect();
+    /// assert_eq!(rules, vec!["PeriodBeforeNumberRule"]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn explain(&self, text: &str) -> Vec<Step> {
+        let steps = RefCell::new(Vec::new());
+        let turns = match &self.turn_parser {
+            Some(parser) => parser.turns(text),
+            None => vec![Turn::whole(text)],
+        };
+        for turn in turns {
+            self.sentences(&text[turn.body], Tracer::new(&steps))
+                .for_each(drop);
+        }
+        steps.into_inner()
+    }
+
+    /// Insert `delimiter` in place at the start of every segment of `buffer` but the first, and
+    /// return the number of delimiters inserted. No string is allocated for each segment, and
+    /// the buffer grows at most once, for preprocessing pipelines which write the result out
+    /// right away. Whitespaces after a sentence stay before the delimiter.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::new()?;
+    /// let mut buffer = String::from("Hi Mr. Kim. Let's meet at 3 P.M.\nSee you.");
+    /// assert_eq!(segmenter.insert_delimiters(&mut buffer, "\u{2029}"), 2);
+    /// assert_eq!(buffer, "Hi Mr. Kim. \u{2029}Let's meet at 3 P.M.\n\u{2029}See you.");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn insert_delimiters(&self, buffer: &mut String, delimiter: &str) -> usize {
+        let offsets = self.boundaries(buffer);
+        let mut bytes = mem::take(buffer).into_bytes();
+        insert_all(&mut bytes, &offsets, delimiter.as_bytes());
+        // NOTE: 구분자를 글자 경계에만 넣으므로 항상 UTF-8이다.
+        *buffer = String::from_utf8(bytes).unwrap();
+        offsets.len()
+    }
+
+    /// Same as [`insert_delimiters()`](Segmenter::insert_delimiters), but for a byte buffer and
+    /// delimiter bytes which need not be UTF-8, like `b"\0"`. Fails without touching `buffer` if
+    /// it is not UTF-8.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::new()?;
+    /// let mut buffer = b"Hi Mr. Kim. Bye.".to_vec();
+    /// assert_eq!(segmenter.insert_delimiter_bytes(&mut buffer, b"\0")?, 1);
+    /// assert_eq!(buffer, b"Hi Mr. Kim. \0Bye.");
+    ///
+    /// let mut invalid = b"Hi.\xff Bye.".to_vec();
+    /// assert!(segmenter.insert_delimiter_bytes(&mut invalid, b"\0").is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn insert_delimiter_bytes(
+        &self,
+        buffer: &mut Vec<u8>,
+        delimiter: &[u8],
+    ) -> Result<usize, Utf8Error> {
+        let offsets = self.boundaries(std::str::from_utf8(buffer)?);
+        insert_all(buffer, &offsets, delimiter);
+        Ok(offsets.len())
+    }
+
+    /// Byte offsets where every segment of `text` but the first starts.
+    fn boundaries(&self, text: &str) -> Vec<usize> {
+        self.segments(text)
+            .skip(1)
+            .map(|segment| segment.start)
+            .collect()
+    }
+
+    /// Summarize the structure of `text`: the number of paragraphs, headings and sentences, and
+    /// the enumerations found by the list item rules. Ingestion pipelines can use it to route
+    /// documents by their structure. Headings are detected heuristically. See [`DocStructure`].
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::{ListKind, Segmenter};
+    ///
+    /// let segmenter = Segmenter::new()?;
+    /// let text = "Usage\nInstall it first.\n\nSteps\n1. Open it. Wait.\n2. Close it.\n";
+    /// let structure = segmenter.analyze(text);
+    /// assert_eq!(structure.paragraphs, 2);
+    /// assert_eq!(structure.headings, 2);
+    /// assert_eq!(structure.sentences, 4);
+    /// assert_eq!(structure.lists.len(), 1);
+    /// assert_eq!(structure.lists[0].kind, ListKind::Numbered);
+    /// assert_eq!(structure.lists[0].items, 2);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn analyze(&self, text: &str) -> DocStructure {
+        DocStructure::new(text, self.segments(text), &self.list_item_replacer)
+    }
+
+    /// Segment `text`, and count the segments which are capitalized and which end with a
+    /// terminal punctuation, to tell well-edited prose from low-quality web text. See
+    /// [`Quality`].
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::new()?;
+    /// let article = segmenter.quality("Hi Mr. Kim. Let's meet at 3 P.M. See you.");
+    /// assert_eq!(article.score(), 1.0);
+    ///
+    /// let menu = segmenter.quality("home\nabout us\nContact\nlogin");
+    /// assert_eq!(menu.capitalized_ratio(), 0.25);
+    /// assert_eq!(menu.terminated_ratio(), 0.0);
+    /// assert!(menu.score() < 0.5);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn quality(&self, text: &str) -> Quality {
+        Quality::from_segments(&self.segments(text).collect::<Vec<_>>())
+    }
+
+    /// Every quoted span of `text` in straight or curly double quotes, single quotes or
+    /// guillemets, with the indices of the segments which it spans. Only the outermost quotation
+    /// is returned for nested ones. Quotation marks which are never closed are ignored, and a
+    /// quotation never spans a blank line. Apostrophes between two letters like "don't" are not
+    /// taken for quotation marks.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::new()?;
+    /// let text = "Kim said: “We won't stop. Never.” Lee said «non». It's over.";
+    /// let quotations: Vec<_> = segmenter
+    ///     .quotations(text)
+    ///     .into_iter()
+    ///     .map(|quotation| (quotation.content(), quotation.sentences))
+    ///     .collect();
+    /// assert_eq!(
+    ///     quotations,
+    ///     vec![("We won't stop. Never.", 0..1), ("non", 1..2)]
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn quotations<'a>(&self, text: &'a str) -> Vec<Quotation<'a>> {
+        let ends: Vec<_> = self
+            .segments(text)
+            .map(|segment| segment.start + segment.text.len())
+            .collect();
+        let masked = quotation::mask_apostrophes(text);
+        self.quotation_marks
+            .enclosed_ranges_by_paragraph(&masked)
+            .into_iter()
+            .map(|range| {
+                let first = ends.partition_point(|&end| end <= range.start);
+                let last = ends.partition_point(|&end| end < range.end);
+                Quotation {
+                    text: &text[range.clone()],
+                    start: range.start,
+                    sentences: first..(last + 1).min(ends.len()),
+                }
+            })
+            .collect()
+    }
+
+    fn sentences<'a>(
+        &'a self,
+        original_input: &'a str,
+        tracer: Tracer<'a>,
+    ) -> impl Iterator<Item = Segment<'a>> {
+        let repaired = if self.repair_mojibake {
+            RepairedMojibake::new(original_input)
+        } else {
+            None
+        };
+        let repaired_text = repaired
+            .as_ref()
+            .map_or(original_input, |r| r.text.as_str());
+        let folded = if self.fold_all_caps {
+            all_caps::fold(repaired_text)
+        } else {
+            None
+        };
+        // NOTE: 대문자를 소문자로 바꿔도 바이트 위치는 그대로이므로, 바꾼 텍스트의 위치를 원문에
+        // 그대로 쓸 수 있다.
+        let text = folded.as_deref().unwrap_or(repaired_text);
+        let collapsed = if self.collapse_whitespace {
+            CollapsedWhitespace::new(text)
+        } else {
+            None
+        };
+        let unrepaired = |offset| {
+            repaired
+                .as_ref()
+                .map_or(offset, |r| r.original_offset(offset))
+        };
+
+        // 고치거나 소문자로 바꾸거나 공백을 줄인 텍스트에서 문장을 나눈 뒤, 원문의 위치로
+        // 되돌린다.
+        let remapped = match &collapsed {
+            Some(collapsed) => Some(
+                self.merged_sentences(&collapsed.text, tracer)
+                    .map(|segment| {
+                        position(&segment, |offset| {
+                            unrepaired(collapsed.original_offset(offset))
+                        })
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+            None if repaired.is_some() || folded.is_some() => Some(
+                self.merged_sentences(text, tracer)
+                    .map(|segment| position(&segment, unrepaired))
+                    .collect(),
+            ),
+            None => None,
+        };
+        let plain = match remapped {
+            Some(_) => None,
+            None => Some(self.merged_sentences(original_input, tracer)),
+        };
+        plain
+            .into_iter()
+            .flatten()
+            .chain(
+                remapped
+                    .into_iter()
+                    .flatten()
+                    .map(move |(start, end, marker_end, dateline)| Segment {
+                        text: &original_input[start..end],
+                        start,
+                        kind: match marker_end {
+                            Some(marker_end) => Kind::ListItem {
+                                marker: &original_input[start..marker_end],
+                            },
+                            None if dateline => Kind::Dateline,
+                            None => Kind::Sentence,
+                        },
+                        speaker: None,
+                        overlong: false,
+                    }),
+            )
+    }
+
+    fn merged_sentences<'a>(
+        &'a self,
+        original_input: &'a str,
+        tracer: Tracer<'a>,
+    ) -> impl Iterator<Item = Segment<'a>> {
+        let mut ranges = if self.merge_unbalanced_quotes {
+            self.between_punctuation
+                .enclosed_ranges_by_paragraph(original_input)
+        } else {
+            Vec::new()
+        };
+        // 여러 줄에 걸친 괄호는 줄바꿈에서 나뉘므로, 괄호 안의 문장 경계를 같은 방법으로 없앤다.
+        if let Some(brackets) = &self.multiline_brackets {
+            ranges.extend(
+                brackets
+                    .enclosed_ranges_by_paragraph(original_input)
+                    .into_iter()
+                    .filter(|range| original_input[range.clone()].contains('\n')),
+            );
+            ranges.sort_by_key(|range| range.start);
+        }
+        let mut ranges = ranges.into_iter().peekable();
+        let mut segments = self.split(original_input, tracer).peekable();
+
+        // 문장 경계가 따옴표나 괄호 안에 있으면 다음 문장과 합친다.
+        std::iter::from_fn(move || {
+            let mut segment = segments.next()?;
+            loop {
+                let end = segment.start + segment.text.len();
+                while ranges.next_if(|range| range.end <= end).is_some() {}
+                match (ranges.peek(), segments.peek()) {
+                    (Some(range), Some(_)) if range.start < end => {
+                        let next = segments.next().unwrap(); // Must exist
+                        let next_end = next.start + next.text.len();
+                        segment.text = &original_input[segment.start..next_end];
+                    }
+                    // 추임새만 있는 문장이나 문장 중간에서 끊긴 줄은 다음 문장에 붙인다.
+                    (_, Some(next))
+                        if self
+                            .speech
+                            .as_ref()
+                            .is_some_and(|speech| speech.is_filler(segment.text))
+                            || self.join_wrapped_lines
+                                && is_wrapped_line(segment.text, next.text) =>
+                    {
+                        let next = segments.next().unwrap(); // Must exist
+                        let next_end = next.start + next.text.len();
+                        segment.text = &original_input[segment.start..next_end];
+                    }
+                    _ => return Some(segment),
+                }
+            }
+        })
+    }
+
+    fn split<'a>(
+        &'a self,
+        original_input: &'a str,
+        tracer: Tracer<'a>,
+    ) -> impl Iterator<Item = Segment<'a>> {
+        // 기사 첫머리의 데이트라인 뒤에 줄바꿈을 넣어 첫 문장과 나눈다.
+        let datelines = match &self.datelines {
+            Some(datelines) => datelines.find(original_input),
+            None => Vec::new(),
+        };
+        // 콜론 뒤에 같은 줄로 이어지는 목록의 항목마다 앞에 줄바꿈을 넣는다.
+        let mut line_breaks: Vec<_> = datelines.iter().map(|range| range.end).collect();
+        if let Some(colon_lists) = &self.colon_lists {
+            line_breaks.extend(colon_lists.find(original_input));
+        }
+        line_breaks.sort_unstable();
+        let mut text = Cow::Borrowed(original_input);
+        for &idx in line_breaks.iter().rev() {
+            text.to_mut().insert(idx, '\r');
+        }
+        let dateline_starts: Vec<_> = datelines.into_iter().map(|range| range.start).collect();
+
+        // 사용자가 지정한 영역 안의 문장부호를 가린다. SubSymbolsRules에서 복원된다.
+        for regex in &self.protected_regions {
+            text = Cow::Owned(regex.replace_all(
+                &text,
+                self.replace_punctuation(false, Stage::Document, tracer),
+            ));
+        }
+
         // NOTE: 루비 버전에는 이런 처리가 없으나, pySBD 3.1.0에 이 처리가 들어갔다. pySBD와 동작을
         // 맞추기위해 동일하게 처리해준다.
-        let text = original_input.replace('\n', "\r");
+        let mut text = text.replace('\n', "\r");
 
-        let text = self.list_item_replacer.add_line_break(&text);
+        if let Some(speech) = &self.speech {
+            text = speech.add_line_breaks(&text);
+        }
+
+        let text = self.list_item_replacer.mark_list_items(&text, tracer);
 
         // replace_abbreviations()
-        let mut text = self.abbreviation_replacer.replace(&text);
+        let mut text = self.abbreviation_replacer.replace_traced(&text, tracer);
+
+        if let Some(rule) = &self.salutation {
+            text = tracer.apply(Stage::Document, rule, &text);
+        }
 
         // replace_numbers()
         for rule in &self.number_rules {
-            text = rule.replace_all(&text);
+            text = tracer.apply(Stage::Document, rule, &text);
         }
 
         // replace_continuous_punctuation()
@@ -296,13 +1387,16 @@ impl Segmenter {
         //
         // Reference:
         //   https://github.com/diasks2/pragmatic_segmenter/commit/d9ec1a35
-        let text = self.numbered_reference.replace_all(&text);
+        let text = match &self.numbered_reference {
+            Some(rule) => tracer.apply(Stage::Document, rule, &text),
+            None => text,
+        };
 
         let mut text = self
             .abbreviation_with_multiple_periods_and_email_regex
             .replace_all(&text, "$1∮$2");
         for rule in &self.misc_rules {
-            text = Cow::Owned(rule.replace_all(&text));
+            text = Cow::Owned(tracer.apply(Stage::Document, rule, &text));
         }
 
         //
@@ -314,66 +1408,66 @@ impl Segmenter {
             .parens_between_double_quotes_regex
             .replace_all(&text, |c: &Captures| {
                 let mat = c.at(0).unwrap(); // Must exists
-                let mat = self.parens_between_double_quotes_0.replace_all(mat);
-                self.parens_between_double_quotes_1.replace_all(&mat)
+                let mat = tracer.apply(Stage::Document, &self.parens_between_double_quotes_0, mat);
+                tracer.apply(Stage::Document, &self.parens_between_double_quotes_1, &mat)
             });
 
-        let mut prior_start_char_idx = 0;
+        let mut prior_end_char_idx = 0;
 
         // TODO: flat_map() 에서 임시 Vec, String 할당 줄이기
         text.split('\r')
-            .filter(|s| !s.is_empty())
-            .map(|s| s.to_string())
+            .enumerate()
+            .filter(|(_, s)| !s.is_empty())
+            .map(|(idx, s)| {
+                (
+                    self.list_item_replacer.list_marker(s, idx > 0),
+                    s.to_string(),
+                )
+            })
             .collect::<Vec<_>>() // String을 own하는 버전의 새 split 함수를 만들면 이부분을 제거할 수 있음
             .into_iter()
-            .flat_map(move |sent| {
+            // list item marker는 각 줄에서 처음으로 나오는 문장에만 붙여준다.
+            .flat_map(move |(marker, sent)| {
                 // English.SingleNewLineRule
-                let mut sent = sent.replace(r"\n", "ȹ");
+                //
+                // NOTE: pySBD는 항상 글자 그대로의 "\n"을 줄바꿈으로 바꾸지만, 코드 조각처럼 원래
+                // 두 글자인 경우가 있으므로 옵션을 켰을 때만 바꾼다.
+                let mut sent = if self.literal_newlines {
+                    sent.replace(r"\n", "ȹ")
+                } else {
+                    sent
+                };
                 // English.EllipsisRules.All
                 for rule in &self.ellipsis_rules {
-                    sent = rule.replace_all(&sent);
+                    sent = tracer.apply(Stage::Line, rule, &sent);
                 }
                 // check_for_punctuation()
-                if PUNCTUATIONS.iter().any(|&p| sent.contains(p)) {
+                if self.punctuations.iter().any(|&p| sent.contains(p)) {
                     // process_text()
-                    if !sent.ends_with(&PUNCTUATIONS[..]) {
+                    if !sent.ends_with(&self.punctuations[..]) {
                         sent += "ȸ";
                     }
 
                     // ExclamationWords.apply_rules()
                     sent = self
                         .exclamation_regex
-                        .replace_all(&sent, self.replace_punctuation(false));
+                        .replace_all(&sent, self.replace_punctuation(false, Stage::Line, tracer));
 
                     // between_punctuation()
                     if self.word_with_leading_apostrophe.find(&sent).is_none()
                         || self.trailing_apostrophe.find(&sent).is_some()
                     {
-                        sent = self
-                            .between_single_quotes_regex
-                            .replace_all(&sent, self.replace_punctuation(true));
+                        sent = self.between_single_quotes_regex.replace_all(
+                            &sent,
+                            self.replace_punctuation(true, Stage::Line, tracer),
+                        );
                     }
                     sent = self
                         .between_single_quote_slanted_regex
-                        .replace_all(&sent, self.replace_punctuation(false));
-                    sent = self
-                        .between_double_quotes_regex_2
-                        .replace_all(&sent, self.replace_punctuation(false));
-                    sent = self
-                        .between_square_brackets_regex_2
-                        .replace_all(&sent, self.replace_punctuation(false));
-                    sent = self
-                        .between_parens_regex_2
-                        .replace_all(&sent, self.replace_punctuation(false));
-                    sent = self
-                        .between_quote_arrow_regex_2
-                        .replace_all(&sent, self.replace_punctuation(false));
-                    sent = self
-                        .between_em_dashes_regex_2
-                        .replace_all(&sent, self.replace_punctuation(false));
-                    sent = self
-                        .between_quote_slanted_regex_2
-                        .replace_all(&sent, self.replace_punctuation(false));
+                        .replace_all(&sent, self.replace_punctuation(false, Stage::Line, tracer));
+                    sent = self.between_punctuation.replace_all(&sent, |mat| {
+                        self.sub_punctuation(mat, false, Stage::Line, tracer)
+                    });
 
                     // handle text having only doublepunctuations
                     if self.double_punctuation.find(&sent).is_none() {
@@ -384,24 +1478,25 @@ impl Segmenter {
                             .replace(r"!!", "☄");
                     }
                     for rule in &self.question_mark_in_quotation_and_exclamation_point_rules {
-                        sent = rule.replace_all(&sent);
+                        sent = tracer.apply(Stage::Line, rule, &sent);
                     }
 
                     // ListItemReplacer(sent).replace_parens()
-                    sent = self.replace_parens.replace_all(&sent);
+                    sent = tracer.apply(Stage::Line, &self.replace_parens, &sent);
 
                     // sentence_boundary_punctuation()
                     // retain exclamation mark if it is an ending character of a given text
                     sent = sent.replace(r"&ᓴ&$", "!");
+                    let mut marker = marker;
                     self.sentence_boundary_regex
                         .find_iter(&sent)
-                        .map(|r| sent[r.0..r.1].to_string())
+                        .map(|r| (marker.take(), sent[r.0..r.1].to_string()))
                         .collect::<Vec<_>>()
                 } else {
-                    vec![sent]
+                    vec![(marker, sent)]
                 }
             })
-            .flat_map(move |mut sent| {
+            .flat_map(move |(marker, mut sent)| {
                 // SubSymbolsRules
                 sent = sent
                     .replace('∯', ".")
@@ -425,9 +1520,12 @@ impl Segmenter {
                 // post_process_segments()
                 //
                 // NOTE: post_process_segments 함수는 pySBD와 루비 pragmatic_segmenter의 동작이 전혀
-                // 다르다. pySBD를 따라간다.
-                if sent.len() > 2 && self.post_process_regex.find(&sent).is_some() {
-                    return vec![sent];
+                // 다르다. 기본적으로 pySBD를 따라간다.
+                if self.post_process == PostProcess::Compatible
+                    && sent.len() > 2
+                    && self.post_process_regex.find(&sent).is_some()
+                {
+                    return vec![(marker, sent)];
                 }
 
                 // ReinsertEllipsisRules
@@ -444,69 +1542,149 @@ impl Segmenter {
                     .find(&sent)
                     .is_some()
                 {
-                    self.split_space_quotation_at_end_of_sentence_regex
-                        .split(&sent)
-                        .map(|s| s.to_string())
-                        .collect()
+                    // NOTE: pySBD는 re.split()으로 공백을 삼켜버리는데, 여기선 계산한 인덱스에서
+                    // 잘라서 공백을 앞 문장에 붙여둔다. 글자가 하나도 사라지지 않으므로 아래에서
+                    // 원문의 span을 찾을 때 실패하지 않는다.
+                    let mut marker = marker;
+                    let mut pieces = Vec::new();
+                    let mut last = 0;
+                    for (_, end) in self
+                        .split_space_quotation_at_end_of_sentence_regex
+                        .find_iter(&sent)
+                    {
+                        pieces.push((marker.take(), sent[last..end].to_string()));
+                        last = end;
+                    }
+                    pieces.push((marker, sent[last..].to_string()));
+                    pieces
                 } else {
-                    vec![sent.replace('\n', "").trim().to_string()]
+                    vec![(marker, sent.replace('\n', "").trim().to_string())]
                 }
             })
-            .map(|sent| sent.replace(r"&⎋&", "'"))
+            .map(|(marker, sent)| (marker, sent.replace(r"&⎋&", "'")))
             // NOTE: pySBD에만 이하의 처리가 존재하고, 원본 루비코드에는 이런 동작이 없다. 일단
             // 동작을 맞추기 위해 동일한 처리를 해주지만, 아래 코드때문에 성능손실이 크다.
-            .flat_map(move |sent| -> Vec<_> {
+            .filter_map(move |(marker, sent)| {
                 // since SENTENCE_BOUNDARY_REGEX doesnt account
                 // for trailing whitespaces \s* & is used as suffix
                 // to keep non-destructive text after segments joins
 
                 // NOTE: escape 한 뒤 compile했기 때문에, 실패의 여지가 없다.
                 let re = regex::Regex::new(&format!(r"{}\s*", regex::escape(&sent))).unwrap();
-                re.find_iter(original_input)
-                    .filter_map(|mat| {
-                        let match_str = mat.as_str();
-                        let match_start_idx = mat.start();
-                        if match_start_idx >= prior_start_char_idx {
-                            prior_start_char_idx = match_start_idx;
-                            Some(match_str)
-                        // making sure if curren sentence and its span
-                        // is either first sentence along with its char spans
-                        // or current sent spans adjacent to prior sentence spans
-                        } else {
-                            None
-                        }
-                    })
-                    .collect()
+                let mat = re
+                    .find_iter(original_input)
+                    // making sure if curren sentence and its span
+                    // is either first sentence along with its char spans
+                    // or current sent spans adjacent to prior sentence spans
+                    .find(|mat| mat.start() >= prior_end_char_idx)?;
+                prior_end_char_idx = mat.end();
+
+                let text = mat.as_str();
+                let kind = match marker {
+                    Some(marker) if text.starts_with(&marker) => Kind::ListItem {
+                        marker: &text[..marker.len()],
+                    },
+                    _ if dateline_starts.contains(&mat.start()) => Kind::Dateline,
+                    _ => Kind::Sentence,
+                };
+                Some(Segment {
+                    text,
+                    start: mat.start(),
+                    kind,
+                    speaker: None,
+                    overlong: false,
+                })
             })
     }
 
-    fn replace_punctuation(&self, is_match_type_single: bool) -> impl Fn(&Captures) -> String + '_ {
+    fn replace_punctuation<'t>(
+        &'t self,
+        is_match_type_single: bool,
+        stage: Stage,
+        tracer: Tracer<'t>,
+    ) -> impl Fn(&Captures) -> String + 't {
         move |c: &Captures| {
             let mat = c.at(0).unwrap(); // Must exists
+            self.sub_punctuation(mat, is_match_type_single, stage, tracer)
+        }
+    }
 
-            // NOTE: 원래 이 자리에서 EscapeRegexReservedCharacters.All 규칙이 적용되어야
-            // 하나, pySBD의 구현 버그로 인해 EscapeRegexReservedCharacters.All가 아무일도
-            // 하지 않는다. 버그이지만, pySBD의 동작을 따라가기위해 버그를 유지하겠다.
-
-            let mut mat = mat.replace('.', "∯");
-            mat = mat.replace('。', "&ᓰ&");
-            mat = mat.replace('．', "&ᓱ&");
-            mat = mat.replace('！', "&ᓳ&");
-            mat = mat.replace('!', "&ᓴ&");
-            mat = mat.replace('?', "&ᓷ&");
-            mat = mat.replace('？', "&ᓸ&");
-            if !is_match_type_single {
-                mat = mat.replace('\'', "&⎋&");
-            }
-            for rule in &self.sub_escaped_regex_reserved_characters {
-                mat = rule.replace_all(&mat);
-            }
-            mat
+    fn sub_punctuation(
+        &self,
+        mat: &str,
+        is_match_type_single: bool,
+        stage: Stage,
+        tracer: Tracer<'_>,
+    ) -> String {
+        // NOTE: 원래 이 자리에서 EscapeRegexReservedCharacters.All 규칙이 적용되어야
+        // 하나, pySBD의 구현 버그로 인해 EscapeRegexReservedCharacters.All가 아무일도
+        // 하지 않는다. 버그이지만, pySBD의 동작을 따라가기위해 버그를 유지하겠다.
+
+        let mut mat = mat.replace('.', "∯");
+        mat = mat.replace('。', "&ᓰ&");
+        mat = mat.replace('．', "&ᓱ&");
+        mat = mat.replace('！', "&ᓳ&");
+        mat = mat.replace('!', "&ᓴ&");
+        mat = mat.replace('?', "&ᓷ&");
+        mat = mat.replace('？', "&ᓸ&");
+        if !is_match_type_single {
+            mat = mat.replace('\'', "&⎋&");
+        }
+        for rule in &self.sub_escaped_regex_reserved_characters {
+            mat = tracer.apply(stage, rule, &mat);
+        }
+        mat
+    }
+}
+
+/// Whether `segment` ends with a single line break in the middle of a sentence: the line doesn't
+/// end with a punctuation, and `next` starts with a lowercase letter.
+#[cfg(feature = "std")]
+fn is_wrapped_line(segment: &str, next: &str) -> bool {
+    let line = segment.trim_end();
+    segment[line.len()..].matches('\n').count() == 1
+        && !line.ends_with(|c: char| ".!?:;…".contains(c))
+        && next.starts_with(char::is_lowercase)
+}
+
+/// End of the first blank line at or after byte offset `from` of `text`, including every
+/// whitespace after it. `text.len()` if there is no such blank line.
+#[cfg(feature = "std")]
+fn paragraph_end(text: &str, mut from: usize) -> usize {
+    if from >= text.len() {
+        return text.len();
+    }
+    while !text.is_char_boundary(from) {
+        from += 1;
+    }
+    let mut prev = None;
+    for (idx, _) in text[from..].match_indices('\n') {
+        let idx = from + idx;
+        if prev.is_some_and(|prev| text[prev..idx].trim().is_empty()) {
+            return text.len() - text[idx..].trim_start().len();
         }
+        prev = Some(idx);
     }
+    text.len()
+}
+
+/// Byte range, end of the list item marker, and whether it is a dateline, of `segment` in the
+/// original text, where `original_offset` maps a byte offset of the text which was segmented.
+#[cfg(feature = "std")]
+fn position(
+    segment: &Segment,
+    original_offset: impl Fn(usize) -> usize,
+) -> (usize, usize, Option<usize>, bool) {
+    let start = original_offset(segment.start);
+    let end = original_offset(segment.start + segment.text.len());
+    let marker_end = match segment.kind {
+        Kind::ListItem { marker } => Some(original_offset(segment.start + marker.len())),
+        _ => None,
+    };
+    (start, end, marker_end, segment.kind == Kind::Dateline)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
     use std::error::Error;
@@ -528,4 +1706,549 @@ mod tests {
         assert_eq!(actual, expected);
         Ok(())
     }
+
+    #[test]
+    fn invalid_abbreviation_pack() {
+        let pack = AbbreviationPack::new("bad").with("a(b", AbbreviationKind::Plain);
+        assert!(Segmenter::builder().abbreviations(pack).build().is_err());
+    }
+
+    #[test]
+    fn rules_are_named() -> TestResult {
+        let seg = Segmenter::new()?;
+        assert!(seg.rules().all(|rule| !rule.name().is_empty()));
+        Ok(())
+    }
+
+    #[test]
+    fn disable_rule() -> TestResult {
+        let seg = Segmenter::new()?;
+        for rule in seg.rules() {
+            let disabled = Segmenter::builder().disable_rule(rule.name()).build()?;
+            assert!(disabled.rules().all(|other| other.name() != rule.name()));
+            assert_ne!(disabled.fingerprint(), seg.fingerprint());
+        }
+
+        let err = Segmenter::builder()
+            .disable_rule("NoSuchRule")
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(err.to_string(), r#"unknown rule: "NoSuchRule""#);
+        Ok(())
+    }
+
+    #[test]
+    fn rule_examples() -> TestResult {
+        let seg = Segmenter::new()?;
+        for rule in seg.rules() {
+            rule.check_examples()?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_protected_pattern() {
+        assert!(Segmenter::builder().protected_pattern("(").build().is_err());
+    }
+
+    #[test]
+    fn protected_pattern() -> TestResult {
+        let seg = Segmenter::builder()
+            .protected_pattern(r"pH \d+\.\d+ sol\.")
+            .build()?;
+        let input = "It's pH 7.4 sol. 'Tris' was added! Done.";
+        let actual: Vec<_> = seg.segment(input).collect();
+        assert_eq!(actual, vec!["It's pH 7.4 sol. 'Tris' was added! ", "Done."]);
+        Ok(())
+    }
+
+    #[test]
+    fn nested_enclosing_punctuations() -> TestResult {
+        let seg = Segmenter::new()?;
+        let input = "A (nested (deep. inside) thing.) here. Next.";
+        let actual: Vec<_> = seg.segment(input).collect();
+        assert_eq!(
+            actual,
+            vec!["A (nested (deep. inside) thing.) here. ", "Next."]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn merge_unbalanced_quotes() -> TestResult {
+        let seg = Segmenter::builder().merge_unbalanced_quotes(true).build()?;
+        let input = "He said (see\nbelow. It is long.) ok. \"Open.\n\nNew one. Next.";
+        let actual: Vec<_> = seg.segment(input).collect();
+        assert_eq!(
+            actual,
+            vec![
+                "He said (see\nbelow. It is long.) ok. ",
+                "\"Open.\n\n",
+                "New one. ",
+                "Next."
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn multiline_brackets() -> TestResult {
+        let seg = Segmenter::builder().multiline_brackets(true).build()?;
+        let cases: &[&[&str]] = &[
+            &["The term [as used\nherein. It applies] is broad. ", "Next."],
+            &["A \"quote.\n", "Next\" (one. Two.) ok."],
+            &["Open (never closed.\n", "Next line."],
+            &["It (spans\n\n", "paragraphs) here. ", "Ok."],
+        ];
+        for &expected in cases {
+            let input = expected.concat();
+            let actual: Vec<_> = seg.segment(&input).collect();
+            assert_eq!(actual, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn max_length() -> TestResult {
+        let seg = Segmenter::builder()
+            .max_length(12, Overlong::Split)
+            .build()?;
+        let cases: &[&[&str]] = &[
+            &["Short one. ", "A long one, ", "with a ", "comma. ", "Ok."],
+            &["Runonwithout", "anyspaces."],
+            &["긴 문장이 여기에, ", "쉼표 뒤에 있다."],
+        ];
+        for &expected in cases {
+            let input = expected.concat();
+            let actual: Vec<_> = seg.segment(&input).collect();
+            assert_eq!(actual, expected);
+            for segment in seg.segments(&input) {
+                assert_eq!(&input[segment.start..][..segment.text.len()], segment.text);
+            }
+        }
+
+        let kinds: Vec<_> = seg
+            .segments("Short one. A long one, with a comma.")
+            .map(|segment| segment.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![Kind::Sentence, Kind::Sentence, Kind::Clause, Kind::Clause]
+        );
+
+        let seg = Segmenter::builder()
+            .max_length(12, Overlong::Flag)
+            .build()?;
+        let flags: Vec<_> = seg
+            .segments("Short one. A long one, with a comma.")
+            .map(|segment| segment.overlong)
+            .collect();
+        assert_eq!(flags, vec![false, true]);
+
+        let seg = Segmenter::builder()
+            .transcript(true)
+            .max_length(8, Overlong::Split)
+            .build()?;
+        for segment in seg.segments("SPEAKER 1: Hello there my friend. Bye.") {
+            match segment.kind {
+                Kind::Turn { prefix } => assert!(segment.text.starts_with(prefix)),
+                Kind::ListItem { marker } => assert!(segment.text.starts_with(marker)),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn custom_uppercase_letters() -> TestResult {
+        let builder = Segmenter::builder()
+            .uppercase_letters(UppercaseLetters::Custom("[A-ZⅠ-Ⅻ]".to_string()));
+        let lower = re(&builder.lowercase_pattern())?;
+        for letter in &["a", "É", "は"] {
+            assert!(lower.is_match(letter), "{}", letter);
+        }
+        for letter in &["A", "Ⅳ", "1"] {
+            assert!(!lower.is_match(letter), "{}", letter);
+        }
+        let letters = re(&format!("[{}]", builder.letter_class()))?;
+        for letter in &["a", "É", "は", "Ⅳ"] {
+            assert!(letters.is_match(letter), "{}", letter);
+        }
+        assert!(!letters.is_match("1"));
+        Ok(())
+    }
+
+    #[test]
+    fn salutations() -> TestResult {
+        let seg = Segmenter::builder().salutations(true).build()?;
+        seg.rules().try_for_each(Rule::check_examples)?;
+
+        let cases: &[&[&str]] = &[
+            &["Dear Mr. Kim, ", "I hope you are well. ", "Bye."],
+            &["RE: Contract No. 5\n", "Hi John,\n", "Please sign it."],
+            &["Good morning! ", "The meeting moved."],
+            &["He said hi, then left."],
+        ];
+        for &expected in cases {
+            let input = expected.concat();
+            let actual: Vec<_> = seg.segment(&input).collect();
+            assert_eq!(actual, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn speech_recognition() -> TestResult {
+        let seg = Segmenter::builder()
+            .speech_recognition(SpeechRecognition::default().pause_markers(["[pause]"]))
+            .build()?;
+        let cases: &[&[&str]] = &[
+            &[
+                "ok we are live [pause] ",
+                "uh Um [pause] so today we start ",
+                "So first the plan",
+            ],
+            &["Hmm. It works. ", "Then it stops."],
+            &["Hello there and welcome back"],
+        ];
+        for &expected in cases {
+            let input = expected.concat();
+            let actual: Vec<_> = seg.segment(&input).collect();
+            assert_eq!(actual, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn first_sentences() -> TestResult {
+        let seg = Segmenter::new()?;
+        let paragraph = "Hi Mr. Kim. Let's meet at 3 P.M.\nSee you.\n \n";
+        let text = paragraph.repeat(100) + "The end.";
+        let all: Vec<_> = seg.segments(&text).collect();
+        for n in [0, 1, 4, all.len() + 1] {
+            assert_eq!(seg.first_sentences(&text, n), all[..n.min(all.len())]);
+        }
+        assert_eq!(paragraph_end(&text, 0), paragraph.len());
+        assert_eq!(paragraph_end("a\n\n", 3), 3);
+        assert_eq!(paragraph_end("Ç\n\nb", 1), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn analyze() -> TestResult {
+        let seg = Segmenter::new()?;
+        let text = "Introduction\nThis is a doc. It has lists.\n\nSteps\n1. Open it. Then wait.\n2. Close it.\nDone now.\n\ni) one ii) two iii) three\na. Red b. Green\n# Title\nText here.";
+        let structure = seg.analyze(text);
+        assert_eq!(structure.paragraphs, 3);
+        assert_eq!(structure.headings, 3);
+        assert_eq!(structure.sentences, 12);
+        let lists: Vec<_> = structure
+            .lists
+            .iter()
+            .map(|list| (list.kind, &text[list.start..], list.items))
+            .map(|(kind, rest, items)| (kind, rest.split(' ').next().unwrap(), items))
+            .collect();
+        assert_eq!(
+            lists,
+            vec![
+                (ListKind::Numbered, "1.", 2),
+                (ListKind::Roman, "i)", 3),
+                (ListKind::Alphabetical, "a.", 2),
+            ]
+        );
+        assert_eq!(seg.analyze(""), DocStructure::default());
+        Ok(())
+    }
+
+    #[test]
+    fn quotations() -> TestResult {
+        let seg = Segmenter::new()?;
+        let text =
+            "He said 'don't go' and left. \"Wait. Come back,\" she cried. It's ‹fine›.\n\n\"Open";
+        let actual: Vec<_> = seg
+            .quotations(text)
+            .into_iter()
+            .map(|quotation| (quotation.text, quotation.start, quotation.sentences))
+            .collect();
+        assert_eq!(
+            actual,
+            vec![
+                ("'don't go'", 8, 0..1),
+                ("\"Wait. Come back,\"", 29, 1..2),
+                ("‹fine›", 64, 2..3),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn presets() -> TestResult {
+        let cases: &[(Preset, &[&str])] = &[
+            (
+                Preset::News,
+                &[
+                    "WASHINGTON (Reuters) - ",
+                    "He met J. R. Smith in the U.S. ",
+                    "He left.",
+                ],
+            ),
+            (
+                Preset::Legal,
+                &["Under Chap. 7 debtors may file. ", "Cf. Sec. 5."],
+            ),
+            (
+                Preset::Biomedical,
+                &[
+                    "See Tab. 3 for details. ",
+                    "Cells grew.¹ ",
+                    "It was shown (12). ",
+                    "Mice got 5 mg t.i.d. p.o. ",
+                    "Results were good.",
+                ],
+            ),
+            (Preset::Chat, &["lol ok!  ", "see you   there"]),
+            (
+                Preset::Ocr,
+                &["\"It was late.\n  He left,\"  she said. ", "Ok."],
+            ),
+            (
+                Preset::Financial,
+                &[
+                    "Item 1A. Risk Factors. ",
+                    "Revenue was $1.5 bn. in FY21, up 3%.(1) ",
+                    "Samsung Co. Ltd. paid $0.45 per sh.* ",
+                    "See Item 1. Business and Item 2. Properties.",
+                ],
+            ),
+            (
+                Preset::Patent,
+                &[
+                    "The device of claim 3, wherein Sect. 2.3.4 applies. ",
+                    "It complies with IEC 60601-1-2:2014. ",
+                    "See U.S. Pat. No. 5,123,456 and FIG. 4.",
+                ],
+            ),
+        ];
+        for &(preset, expected) in cases {
+            let seg = Segmenter::builder().preset(preset).build()?;
+            seg.rules().try_for_each(Rule::check_examples)?;
+            let input = expected.concat();
+            let actual: Vec<_> = seg.segment(&input).collect();
+            assert_eq!(actual, expected, "{:?}", preset);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn collapse_whitespace() -> TestResult {
+        let input = "Items:\n1.\t\tRed  \"one.\"   2.  Blue\n";
+        let seg = Segmenter::builder().collapse_whitespace(true).build()?;
+        let actual: Vec<_> = seg
+            .segments(input)
+            .map(|segment| (segment.text, segment.start, segment.kind))
+            .collect();
+        assert_eq!(
+            actual,
+            vec![
+                ("Items:\n", 0, Kind::Sentence),
+                ("1.\t\tRed  \"one.\"   ", 7, Kind::ListItem { marker: "1." }),
+                ("2.  Blue\n", 25, Kind::ListItem { marker: "2." }),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn fold_all_caps() -> TestResult {
+        let input = "NOTICE\n1.  MR. KIM OF ACME INC. AGREES.  THE TERM IS 2 YRS.\nThe End. Ok.";
+        let seg = Segmenter::builder()
+            .fold_all_caps(true)
+            .collapse_whitespace(true)
+            .build()?;
+        let actual: Vec<_> = seg
+            .segments(input)
+            .map(|segment| (segment.text, segment.kind))
+            .collect();
+        assert_eq!(
+            actual,
+            vec![
+                ("NOTICE\n", Kind::Sentence),
+                (
+                    "1.  MR. KIM OF ACME INC. AGREES.  ",
+                    Kind::ListItem { marker: "1." }
+                ),
+                ("THE TERM IS 2 YRS.\n", Kind::Sentence),
+                ("The End. ", Kind::Sentence),
+                ("Ok.", Kind::Sentence),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn repair_mojibake() -> TestResult {
+        let input = "Itâ€™s â€œdone.â€\u{9d}  IT WAS MR. KIM â€” NOT ME.";
+        let seg = Segmenter::builder()
+            .repair_mojibake(true)
+            .fold_all_caps(true)
+            .collapse_whitespace(true)
+            .build()?;
+        let actual: Vec<_> = seg
+            .segments(input)
+            .map(|segment| (segment.text, segment.start))
+            .collect();
+        assert_eq!(
+            actual,
+            vec![
+                ("Itâ€™s â€œdone.â€\u{9d}  ", 0),
+                ("IT WAS MR. KIM â€” NOT ME.", 33),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn transcript() -> TestResult {
+        let input = "Meeting notes\n[Ann] I think\nBOB:\n[00:12] Sure. Why not";
+
+        let seg = Segmenter::new()?;
+        assert!(seg.segments(input).all(|segment| segment.speaker.is_none()));
+
+        let seg = Segmenter::builder().transcript(true).build()?;
+        let actual: Vec<_> = seg
+            .segments(input)
+            .map(|segment| (segment.text, segment.kind, segment.speaker))
+            .collect();
+        assert_eq!(
+            actual,
+            vec![
+                ("Meeting notes\n", Kind::Sentence, None),
+                (
+                    "[Ann] I think\n",
+                    Kind::Turn { prefix: "[Ann] " },
+                    Some("Ann")
+                ),
+                ("BOB:\n", Kind::Turn { prefix: "BOB:" }, Some("BOB")),
+                ("[00:12] Sure. ", Kind::Turn { prefix: "[00:12] " }, None),
+                ("Why not", Kind::Sentence, None),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn explain() -> TestResult {
+        let seg = Segmenter::new()?;
+        let actual: Vec<_> = seg
+            .explain("Meet at 5 P.M. Then... go home. It is 3.5 km.\n1. one 2. two")
+            .into_iter()
+            .map(|step| (step.stage, step.rule))
+            .collect();
+        assert_eq!(
+            actual,
+            vec![
+                (Stage::ListItems, "SpaceBetweenListItemsSecondRule".into()),
+                (Stage::Abbreviations, "UpperCasePmRule".into()),
+                (Stage::Document, "PeriodBeforeNumberRule".into()),
+                (Stage::Line, "OtherThreePeriodRule".into()),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn numbered_references() -> TestResult {
+        let input = "It was proved.(12) And it was used.[3] Then again.1234 Done.";
+
+        let seg = Segmenter::new()?;
+        let actual: Vec<_> = seg.segment(input).collect();
+        assert_eq!(
+            actual,
+            vec![
+                "It was proved.",
+                "(12) ",
+                "And it was used.[3] ",
+                "Then again.1234 ",
+                "Done."
+            ]
+        );
+
+        let references = NumberedReferences::default()
+            .parentheses(true)
+            .bare_numbers(false);
+        let seg = Segmenter::builder()
+            .numbered_references(references)
+            .build()?;
+        let actual: Vec<_> = seg.segment(input).collect();
+        assert_eq!(
+            actual,
+            vec![
+                "It was proved.(12) ",
+                "And it was used.[3] ",
+                "Then again.1234 Done."
+            ]
+        );
+
+        let seg = Segmenter::builder()
+            .numbered_references(NumberedReferences::disabled())
+            .build()?;
+        assert!(seg
+            .rules()
+            .all(|rule| rule.name() != "NumberedReferenceRule"));
+        Ok(())
+    }
+
+    #[test]
+    fn numeric_expressions() -> TestResult {
+        let numeric = NumericExpressions::default()
+            .coordinates(true)
+            .degrees(true);
+        let seg = Segmenter::builder().numeric_expressions(numeric).build()?;
+        let cases: &[&[&str]] = &[
+            &["Paris is at 48.8566° N, 2.3522° E. ", "It is big."],
+            &[
+                "Paris is at lat. 48.8566, long. 2.3522 in France. ",
+                "It is big.",
+            ],
+            &["The site is at 48°51′24″N. 2°21′03″E. ", "Nice."],
+            &["The spot 12° 34.5′ N. 45° 12.3′ W. ", "Good."],
+            &["Temp: -3.5 ° C. ", "Cold."],
+            &["The point N. 48.85° is here."],
+        ];
+        for &expected in cases {
+            let input = expected.concat();
+            let actual: Vec<_> = seg.segment(&input).collect();
+            assert_eq!(actual, expected);
+        }
+
+        let seg = Segmenter::builder()
+            .numeric_expressions(NumericExpressions::default().geo_location(false))
+            .build()?;
+        assert!(seg.rules().all(|rule| rule.name() != "GeoLocationRule"));
+        Ok(())
+    }
+
+    #[test]
+    fn lowercase_sentence_starts() -> TestResult {
+        let input = r#"wow! he said "hi." then left (see above) and then"#;
+
+        let seg = Segmenter::new()?;
+        let actual: Vec<_> = seg.segment(input).collect();
+        assert_eq!(actual, vec![input]);
+
+        let seg = Segmenter::builder()
+            .lowercase_sentence_starts(true)
+            .build()?;
+        let actual: Vec<_> = seg.segment(input).collect();
+        assert_eq!(
+            actual,
+            vec![
+                "wow! ",
+                r#"he said "hi." "#,
+                "then left (see above) and then"
+            ]
+        );
+        Ok(())
+    }
 }
@@ -4,12 +4,18 @@ use std::error::Error;
 
 use onig::{Captures, Regex};
 
+use crate::builder::{NumeralConflict, SegmenterBuilder};
 use crate::rule::Rule;
+use crate::trace::{Stage, Tracer};
 use crate::util::{re, re_i};
 
+/// The list item rules of a [`Segmenter`](crate::Segmenter), which put every item of an
+/// enumeration like "1. ", "b) " or "(iv) " on its own line. Get one with
+/// [`Segmenter::list_item_replacer()`](crate::Segmenter::list_item_replacer).
 pub struct ListItemReplacer {
     roman_numerals: HashMap<&'static str, isize>,
     latin_numerals: HashMap<&'static str, isize>,
+    numeral_conflict: NumeralConflict,
 
     alphabetical_list_with_periods: Regex,
     alphabetical_list_with_parens: Regex,
@@ -30,6 +36,9 @@ pub struct ListItemReplacer {
     find_numbered_list_parens: regex::Regex,
 
     space_between_list_items_third_rule: Rule,
+
+    numbered_list_marker_regex: Regex,
+    alphabetical_list_marker_regex: Regex,
 }
 
 const ROMAN_NUMERALS: &[&str] = &[
@@ -42,7 +51,7 @@ const LATIN_NUMERALS: &[&str] = &[
 ];
 
 impl ListItemReplacer {
-    pub fn new() -> Result<Self, Box<dyn Error>> {
+    pub(crate) fn new(builder: &SegmenterBuilder) -> Result<Self, Box<dyn Error>> {
         #[must_use]
         fn map_from_list(list: &[&'static str]) -> HashMap<&'static str, isize> {
             list.iter()
@@ -54,6 +63,7 @@ impl ListItemReplacer {
         Ok(ListItemReplacer {
             roman_numerals: map_from_list(ROMAN_NUMERALS),
             latin_numerals: map_from_list(LATIN_NUMERALS),
+            numeral_conflict: builder.numeral_conflict,
 
             // Example: https://rubular.com/r/XcpaJKH0sz
             //
@@ -102,14 +112,22 @@ impl ListItemReplacer {
             // Example:
             //   https://rubular.com/r/Wv4qLdoPx7
             //   https://regex101.com/r/62YBlv/1
-            space_between_list_items_first_rule: Rule::new(r"(?<=\S\S)\s(?=\S\s*\d+♨)", "\r")?,
+            space_between_list_items_first_rule: Rule::new(
+                "SpaceBetweenListItemsFirstRule",
+                r"(?<=\S\S)\s(?=\S\s*\d+♨)",
+                "\r",
+            )?,
 
             // NOTE: pySBD와 pragmatic-segmenter(루비 구현체)가 다른 regex를 씀, pySBD를 따라감
             //
             // Example:
             //   https://rubular.com/r/AizHXC6HxK
             //   https://regex101.com/r/62YBlv/2
-            space_between_list_items_second_rule: Rule::new(r"(?<=\S\S)\s(?=\d{1,2}♨)", "\r")?,
+            space_between_list_items_second_rule: Rule::new(
+                "SpaceBetweenListItemsSecondRule",
+                r"(?<=\S\S)\s(?=\d{1,2}♨)",
+                "\r",
+            )?,
 
             // Refernce: https://github.com/nipunsadvilkar/pySBD/blob/90699972/pysbd/lists_item_replacer.py#L154
             find_numbered_list_parens: regex::Regex::new(r"☝.+\n.+☝|☝.+\r.+☝")?,
@@ -119,12 +137,100 @@ impl ListItemReplacer {
             // Example:
             //   https://rubular.com/r/GE5q6yID2j
             //   https://regex101.com/r/62YBlv/3
-            space_between_list_items_third_rule: Rule::new(r"(?<=\S\S)\s(?=\d{1,2}☝)", "\r")?,
+            space_between_list_items_third_rule: Rule::new(
+                "SpaceBetweenListItemsThirdRule",
+                r"(?<=\S\S)\s(?=\d{1,2}☝)",
+                "\r",
+            )?,
+
+            // 위의 규칙들로 줄바꿈된 뒤의 list item marker 모양
+            numbered_list_marker_regex: re(r"\A\d{1,2}(?:∯|\))(?=\s)")?,
+            alphabetical_list_marker_regex: re(
+                r"\A(?:&✂&[a-zA-Z]+\)|[a-zA-Z]∯(?=\s)|[a-zA-Z]+\)(?=\s))",
+            )?,
         })
     }
 
+    pub(crate) fn rules(&self) -> impl Iterator<Item = &Rule> {
+        vec![
+            &self.space_between_list_items_first_rule,
+            &self.space_between_list_items_second_rule,
+            &self.space_between_list_items_third_rule,
+        ]
+        .into_iter()
+    }
+
+    pub(crate) fn rules_mut(&mut self) -> impl Iterator<Item = &mut Rule> {
+        vec![
+            &mut self.space_between_list_items_first_rule,
+            &mut self.space_between_list_items_second_rule,
+            &mut self.space_between_list_items_third_rule,
+        ]
+        .into_iter()
+    }
+
+    /// Insert a line break before every list item of `text`. A space between two items becomes
+    /// a line break, and no line break is inserted at the start of the text or right after
+    /// another line break. Every other character is kept as it is, so applying it again doesn't
+    /// change the result, and the result can be segmented as usual.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::new()?;
+    /// let replacer = segmenter.list_item_replacer();
+    /// let text = replacer.add_line_break("Steps: 1. Open it. 2. Close it. a) one b) two");
+    /// assert_eq!(text, "Steps:\n1. Open it.\n2. Close it. \na) one \nb) two");
+    /// assert_eq!(replacer.add_line_break(&text), text);
+    ///
+    /// let result: Vec<_> = segmenter.segment(&text).collect();
+    /// assert_eq!(result[..2], ["Steps:\n", "1. Open it.\n"]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     #[must_use]
     pub fn add_line_break(&self, text: &str) -> String {
+        // NOTE: 분리 과정과 같은 조건에서 항목을 찾도록, 줄바꿈을 \r로 바꿔서 표시한다.
+        let marked = self.mark_list_items(&text.replace('\n', "\r"), Tracer::default());
+
+        // 표시된 문자열은 원문에 \r을 끼워넣고, 몇몇 글자를 표시용 글자로 바꾼 것이다. 둘을
+        // 맞춰보며 끼워넣은 줄바꿈만 원문에 반영한다.
+        let mut result = String::with_capacity(marked.len());
+        let mut original = text.chars().peekable();
+        let mut rest = marked.as_str();
+        while let Some(c) = rest.chars().next() {
+            let next = original.peek().copied();
+            if let (Some(after), Some('(')) = (rest.strip_prefix("&✂&"), next) {
+                result.push('(');
+                original.next();
+                rest = after;
+                continue;
+            }
+            rest = &rest[c.len_utf8()..];
+            match (c, next) {
+                ('∯', Some('.')) => result.push('.'),
+                ('\r', Some(next @ ('\r' | '\n'))) => result.push(next),
+                // 항목 사이의 공백
+                ('\r', Some(next)) if next.is_whitespace() => result.push('\n'),
+                // 끼워넣은 줄바꿈
+                ('\r', _) => {
+                    if !result.is_empty() && !result.ends_with(&['\r', '\n'][..]) {
+                        result.push('\n');
+                    }
+                    continue;
+                }
+                (c, Some(next)) if c == next => result.push(c),
+                // NOTE: 위의 경우 말고는 생기지 않지만, 혹시라도 어긋나면 원문을 그대로 돌려준다.
+                _ => return text.to_string(),
+            }
+            original.next();
+        }
+        result
+    }
+
+    /// Insert a `\r` before every list item of `text`, and replace the periods of the markers
+    /// with `∯`, as pySBD does. This is the step of the segmentation.
+    #[must_use]
+    pub(crate) fn mark_list_items(&self, text: &str, tracer: Tracer<'_>) -> String {
         let text = Cow::Borrowed(text);
 
         // format_alphabetical_lists()
@@ -143,7 +249,7 @@ impl ListItemReplacer {
             '♨',
             true,
         );
-        let text = self.add_line_breaks_for_numbered_list_with_periods(&text);
+        let text = self.add_line_breaks_for_numbered_list_with_periods(&text, tracer);
         let text = text.replace('♨', "∯"); // SubstituteListPeriodRule
 
         // format_numbered_list_with_parens()
@@ -154,10 +260,35 @@ impl ListItemReplacer {
             '☝',
             false,
         );
-        let text = self.add_line_breaks_for_numbered_list_with_parens(&text);
+        let text = self.add_line_breaks_for_numbered_list_with_parens(&text, tracer);
         text.replace('☝', "") // ListMarkerRule
     }
 
+    /// Find the list item marker at the start of a line produced by [`mark_list_items()`], and
+    /// return it as written in the original text ("1.", "a)", "(iv)").
+    ///
+    /// Alphabetical markers are only recognized after a line break, since the first line of the
+    /// text can start with an initial like "A. Lincoln".
+    ///
+    /// [`mark_list_items()`]: ListItemReplacer::mark_list_items
+    #[must_use]
+    pub(crate) fn list_marker(&self, line: &str, after_line_break: bool) -> Option<String> {
+        let (start, end) = self.numbered_list_marker_regex.find(line).or_else(|| {
+            if after_line_break {
+                self.alphabetical_list_marker_regex.find(line)
+            } else {
+                None
+            }
+        })?;
+        Some(line[start..end].replace('∯', ".").replace("&✂&", "("))
+    }
+
+    /// Whether `letters` is a lowercase roman numeral which can be a list item marker.
+    #[must_use]
+    pub(crate) fn is_roman_numeral(&self, letters: &str) -> bool {
+        self.roman_numerals.contains_key(letters)
+    }
+
     #[must_use]
     fn replace_alphabet_list(&self, text: &str, what_to_replace: &str) -> String {
         self.alphabetical_list_letters_and_periods_regex
@@ -190,7 +321,14 @@ impl ListItemReplacer {
                         mat.to_string()
                     }
                 } else if mat == what_to_replace {
-                    format!("\r{}", mat)
+                    // NOTE: 이미 줄바꿈 뒤에 있는 항목에는 줄바꿈을 또 넣지 않는다. 그래야 두번
+                    // 적용해도 결과가 같다.
+                    let (start, _) = m.pos(0).unwrap(); // Must exists
+                    if text[..start].ends_with('\r') {
+                        mat.to_string()
+                    } else {
+                        format!("\r{}", mat)
+                    }
                 } else {
                     mat.to_string()
                 }
@@ -228,6 +366,14 @@ impl ListItemReplacer {
 
         let len = list_array.len();
 
+        // "i", "v", "x"처럼 두 목록에 모두 속하는 글자를 이번에 바꿀지 정한다.
+        let replace_ambiguous = match self.numeral_conflict {
+            NumeralConflict::Both => true,
+            NumeralConflict::Roman => use_roman_numeral,
+            NumeralConflict::Latin => !use_roman_numeral,
+            NumeralConflict::Context => use_roman_numeral == self.has_roman_markers(text, parens),
+        };
+
         let mut result = Cow::Borrowed(text);
         for ind in 0..len {
             let is_strange = if len <= 1 {
@@ -257,6 +403,11 @@ impl ListItemReplacer {
             }
 
             let each = list_array[ind].0;
+            let ambiguous =
+                self.roman_numerals.contains_key(each) && self.latin_numerals.contains_key(each);
+            if ambiguous && !replace_ambiguous {
+                continue;
+            }
             result = Cow::Owned(if parens {
                 self.replace_alphabet_list_parens(&result, each)
             } else {
@@ -267,6 +418,19 @@ impl ListItemReplacer {
         result
     }
 
+    /// Whether `text` has a list item marker of two or more Roman numeral letters, like "ii)" or
+    /// "(iv)" if `parens` is true, or "ii." otherwise.
+    fn has_roman_markers(&self, text: &str, parens: bool) -> bool {
+        text.split_whitespace().any(|word| {
+            let word = if parens {
+                word.trim_start_matches('(').strip_suffix(')')
+            } else {
+                word.strip_suffix('.')
+            };
+            word.is_some_and(|word| word.len() > 1 && self.roman_numerals.contains_key(word))
+        })
+    }
+
     #[must_use]
     fn scan_lists<'a>(
         &self,
@@ -324,13 +488,25 @@ impl ListItemReplacer {
     }
 
     #[must_use]
-    fn add_line_breaks_for_numbered_list_with_periods<'a>(&self, text: &'a str) -> Cow<'a, str> {
+    fn add_line_breaks_for_numbered_list_with_periods<'a>(
+        &self,
+        text: &'a str,
+        tracer: Tracer<'_>,
+    ) -> Cow<'a, str> {
         if text.contains('♨')
             && self.find_numbered_list_1.find(text).is_none()
             && self.find_numbered_list_2.find(text).is_none()
         {
-            let text = self.space_between_list_items_first_rule.replace_all(text);
-            let text = self.space_between_list_items_second_rule.replace_all(&text);
+            let text = tracer.apply(
+                Stage::ListItems,
+                &self.space_between_list_items_first_rule,
+                text,
+            );
+            let text = tracer.apply(
+                Stage::ListItems,
+                &self.space_between_list_items_second_rule,
+                &text,
+            );
             return Cow::Owned(text);
         }
 
@@ -338,9 +514,17 @@ impl ListItemReplacer {
     }
 
     #[must_use]
-    fn add_line_breaks_for_numbered_list_with_parens<'a>(&self, text: &'a str) -> Cow<'a, str> {
+    fn add_line_breaks_for_numbered_list_with_parens<'a>(
+        &self,
+        text: &'a str,
+        tracer: Tracer<'_>,
+    ) -> Cow<'a, str> {
         if text.contains('☝') && self.find_numbered_list_parens.find(text).is_none() {
-            let text = self.space_between_list_items_third_rule.replace_all(text);
+            let text = tracer.apply(
+                Stage::ListItems,
+                &self.space_between_list_items_third_rule,
+                text,
+            );
             return Cow::Owned(text);
         }
 
@@ -357,7 +541,7 @@ mod tests {
 
     #[test]
     fn test_alphabetical_list_with_periods() -> TestResult {
-        let list = ListItemReplacer::new()?;
+        let list = ListItemReplacer::new(&SegmenterBuilder::new())?;
         let text =
             "a. The first item b. The second item c. The third list item D. case insesitive \
 E. Don't select the nextF.dont't select this G should be followed by dot";
@@ -379,7 +563,7 @@ E. Don't select the nextF.dont't select this G should be followed by dot";
 
     #[test]
     fn test_alphabetical_list_with_parens() -> TestResult {
-        let list = ListItemReplacer::new()?;
+        let list = ListItemReplacer::new(&SegmenterBuilder::new())?;
         let text = "\
 a) Hello world.
 b) Hello world.
@@ -420,7 +604,7 @@ f) Hello world.
 
     #[test]
     fn test_alphabetical_list_letters_and_periods_regex() -> TestResult {
-        let list = ListItemReplacer::new()?;
+        let list = ListItemReplacer::new(&SegmenterBuilder::new())?;
         let text = "His name is Mark E. Smith. a. here it is b. another c. one more
  They went to the store. It was John A. Smith. She was Jane B. Smith.";
 
@@ -442,7 +626,7 @@ f) Hello world.
 
     #[test]
     fn test_extract_alphabetical_list_letters_regex() -> TestResult {
-        let list = ListItemReplacer::new()?;
+        let list = ListItemReplacer::new(&SegmenterBuilder::new())?;
         let text =
         "a) here it is b) another c) one more \nThey went to the store. W) hello X) hello Y) hello";
 
@@ -464,7 +648,7 @@ f) Hello world.
 
     #[test]
     fn test_numbered_list_regex_1() -> TestResult {
-        let list = ListItemReplacer::new()?;
+        let list = ListItemReplacer::new(&SegmenterBuilder::new())?;
         let text = "\
 Match below
 
@@ -494,7 +678,7 @@ Dont match below
 
     #[test]
     fn test_numbered_list_regex_2() -> TestResult {
-        let list = ListItemReplacer::new()?;
+        let list = ListItemReplacer::new(&SegmenterBuilder::new())?;
         let text = "\
 Match below
 
@@ -524,7 +708,7 @@ Dont match below
 
     #[test]
     fn test_numbered_list_parens_regex() -> TestResult {
-        let list = ListItemReplacer::new()?;
+        let list = ListItemReplacer::new(&SegmenterBuilder::new())?;
         let text = "\
 1) a
 2) b
@@ -565,7 +749,7 @@ f77) f
 
     #[test]
     fn test_space_between_list_items_first_rule() -> TestResult {
-        let list = ListItemReplacer::new()?;
+        let list = ListItemReplacer::new(&SegmenterBuilder::new())?;
 
         let input = "abcd  ⁃9♨ The first item ⁃10♨ The second item ⁃9♨ The first item ⁃10♨ The second item ⁃9♨ The first item ⁃10♨ The second item ⁃9♨ The first item ⁃10♨ The second item ⁃9♨ The first item ⁃10♨ The second item ⁃9♨ The first item ⁃10♨ The second item";
         let output = "abcd  ⁃9♨ The first item\r⁃10♨ The second item\r⁃9♨ The first item\r⁃10♨ The second item\r⁃9♨ The first item\r⁃10♨ The second item\r⁃9♨ The first item\r⁃10♨ The second item\r⁃9♨ The first item\r⁃10♨ The second item\r⁃9♨ The first item\r⁃10♨ The second item";
@@ -580,7 +764,7 @@ f77) f
 
     #[test]
     fn test_space_between_list_items_second_rule() -> TestResult {
-        let list = ListItemReplacer::new()?;
+        let list = ListItemReplacer::new(&SegmenterBuilder::new())?;
 
         let input = "1♨ The first item 2♨ The second item";
         let output = "1♨ The first item\r2♨ The second item";
@@ -595,7 +779,7 @@ f77) f
 
     #[test]
     fn test_space_between_list_items_third_rule() -> TestResult {
-        let list = ListItemReplacer::new()?;
+        let list = ListItemReplacer::new(&SegmenterBuilder::new())?;
 
         let input = "1☝) The first item 2☝) The second item";
         let output = "1☝) The first item\r2☝) The second item";
@@ -610,7 +794,7 @@ f77) f
 
     #[test]
     fn test_replace_alphabet_list() -> TestResult {
-        let list = ListItemReplacer::new()?;
+        let list = ListItemReplacer::new(&SegmenterBuilder::new())?;
         assert_eq!(
             list.replace_alphabet_list("a. ffegnog b. fgegkl c.", "b"),
             "a. ffegnog \rb∯ fgegkl c."
@@ -620,7 +804,7 @@ f77) f
 
     #[test]
     fn test_replace_alphabet_list_parens() -> TestResult {
-        let list = ListItemReplacer::new()?;
+        let list = ListItemReplacer::new(&SegmenterBuilder::new())?;
         assert_eq!(
             list.replace_alphabet_list_parens("a) ffegnog (b) fgegkl c)", "a"),
             "\ra) ffegnog (b) fgegkl c)"
@@ -637,7 +821,7 @@ f77) f
         // NOTE: 이 테스트케이스를 보면 버그때문에 match가 엉터리로 이뤄지고있는것을 볼 수 있지만,
         // pySBD와 동작을 맞추는것이 목표이기때문에 버그도 그대로 유지한다.
 
-        let list = ListItemReplacer::new()?;
+        let list = ListItemReplacer::new(&SegmenterBuilder::new())?;
         assert_eq!(list.iterate_alphabet_array("i. Hi", false, true), "i. Hi");
 
         let input = "\
@@ -692,9 +876,9 @@ C) Maecenas
         let output = "\
 Do
 
-\r\ra) Lorem
-\r\rb) Donec
-\r\rc) Aenean
+\ra) Lorem
+\rb) Donec
+\rc) Aenean
 
 \r&✂&a) Lorem
 \r&✂&b) Donec
@@ -749,9 +933,9 @@ III) Nam
         let output = "\
 Do
 
-\r\ri) Ut eu volutpat felis.
-\r\rii) Mauris
-\r\riii) Proin
+\ri) Ut eu volutpat felis.
+\rii) Mauris
+\riii) Proin
 
 \r&✂&i) Ut eu volutpat felis.
 \r&✂&ii) Mauris
@@ -772,9 +956,53 @@ III) Nam
         Ok(())
     }
 
+    #[test]
+    fn test_numeral_conflict() -> TestResult {
+        let latin = "u) red v) green w) blue";
+        let roman = "iv) red v) green vi) blue";
+        let cases = [
+            (
+                NumeralConflict::Both,
+                "\ru) red \rv) green \rw) blue",
+                "\riv) red \rv) green \rvi) blue",
+            ),
+            (
+                NumeralConflict::Roman,
+                "\ru) red v) green \rw) blue",
+                "\riv) red \rv) green \rvi) blue",
+            ),
+            (
+                NumeralConflict::Latin,
+                "\ru) red \rv) green \rw) blue",
+                "\riv) red v) green \rvi) blue",
+            ),
+            (
+                NumeralConflict::Context,
+                "\ru) red \rv) green \rw) blue",
+                "\riv) red \rv) green \rvi) blue",
+            ),
+        ];
+        for (policy, latin_output, roman_output) in cases {
+            let list = ListItemReplacer::new(&SegmenterBuilder::new().numeral_conflict(policy))?;
+            assert_eq!(
+                list.iterate_alphabet_array(latin, true, false),
+                latin_output,
+                "{:?}",
+                policy
+            );
+            assert_eq!(
+                list.iterate_alphabet_array(roman, true, true),
+                roman_output,
+                "{:?}",
+                policy
+            );
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_scan_lists() -> TestResult {
-        let list = ListItemReplacer::new()?;
+        let list = ListItemReplacer::new(&SegmenterBuilder::new())?;
 
         let input = "\
 Match below
@@ -863,13 +1091,13 @@ f77) f
 
     #[test]
     fn test_add_line_breaks_for_numbered_list_with_periods() -> TestResult {
-        let list = ListItemReplacer::new()?;
+        let list = ListItemReplacer::new(&SegmenterBuilder::new())?;
 
         let input = "1♨ abcd 2♨ xyz 3♨ asdf 4♨ asdf";
         let output = "1♨ abcd\r2♨ xyz\r3♨ asdf\r4♨ asdf";
 
         assert_eq!(
-            list.add_line_breaks_for_numbered_list_with_periods(input),
+            list.add_line_breaks_for_numbered_list_with_periods(input, Tracer::default()),
             output
         );
 
@@ -878,16 +1106,58 @@ f77) f
 
     #[test]
     fn test_add_line_breaks_for_numbered_list_with_parens() -> TestResult {
-        let list = ListItemReplacer::new()?;
+        let list = ListItemReplacer::new(&SegmenterBuilder::new())?;
 
         let input = "1☝) The first item 2☝) The second item";
         let output = "1☝) The first item\r2☝) The second item";
 
         assert_eq!(
-            list.add_line_breaks_for_numbered_list_with_parens(input),
+            list.add_line_breaks_for_numbered_list_with_parens(input, Tracer::default()),
             output
         );
 
         Ok(())
     }
+
+    #[test]
+    fn test_add_line_break() -> TestResult {
+        let list = ListItemReplacer::new(&SegmenterBuilder::new())?;
+        let cases = [
+            ("a) one b) two c) three", "a) one \nb) two \nc) three"),
+            ("x\na) one\nb) two", "x\na) one\nb) two"),
+            ("(i) one (ii) two", "(i) one \n(ii) two"),
+            ("1. one 2. two 3. three", "1. one\n2. two\n3. three"),
+            ("Items:\r\n1. one\r\n2. two", "Items:\r\n1. one\r\n2. two"),
+            ("He said: 1) a 2) b", "He said:\n1) a 2) b"),
+            ("Call 911. Now.", "Call 911. Now."),
+            ("", ""),
+        ];
+        for (input, expected) in cases {
+            let once = list.add_line_break(input);
+            assert_eq!(once, expected, "{:?}", input);
+            assert_eq!(list.add_line_break(&once), once, "{:?}", input);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_marker() -> TestResult {
+        let list = ListItemReplacer::new(&SegmenterBuilder::new())?;
+
+        assert_eq!(
+            list.list_marker("1∯ The first item", false),
+            Some("1.".into())
+        );
+        assert_eq!(list.list_marker("12) The item", false), Some("12)".into()));
+        assert_eq!(list.list_marker("b∯ The item", true), Some("b.".into()));
+        assert_eq!(
+            list.list_marker("&✂&iv) The item", true),
+            Some("(iv)".into())
+        );
+        assert_eq!(list.list_marker("A∯ Lincoln", false), None);
+        assert_eq!(list.list_marker("Mr∯ Kim", true), None);
+        assert_eq!(list.list_marker("The end.", true), None);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,142 @@
+//! Reduced segmenter which builds without std. It has the placeholder-free scanner behind
+//! [`fallback::split()`](crate::fallback::split), the punctuation tables it shares with the full
+//! pipeline, and utilities on byte spans of sentences.
+//!
+//! Everything else of this crate, including [`Segmenter`](crate::Segmenter), needs regular
+//! expressions and std. Embedded targets and WebAssembly modules which only have `core` and
+//! `alloc` can disable the default `std` feature and enable the `lite` feature to use this
+//! module:
+//!
+//! ```toml
+//! [dependencies]
+//! pragmatic-segmenter = { version = "0.1", default-features = false, features = ["lite"] }
+//! ```
+//!
+//! ```rust
+//! use pragmatic_segmenter::lite;
+//!
+//! let text = "Is it 3.14? Yes.  Ok";
+//! let spans: Vec<_> = lite::spans(text).collect();
+//! assert_eq!(spans, vec![0..12, 12..18, 18..20]);
+//! assert_eq!(lite::trim_span(text, spans[1].clone()), 12..16);
+//! ```
+
+use alloc::vec::Vec;
+use core::iter;
+use core::ops::Range;
+
+/// Punctuations which may end a sentence.
+#[rustfmt::skip]
+pub const TERMINALS: &[char] = &[
+    '.', '!', '?', '…', '‼', '⁇', '⁈', '⁉', '؟', '।', '॥', '。', '！', '？', '｡',
+];
+
+/// Terminal punctuations which end a sentence even without whitespace after them.
+pub const CJK_TERMINALS: &[char] = &['。', '！', '？', '｡'];
+
+/// Closing quotation marks and brackets, which stay with the sentence they close.
+#[rustfmt::skip]
+pub const CLOSINGS: &[char] = &[
+    '"', '\'', '”', '’', '»', '›', ')', ']', '}', '」', '』', '）', '】', '〉', '》',
+];
+
+/// Opening quotation marks and brackets, and inverted marks of Spanish, which are skipped to
+/// find the first letter of a sentence.
+#[rustfmt::skip]
+pub const OPENINGS: &[char] = &[
+    '"', '\'', '“', '‘', '«', '‹', '(', '[', '{', '「', '『', '（', '【', '〈', '《', '¿', '¡',
+];
+
+/// Split `text` into sentences. Like [`Segmenter::segment()`](crate::Segmenter::segment), every
+/// sentence keeps its trailing whitespace, so concatenating the results gives back `text`.
+pub fn split(text: &str) -> impl Iterator<Item = &str> {
+    spans(text).map(move |span| &text[span])
+}
+
+/// Byte ranges of the sentences of `text`, in the same way as [`split()`]. The ranges are
+/// contiguous and cover the whole `text`.
+pub fn spans(text: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    let mut start = 0;
+    iter::from_fn(move || {
+        if start == text.len() {
+            return None;
+        }
+        let rest = &text[start..];
+        let end = start + boundary(rest).unwrap_or(rest.len());
+        let span = start..end;
+        start = end;
+        Some(span)
+    })
+}
+
+/// Byte offsets where a sentence starts after another, that is, the start of every span of
+/// [`spans()`] but the first.
+pub fn boundaries(text: &str) -> Vec<usize> {
+    spans(text).skip(1).map(|span| span.start).collect()
+}
+
+/// `span` of `text` without the whitespace around it. An empty range at the end of `span` if
+/// it is blank.
+pub fn trim_span(text: &str, span: Range<usize>) -> Range<usize> {
+    let slice = &text[span.clone()];
+    let start = span.end - slice.trim_start().len();
+    let end = span.start + slice.trim_end().len();
+    if start >= end {
+        return span.end..span.end;
+    }
+    start..end
+}
+
+/// Byte offset of the end of the first sentence in `text`, including the whitespace after it.
+fn boundary(text: &str) -> Option<usize> {
+    let mut chars = text.char_indices().peekable();
+    while let Some((idx, c)) = chars.next() {
+        if !TERMINALS.contains(&c) {
+            continue;
+        }
+
+        // "?!", "...", "." followed by closing quotes
+        let mut end = idx + c.len_utf8();
+        while let Some(&(idx, c)) = chars.peek() {
+            if !TERMINALS.contains(&c) && !CLOSINGS.contains(&c) {
+                break;
+            }
+            end = idx + c.len_utf8();
+            chars.next();
+        }
+
+        let rest = &text[end..];
+        let next = rest.trim_start();
+        let space = rest.len() - next.len();
+        if CJK_TERMINALS.contains(&c) {
+            return Some(end + space);
+        }
+        if space == 0 {
+            // "3.14", "www.example.com"
+            continue;
+        }
+        match next.chars().find(|c| !OPENINGS.contains(c)) {
+            // Uppercase letters, and letters of scripts without cases like Hangul
+            Some(c) if c.is_alphabetic() && !c.is_lowercase() => return Some(end + space),
+            None => return Some(end + space),
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spans() {
+        let text = "Hi. 你好。我很好！ \n";
+        assert_eq!(spans(text).collect::<Vec<_>>(), vec![0..4, 4..13, 13..27]);
+        assert_eq!(boundaries(text), vec![4, 13]);
+        assert_eq!(trim_span(text, 13..27), 13..25);
+        assert_eq!(trim_span(text, 25..27), 27..27);
+        assert_eq!(spans("").count(), 0);
+        assert_eq!(boundaries("No boundary"), Vec::<usize>::new());
+    }
+}
@@ -0,0 +1,180 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+
+use onig::Regex;
+
+use crate::util::re;
+use crate::RuleMismatch;
+
+/// A named regular expression which merges two adjacent segments back into one, the mirror of
+/// [`Rule`](crate::Rule) for correcting over-splitting. The regex, written in Oniguruma (Ruby)
+/// syntax, is matched against the text of the two segments put together, and they are merged if
+/// a match starts in the first segment and ends in the second one. Add it with
+/// [`merge_rule()`](crate::SegmenterBuilder::merge_rule).
+///
+/// ```rust
+/// use pragmatic_segmenter::{MergeRule, Segmenter};
+///
+/// // A sentence rarely starts with a lowercase letter.
+/// let rule = MergeRule::new("LowercaseContinuationRule", r"[.!?]\s+\p{Ll}")?
+///     .with_example("See Fig. 3a. ", "it shows the drop.", true)
+///     .with_example("It dropped. ", "Then it rose.", false);
+/// rule.check_examples()?;
+///
+/// let segmenter = Segmenter::builder().merge_rule(rule).build()?;
+/// let result: Vec<_> = segmenter
+///     .segment("See Fig. 3a. it shows the drop. Done.")
+///     .collect();
+/// assert_eq!(result, vec!["See Fig. 3a. it shows the drop. ", "Done."]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct MergeRule {
+    name: Cow<'static, str>,
+    description: Cow<'static, str>,
+    examples: Vec<(Cow<'static, str>, Cow<'static, str>, bool)>,
+    pattern: String,
+    regex: Regex,
+}
+
+impl MergeRule {
+    /// Compile a new merge rule.
+    pub fn new(name: impl Into<Cow<'static, str>>, regex: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(MergeRule {
+            name: name.into(),
+            description: Cow::Borrowed(""),
+            examples: Vec::new(),
+            pattern: regex.to_string(),
+            regex: re(regex)?,
+        })
+    }
+
+    /// Set the description of the rule.
+    pub fn with_description(mut self, description: impl Into<Cow<'static, str>>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Add an example pair of adjacent segments, and whether [`merges()`](MergeRule::merges)
+    /// them.
+    pub fn with_example(
+        mut self,
+        before: impl Into<Cow<'static, str>>,
+        after: impl Into<Cow<'static, str>>,
+        merged: bool,
+    ) -> Self {
+        self.examples.push((before.into(), after.into(), merged));
+        self
+    }
+
+    /// Name of the rule, like `"LowercaseContinuationRule"`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Description of the rule. Empty if not given.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Example pairs of segments, and whether they are merged.
+    pub fn examples(&self) -> impl Iterator<Item = (&str, &str, bool)> {
+        self.examples
+            .iter()
+            .map(|(before, after, merged)| (before.as_ref(), after.as_ref(), *merged))
+    }
+
+    /// Source of the regex.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Whether a match of the regex in `before` followed by `after` crosses the boundary between
+    /// them.
+    pub fn merges(&self, before: &str, after: &str) -> bool {
+        let boundary = before.len();
+        let text = format!("{}{}", before, after);
+        self.regex
+            .find_iter(&text)
+            .take_while(|&(start, _)| start < boundary)
+            .any(|(_, end)| end > boundary)
+    }
+
+    /// Check that [`merges()`](MergeRule::merges) tells `expected` for `before` and `after`. The
+    /// input of the mismatch marks the boundary with "⏐".
+    pub fn check(&self, before: &str, after: &str, expected: bool) -> Result<(), RuleMismatch> {
+        let actual = self.merges(before, after);
+        if actual == expected {
+            return Ok(());
+        }
+        let outcome = |merged| if merged { "merged" } else { "split" }.to_string();
+        Err(RuleMismatch {
+            rule: self.name.to_string(),
+            input: format!("{}⏐{}", before, after),
+            expected: outcome(expected),
+            actual: outcome(actual),
+        })
+    }
+
+    /// [`check()`](MergeRule::check) every example given with
+    /// [`with_example()`](MergeRule::with_example).
+    pub fn check_examples(&self) -> Result<(), RuleMismatch> {
+        self.examples()
+            .try_for_each(|(before, after, merged)| self.check(before, after, merged))
+    }
+}
+
+impl Clone for MergeRule {
+    fn clone(&self) -> Self {
+        MergeRule {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            examples: self.examples.clone(),
+            pattern: self.pattern.clone(),
+            regex: re(&self.pattern).unwrap(), // Must succeed, since it was compiled once
+        }
+    }
+}
+
+impl fmt::Debug for MergeRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergeRule")
+            .field("name", &self.name)
+            .field("description", &self.description)
+            .field("examples", &self.examples)
+            .field("pattern", &self.pattern)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merges() -> Result<(), Box<dyn Error>> {
+        // 줄바꿈으로 끝난 접속사 뒤의 줄은 같은 문장이다.
+        let rule = MergeRule::new("DanglingConjunctionRule", r"\b(?:and|or|but)\s+\S")?
+            .with_example("Mix salt and\n", "pepper well.", true)
+            .with_example("Salt and pepper.\n", "Mix well.", false)
+            .with_example("Mix salt and", " pepper.", true);
+        rule.check_examples()?;
+
+        // 경계 앞에서 끝난 매치는 세지 않는다.
+        assert!(!rule.merges("Salt and pepper or\n", ""));
+        assert_eq!(
+            rule.check("Salt and pepper.\n", "Mix well.", true)
+                .unwrap_err()
+                .to_string(),
+            "rule DanglingConjunctionRule failed\n   input: \"Salt and pepper.\\n⏐Mix well.\"\n\
+             expected: \"merged\"\n  actual: \"split\""
+        );
+
+        let segmenter = crate::Segmenter::builder().merge_rule(rule).build()?;
+        let result: Vec<_> = segmenter
+            .segment("Mix salt and\npepper well.\nServe hot.")
+            .collect();
+        assert_eq!(result, vec!["Mix salt and\npepper well.\n", "Serve hot."]);
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+//! Minimization of inputs which break the invariants of the segmentation, for triaging bug
+//! reports. An input whose segmentation panics, loses some text or covers some text twice is
+//! shrunk character by character to a small reproducer which still breaks the same invariant.
+//! The result only depends on the input and the segmenter, so the same report always gives the
+//! same reproducer.
+//!
+//! ```rust
+//! use pragmatic_segmenter::minimize::{self, Violation};
+//! use pragmatic_segmenter::Segmenter;
+//!
+//! let segmenter = Segmenter::new()?;
+//! assert_eq!(minimize::check(&segmenter, "Hi Mr. Kim. Bye."), None);
+//!
+//! // "∯" is used as a placeholder of periods during the segmentation
+//! let text = "Hi Mr. Kim. Let's meet at 3 P.M. It costs 5∯ now. See you.";
+//! let minimized = minimize::minimize(&segmenter, text).unwrap();
+//! assert_eq!(minimized.text, "∯");
+//! assert_eq!(minimized.violation, Violation::Lost);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! A panic is caught with [`std::panic::catch_unwind()`], but the panic hook still prints its
+//! message every time it happens during the minimization. Install a silent hook with
+//! [`std::panic::set_hook()`] beforehand to avoid it.
+
+use std::any::Any;
+use std::fmt;
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::Segmenter;
+
+/// Invariant of the segmentation which an input breaks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Violation {
+    /// The segmentation panicked, with the message of the panic.
+    Panic(String),
+    /// Some non-whitespace text of the input is not in any segment.
+    Lost,
+    /// Some text of the input is in more than one segment.
+    Overlapping,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::Panic(message) => write!(f, "panicked: {}", message),
+            Violation::Lost => f.write_str("text is lost"),
+            Violation::Overlapping => f.write_str("segments overlap"),
+        }
+    }
+}
+
+/// Result of [`minimize()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Minimized {
+    /// Smallest input found, from which no single character can be removed without fixing the
+    /// violation.
+    pub text: String,
+    /// Violation of [`text`](Minimized::text). It is the same kind of violation as the one of
+    /// the original input, though the message of a panic may differ.
+    pub violation: Violation,
+    /// Number of segmentations run.
+    pub checks: usize,
+}
+
+/// Segment `text` with `segmenter`, and return the invariant which it breaks, if any.
+pub fn check(segmenter: &Segmenter, text: &str) -> Option<Violation> {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| segmenter.checked_segments(text).err()));
+    match result {
+        Ok(None) => None,
+        Ok(Some(error)) if !error.lost.is_empty() => Some(Violation::Lost),
+        Ok(Some(_)) => Some(Violation::Overlapping),
+        Err(payload) => Some(Violation::Panic(panic_message(payload.as_ref()))),
+    }
+}
+
+/// Shrink `text` to a small input which breaks the same invariant as `text`, or return `None` if
+/// `text` breaks none.
+///
+/// Chunks of characters are removed while the violation remains, starting from halves of the
+/// input down to single characters, like the delta debugging algorithm. It takes a few thousand
+/// segmentations for an input of a few kilobytes.
+pub fn minimize(segmenter: &Segmenter, text: &str) -> Option<Minimized> {
+    let mut violation = check(segmenter, text)?;
+    let kind = mem::discriminant(&violation);
+    let mut checks = 1;
+    let mut chars: Vec<char> = text.chars().collect();
+    let mut chunk = chars.len().div_ceil(2);
+    while chunk > 0 {
+        let mut reduced = false;
+        let mut start = 0;
+        while start < chars.len() {
+            let end = (start + chunk).min(chars.len());
+            let candidate: String = chars[..start].iter().chain(&chars[end..]).collect();
+            checks += 1;
+            match check(segmenter, &candidate) {
+                Some(found) if mem::discriminant(&found) == kind => {
+                    // NOTE: 지운 자리에 다음 조각이 오므로 start를 그대로 둔다.
+                    chars.drain(start..end);
+                    violation = found;
+                    reduced = true;
+                }
+                _ => start = end,
+            }
+        }
+        // 한 글자씩 지워봐도 줄일 수 없으면 끝난다.
+        if !reduced {
+            chunk = if chunk == 1 { 0 } else { chunk.div_ceil(2) };
+        }
+    }
+    Some(Minimized {
+        text: chars.into_iter().collect(),
+        violation,
+        checks,
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message.to_string(),
+        None => match payload.downcast_ref::<String>() {
+            Some(message) => message.clone(),
+            None => "unknown panic".to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimize() -> Result<(), Box<dyn std::error::Error>> {
+        let segmenter = Segmenter::new()?;
+        assert_eq!(minimize(&segmenter, ""), None);
+        assert_eq!(minimize(&segmenter, "Hi Mr. Kim. Bye."), None);
+
+        let text = "First. Then ♨ and ☝ and 5∯ were there. Last.";
+        let first = minimize(&segmenter, text).unwrap();
+        assert_eq!(first.violation, Violation::Lost);
+        assert!(first.text.chars().count() <= 3);
+        assert_eq!(check(&segmenter, &first.text), Some(Violation::Lost));
+        assert_eq!(minimize(&segmenter, text), Some(first));
+
+        assert_eq!(
+            panic_message(&"boom" as &(dyn Any + Send)),
+            "boom".to_string()
+        );
+        Ok(())
+    }
+}
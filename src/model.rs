@@ -0,0 +1,262 @@
+//! Small statistical model which decides whether a period after an abbreviation, an initial or
+//! an acronym ends a sentence, like "Inc." in "Apple Inc. The company" or in "Apple Inc. Board".
+//! The rules decide such periods with lists of common sentence starters, and the model is
+//! consulted for these periods only, so every other boundary stays as the rules put it.
+//!
+//! The model is a logistic regression over character n-grams and words around the period. It
+//! has no dependency and is distributed as plain text, one feature and its weight per line, so
+//! a model trained on a corpus of the target domain can be shipped along with an application.
+//!
+//! ```rust
+//! use pragmatic_segmenter::model::NgramModel;
+//! use pragmatic_segmenter::Segmenter;
+//!
+//! let examples = [
+//!     ("He works at Apple Inc. The firm is big.", 21, true),
+//!     ("They sued Acme Inc. The case is open.", 18, true),
+//!     ("It is Apple Inc. Board news.", 15, false),
+//!     ("Ask Acme Inc. Board members.", 12, false),
+//! ];
+//! let model = NgramModel::train(examples, 20);
+//!
+//! let text = "We met Foo Inc. Board members. Then Foo Inc. The deal is done.";
+//! let segmenter = Segmenter::new()?;
+//! let result: Vec<_> = segmenter.segment(text).collect();
+//! assert_eq!(
+//!     result,
+//!     vec!["We met Foo Inc. ", "Board members. ", "Then Foo Inc. ", "The deal is done."]
+//! );
+//!
+//! let segmenter = Segmenter::builder().model(model).build()?;
+//! let result: Vec<_> = segmenter.segment(text).collect();
+//! assert_eq!(
+//!     result,
+//!     vec!["We met Foo Inc. Board members. ", "Then Foo Inc. ", "The deal is done."]
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+
+use crate::util::fnv1a;
+use crate::{BoundaryCandidate, BoundaryScorer};
+
+/// Name of the intercept in the text format.
+const BIAS: &str = "bias";
+
+/// Logistic regression model of sentence boundaries after a period. See the
+/// [module documentation](self).
+#[derive(Clone, PartialEq, Default)]
+pub struct NgramModel {
+    bias: f32,
+    weights: BTreeMap<String, f32>,
+}
+
+impl NgramModel {
+    /// Parse a model in the text format written by [`Display`](fmt::Display): one feature per
+    /// line, followed by a tab and its weight. The intercept is named "bias". Empty lines and
+    /// lines which start with `#` are ignored.
+    pub fn parse(data: &str) -> Result<Self, Box<dyn Error>> {
+        let mut model = NgramModel::default();
+        for (idx, line) in data.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (feature, weight) = line
+                .rsplit_once('\t')
+                .ok_or_else(|| format!("line {}: expected a feature and a weight", idx + 1))?;
+            let weight: f32 = weight
+                .parse()
+                .map_err(|err| format!("line {}: {}", idx + 1, err))?;
+            if feature == BIAS {
+                model.bias = weight;
+            } else {
+                model.weights.insert(feature.to_string(), weight);
+            }
+        }
+        Ok(model)
+    }
+
+    /// Train a model with stochastic gradient descent on `examples` of a text, the byte offset
+    /// of a period in it, and whether the period ends a sentence. The examples are visited in
+    /// order `epochs` times, so the result is deterministic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the offset of an example is not the byte offset of a character of its text.
+    pub fn train<'a>(
+        examples: impl IntoIterator<Item = (&'a str, usize, bool)>,
+        epochs: usize,
+    ) -> Self {
+        const LEARNING_RATE: f32 = 0.2;
+        const L2: f32 = 1e-4;
+
+        let examples: Vec<_> = examples
+            .into_iter()
+            .map(|(text, period, label)| match features(text, period) {
+                Some(features) => (features, label),
+                None => panic!("no character at byte {} of {:?}", period, text),
+            })
+            .collect();
+        let mut model = NgramModel::default();
+        for _ in 0..epochs {
+            for (features, label) in &examples {
+                let gradient = f32::from(u8::from(*label)) - model.score(features);
+                model.bias += LEARNING_RATE * gradient;
+                for feature in features {
+                    let weight = model.weights.entry(feature.clone()).or_default();
+                    *weight += LEARNING_RATE * (gradient - L2 * *weight);
+                }
+            }
+        }
+        model
+    }
+
+    /// Probability that the period at byte offset `period` of `text` ends a sentence, or `None`
+    /// if `period` is not the byte offset of a character of `text`.
+    pub fn probability(&self, text: &str, period: usize) -> Option<f32> {
+        Some(self.score(&features(text, period)?))
+    }
+
+    fn score(&self, features: &[String]) -> f32 {
+        let sum: f32 = self.bias
+            + features
+                .iter()
+                .filter_map(|feature| self.weights.get(feature))
+                .sum::<f32>();
+        1.0 / (1.0 + (-sum).exp())
+    }
+}
+
+/// A period ends a sentence if its [`probability()`](NgramModel::probability) is at least 0.5.
+impl BoundaryScorer for NgramModel {
+    fn is_boundary(&self, text: &str, candidate: &BoundaryCandidate) -> bool {
+        self.probability(text, candidate.period)
+            .is_some_and(|probability| probability >= 0.5)
+    }
+}
+
+/// The model in its text format, which [`parse()`](NgramModel::parse) reads back.
+impl fmt::Display for NgramModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}\t{}", BIAS, self.bias)?;
+        for (feature, weight) in &self.weights {
+            writeln!(f, "{}\t{}", feature, weight)?;
+        }
+        Ok(())
+    }
+}
+
+/// Only the size and a hash of the weights, since a model may have many thousands of features.
+impl fmt::Debug for NgramModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NgramModel")
+            .field("features", &self.weights.len())
+            .field("hash", &fnv1a(self.to_string().as_bytes()))
+            .finish()
+    }
+}
+
+/// Features of the period at byte offset `period` of `text`: the words before and after it,
+/// their shapes, and the character n-grams next to the period. `None` if `period` is not the
+/// byte offset of a character of `text`.
+pub(crate) fn features(text: &str, period: usize) -> Option<Vec<String>> {
+    let before = text.get(..period)?;
+    let word = before
+        .rsplit(char::is_whitespace)
+        .next()
+        .unwrap_or_default();
+    // NOTE: 마침표가 아닌 글자일 수도 있으므로, 글자 길이만큼 건너뛴다.
+    let mark = text[period..].chars().next()?;
+    let after = text[period + mark.len_utf8()..].trim_start();
+    let next = after.split(char::is_whitespace).next().unwrap_or_default();
+    let word_lower = word.to_lowercase();
+    let next_lower = next.to_lowercase();
+
+    let mut features = vec![
+        format!("w={}", word_lower),
+        format!("ws={}", shape(word)),
+        format!("n={}", next_lower),
+        format!("ns={}", shape(next)),
+        format!("wn={}|{}", word_lower, next_lower),
+        format!("len={}", word.chars().count().min(6)),
+    ];
+    let left: Vec<char> = word_lower.chars().rev().take(3).collect();
+    let right: Vec<char> = next_lower.chars().take(3).collect();
+    for n in 1..=3 {
+        if left.len() >= n {
+            let gram: String = left[..n].iter().rev().collect();
+            features.push(format!("l{}={}", n, gram));
+        }
+        if right.len() >= n {
+            let gram: String = right[..n].iter().collect();
+            features.push(format!("r{}={}", n, gram));
+        }
+    }
+    Some(features)
+}
+
+/// Shape of a word like "Xx" for "Kim", "X.X" for "U.S" or "d" for "42", where runs of the same
+/// class are collapsed.
+fn shape(word: &str) -> String {
+    let mut shape = String::new();
+    for c in word.chars() {
+        let class = if c.is_uppercase() {
+            'X'
+        } else if c.is_lowercase() {
+            'x'
+        } else if c.is_numeric() {
+            'd'
+        } else {
+            c
+        };
+        if !shape.ends_with(class) {
+            shape.push(class);
+        }
+    }
+    shape
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_features() {
+        assert_eq!(
+            features("Apple Inc. The firm", 9).unwrap(),
+            vec![
+                "w=inc",
+                "ws=Xx",
+                "n=the",
+                "ns=Xx",
+                "wn=inc|the",
+                "len=3",
+                "l1=c",
+                "r1=t",
+                "l2=nc",
+                "r2=th",
+                "l3=inc",
+                "r3=the",
+            ]
+        );
+        assert!(features("끝。다음", 3).is_some());
+        assert_eq!(features("끝。다음", 4), None);
+        assert_eq!(features("Apple Inc.", 10), None);
+        assert_eq!(shape("U.S"), "X.X");
+        assert_eq!(shape("42nd"), "dx");
+    }
+
+    #[test]
+    fn test_parse() -> Result<(), Box<dyn Error>> {
+        let model = NgramModel::parse("# comment\nbias\t-0.5\n\nw=inc\t1.5\n")?;
+        assert_eq!(model.bias, -0.5);
+        assert_eq!(model.weights.get("w=inc"), Some(&1.5));
+        assert_eq!(NgramModel::parse(&model.to_string())?, model);
+        assert!(NgramModel::parse("w=inc 1.5").is_err());
+        assert!(NgramModel::parse("w=inc\tlarge").is_err());
+        Ok(())
+    }
+}
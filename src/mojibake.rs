@@ -0,0 +1,88 @@
+use crate::util;
+
+/// Typographic characters encoded in UTF-8 and decoded as Windows-1252, which is the most common
+/// mojibake of web pages: (third character of the mojibake, original character). The first two
+/// characters are always "â€".
+const PUNCTUATIONS: &[(char, char)] = &[
+    ('™', '’'),
+    ('˜', '‘'),
+    ('œ', '“'),
+    // NOTE: 0x9D는 Windows-1252에 없는 바이트라, 대부분의 디코더가 U+009D로 읽는다.
+    ('\u{9d}', '”'),
+    ('“', '–'),
+    ('”', '—'),
+    ('¦', '…'),
+    ('¢', '•'),
+];
+
+/// Text whose mojibake punctuations like "â€™" are repaired into "’", along with the mapping of
+/// byte offsets back to the original text.
+pub(crate) struct RepairedMojibake {
+    pub text: String,
+    /// (offset of a repaired character in `text`, number of bytes removed up to that character)
+    runs: Vec<(usize, usize)>,
+}
+
+impl RepairedMojibake {
+    /// `None` if there's nothing to repair.
+    pub fn new(original: &str) -> Option<Self> {
+        let mut text = String::with_capacity(original.len());
+        let mut runs = Vec::new();
+        let mut removed = 0;
+        let mut rest = original;
+        while let Some(idx) = rest.find("â€") {
+            text += &rest[..idx];
+            let after = &rest[idx + "â€".len()..];
+            let repaired = after.chars().next().and_then(|third| {
+                PUNCTUATIONS
+                    .iter()
+                    .find(|&&(mojibake, _)| mojibake == third)
+                    .map(|&(mojibake, original)| (mojibake.len_utf8(), original))
+            });
+            match repaired {
+                Some((len, punctuation)) => {
+                    removed += "â€".len() + len - punctuation.len_utf8();
+                    runs.push((text.len(), removed));
+                    text.push(punctuation);
+                    rest = &after[len..];
+                }
+                None => {
+                    text += "â€";
+                    rest = after;
+                }
+            }
+        }
+        if runs.is_empty() {
+            return None;
+        }
+        text += rest;
+        Some(RepairedMojibake { text, runs })
+    }
+
+    /// Byte offset in the original text which corresponds to `offset` of the repaired text. A
+    /// range which includes a repaired character includes the whole mojibake of the original.
+    pub fn original_offset(&self, offset: usize) -> usize {
+        util::original_offset(&self.runs, offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repaired_mojibake() {
+        assert!(RepairedMojibake::new("It’s “fine”. Price: 5€").is_none());
+        assert!(RepairedMojibake::new("Odd â€ here").is_none());
+
+        let original = "Itâ€™s â€œokâ€\u{9d} â€” yes";
+        let repaired = RepairedMojibake::new(original).unwrap();
+        assert_eq!(repaired.text, "It’s “ok” — yes");
+        // "’" 다음, "“" 앞과 다음, "”" 앞, 끝
+        let offsets: Vec<_> = [5, 7, 10, 12, repaired.text.len()]
+            .iter()
+            .map(|&offset| repaired.original_offset(offset))
+            .collect();
+        assert_eq!(offsets, vec![10, 12, 19, 21, original.len()]);
+    }
+}
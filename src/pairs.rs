@@ -0,0 +1,100 @@
+//! Pairs of sentences for next sentence prediction and sentence similarity datasets. Each pair
+//! holds two [`Segment`]s, so the spans of both sentences in the input are available.
+//!
+//! ```rust
+//! use pragmatic_segmenter::{pairs, Segmenter};
+//!
+//! let segmenter = Segmenter::new()?;
+//! let text = "Hi Mr. Kim. Let's meet at 3 P.M. See you there.";
+//! let consecutive: Vec<_> = pairs::consecutive(segmenter.segments(text))
+//!     .map(|(first, second)| (first.text, second.start))
+//!     .collect();
+//! assert_eq!(
+//!     consecutive,
+//!     vec![("Hi Mr. Kim. ", 12), ("Let's meet at 3 P.M. ", 33)]
+//! );
+//!
+//! let skipping: Vec<_> = pairs::skipping(segmenter.segments(text), 1)
+//!     .map(|(first, second)| (first.text, second.text))
+//!     .collect();
+//! assert_eq!(skipping, vec![("Hi Mr. Kim. ", "See you there.")]);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::VecDeque;
+
+use crate::Segment;
+
+/// Pairs of every two consecutive segments.
+pub fn consecutive<'a, I>(segments: I) -> Pairs<'a, I::IntoIter>
+where
+    I: IntoIterator<Item = Segment<'a>>,
+{
+    skipping(segments, 0)
+}
+
+/// Pairs of segments with `k` segments between them. `skipping(segments, 0)` is the same as
+/// [`consecutive()`].
+pub fn skipping<'a, I>(segments: I, k: usize) -> Pairs<'a, I::IntoIter>
+where
+    I: IntoIterator<Item = Segment<'a>>,
+{
+    Pairs {
+        segments: segments.into_iter(),
+        window: VecDeque::with_capacity(k + 2),
+        skip: k,
+    }
+}
+
+/// Iterator over pairs of segments, returned by [`consecutive()`] and [`skipping()`].
+#[derive(Debug, Clone)]
+pub struct Pairs<'a, I> {
+    segments: I,
+    window: VecDeque<Segment<'a>>,
+    skip: usize,
+}
+
+impl<'a, I> Iterator for Pairs<'a, I>
+where
+    I: Iterator<Item = Segment<'a>>,
+{
+    type Item = (Segment<'a>, Segment<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.window.len() < self.skip + 2 {
+            self.window.push_back(self.segments.next()?);
+        }
+        let first = self.window.pop_front()?;
+        Some((first, self.window[self.skip]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Kind;
+
+    #[test]
+    fn test_skipping() {
+        let text = "abcde";
+        let segments = (0..text.len()).map(|start| Segment {
+            text: &text[start..start + 1],
+            start,
+            kind: Kind::Sentence,
+            speaker: None,
+            overlong: false,
+        });
+        let texts = |k| -> Vec<_> {
+            skipping(segments.clone(), k)
+                .map(|(first, second)| (first.text, second.text))
+                .collect()
+        };
+        assert_eq!(
+            texts(0),
+            vec![("a", "b"), ("b", "c"), ("c", "d"), ("d", "e")]
+        );
+        assert_eq!(texts(2), vec![("a", "d"), ("b", "e")]);
+        assert_eq!(texts(4), vec![]);
+        assert_eq!(consecutive(segments.take(1)).count(), 0);
+    }
+}
@@ -0,0 +1,154 @@
+//! Segmentation of many documents at once on multiple threads, without a global thread pool.
+//! The threads are provided by an [`Executor`]: scoped threads of the standard library with an
+//! explicit count, the current thread alone for hosts without threads, or a scope of the
+//! caller's own thread pool.
+//!
+//! ```rust
+//! use pragmatic_segmenter::parallel::{segment_all, Threads};
+//! use pragmatic_segmenter::Segmenter;
+//!
+//! let segmenter = Segmenter::new()?;
+//! let documents = ["Hi Mr. Kim. Bye.", "Let's meet at 3 P.M. See you."];
+//! let results = segment_all(&segmenter, &documents, &Threads(2));
+//! let texts: Vec<Vec<_>> = results
+//!     .iter()
+//!     .map(|segments| segments.iter().map(|segment| segment.text).collect())
+//!     .collect();
+//! assert_eq!(
+//!     texts,
+//!     vec![
+//!         vec!["Hi Mr. Kim. ", "Bye."],
+//!         vec!["Let's meet at 3 P.M. ", "See you."],
+//!     ]
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Any thread pool which can run borrowed closures in a scope can be used as an executor. For
+//! example with rayon:
+//!
+//! ```rust,ignore
+//! struct Rayon;
+//!
+//! impl Executor for Rayon {
+//!     fn run(&self, job: &(dyn Fn() + Sync)) {
+//!         rayon::scope(|scope| {
+//!             for _ in 0..rayon::current_num_threads() {
+//!                 scope.spawn(|_| job());
+//!             }
+//!         });
+//!     }
+//! }
+//! ```
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use crate::{Segment, Segmenter};
+
+/// Runs the work of [`segment_all()`] on some threads.
+pub trait Executor {
+    /// Call `job` at least once, possibly on many threads at the same time, and return after
+    /// every call returned. Each call keeps taking documents until none are left, so the
+    /// number of calls is the number of threads which share the work.
+    fn run(&self, job: &(dyn Fn() + Sync));
+}
+
+/// Runs the work on the given number of scoped threads of the standard library, which are
+/// joined before [`segment_all()`] returns. `Threads(0)` uses as many threads as
+/// [`std::thread::available_parallelism()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Threads(pub usize);
+
+impl Executor for Threads {
+    fn run(&self, job: &(dyn Fn() + Sync)) {
+        let count = match self.0 {
+            0 => thread::available_parallelism().map_or(1, |n| n.get()),
+            n => n,
+        };
+        if count == 1 {
+            return job();
+        }
+        thread::scope(|scope| {
+            for _ in 0..count {
+                scope.spawn(job);
+            }
+        });
+    }
+}
+
+/// Runs the work on the calling thread only, for hosts where spawning threads is not allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CurrentThread;
+
+impl Executor for CurrentThread {
+    fn run(&self, job: &(dyn Fn() + Sync)) {
+        job()
+    }
+}
+
+/// Segment every document with `executor`. The results are in the order of `documents`, and
+/// are the same as calling [`Segmenter::segments()`] for each document.
+pub fn segment_all<'a, T>(
+    segmenter: &'a Segmenter,
+    documents: &'a [T],
+    executor: &impl Executor,
+) -> Vec<Vec<Segment<'a>>>
+where
+    T: AsRef<str> + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(documents.len()));
+    executor.run(&|| {
+        // 문서마다 락을 잡지 않도록, 각 스레드가 모은 결과를 마지막에 한번에 넘긴다.
+        let mut local = Vec::new();
+        loop {
+            let idx = next.fetch_add(1, Ordering::Relaxed);
+            let document = match documents.get(idx) {
+                Some(document) => document,
+                None => break,
+            };
+            local.push((
+                idx,
+                segmenter.segments(document.as_ref()).collect::<Vec<_>>(),
+            ));
+        }
+        // NOTE: 다른 스레드가 패닉하지 않는 한 실패하지 않는다.
+        results.lock().unwrap().append(&mut local);
+    });
+
+    let mut results = results.into_inner().unwrap(); // NOTE: 위와 같은 이유로 실패하지 않는다.
+    assert_eq!(
+        results.len(),
+        documents.len(),
+        "Executor::run() returned before finishing the work"
+    );
+    results.sort_unstable_by_key(|&(idx, _)| idx);
+    results.into_iter().map(|(_, segments)| segments).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment_all() -> Result<(), Box<dyn std::error::Error>> {
+        let segmenter = Segmenter::new()?;
+        let documents: Vec<String> = (0..50)
+            .map(|i| format!("Doc {}. It is Mr. Kim's. Bye.", i))
+            .collect();
+        let expected: Vec<Vec<_>> = documents
+            .iter()
+            .map(|document| segmenter.segments(document).collect())
+            .collect();
+        assert_eq!(segment_all(&segmenter, &documents, &Threads(4)), expected);
+        assert_eq!(segment_all(&segmenter, &documents, &Threads(0)), expected);
+        assert_eq!(
+            segment_all(&segmenter, &documents, &CurrentThread),
+            expected
+        );
+        assert!(segment_all(&segmenter, &[] as &[&str], &Threads(3)).is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,192 @@
+use crate::abbreviation_pack;
+use crate::{
+    AbbreviationKind, AbbreviationPack, AcronymBoundary, NumberedReferences, NumericExpressions,
+    Rule, SegmenterBuilder, UppercaseLetters,
+};
+
+/// Options for a kind of text, applied at once with
+/// [`SegmenterBuilder::preset()`](crate::SegmenterBuilder::preset). Options set after the preset
+/// override the ones set by it.
+///
+/// ```rust
+/// use pragmatic_segmenter::{Preset, Segmenter};
+///
+/// let segmenter = Segmenter::builder().preset(Preset::Legal).build()?;
+/// let result: Vec<_> = segmenter
+///     .segment("See para. 3 of the Act. Cf. Smith v. Jones.")
+///     .collect();
+/// assert_eq!(result, vec!["See para. 3 of the Act. ", "Cf. Smith v. Jones."]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Preset {
+    /// News articles: datelines like "WASHINGTON (Reuters) - ", name initials, acronyms which end
+    /// a sentence like "in the U.S. The", and quotations which are never split in the middle.
+    News,
+    /// Contracts, statutes and court decisions: references like "Sec. 5" or "para. 3", "Cf."
+    /// before a citation, numerals in parentheses like "(iv)" wherever they appear, and
+    /// brackets which span line breaks.
+    Legal,
+    /// Scientific and medical papers: citations in superscripts or parentheses, temperatures,
+    /// and the abbreviations of [`AbbreviationPack::biomedical()`].
+    Biomedical,
+    /// Chat messages and social media: sentences which start with a lowercase letter, any
+    /// uppercase letter of Unicode, and runs of spaces.
+    Chat,
+    /// Text recognized from scanned documents: runs of spaces, any uppercase letter of Unicode,
+    /// and quotations which are never split in the middle.
+    Ocr,
+    /// Financial filings like annual reports: headings like "Item 1A." or "Part II.", amounts
+    /// like "$1.5 bn.", company names like "Co. Ltd." and footnote markers like "(1)" or "*"
+    /// after a period.
+    Financial,
+    /// Patents and technical standards: references like "Sect. 5.1", "Pat. No. 5,123,456",
+    /// "FIG. 4" or "IEEE Std. 802.11" and quotations which are never split in the middle.
+    Patent,
+}
+
+#[rustfmt::skip]
+const LEGAL_NUMBER_ABBREVIATIONS: &[&str] = &[
+    "sec", "secs", "subsec", "para", "paras", "subpara", "cl", "ch", "chap", "reg", "regs",
+    "sched", "amend",
+];
+
+#[rustfmt::skip]
+const LEGAL_ABBREVIATIONS: &[&str] = &[
+    "ibid", "seq", "supp", "stat", "cir", "cert", "pet", "resp", "aff", "cong", "ann",
+];
+
+#[rustfmt::skip]
+const PATENT_NUMBER_ABBREVIATIONS: &[&str] = &[
+    "sec", "sect", "secs", "para", "paras", "cl", "fig", "figs", "pat", "pub", "appl", "ser",
+    "ed", "std", "tab", "eq", "eqs",
+];
+
+#[rustfmt::skip]
+const FINANCIAL_ABBREVIATIONS: &[&str] = &[
+    "bn", "bln", "mn", "mln", "tn", "trn", "sh", "shs", "yr", "yrs", "qtr", "qtrs",
+];
+
+/// "Item 1A." and "Part II." of a filing. Matched before the list items are detected, so that
+/// "Item 1. Business and Item 2. Properties" is not taken for a list.
+const FINANCIAL_HEADING: &str = r"\b(?:Item|ITEM)\s+\d{1,2}[A-Z]?\.|\b(?:Part|PART)\s+[IV]{1,3}\.";
+
+impl Preset {
+    pub(crate) fn configure(self, builder: SegmenterBuilder) -> SegmenterBuilder {
+        match self {
+            Preset::News => builder
+                .datelines(true)
+                .name_initials(true)
+                .acronym_boundary(AcronymBoundary::SentenceStarters)
+                .merge_unbalanced_quotes(true)
+                .numeric_expressions(NumericExpressions::default().degrees(true)),
+            Preset::Legal => builder
+                .name_initials(true)
+                .numerals_in_parens_in_any_context(true)
+                .merge_unbalanced_quotes(true)
+                .multiline_brackets(true)
+                .abbreviations(AbbreviationPack::from_groups(
+                    "legal",
+                    &[
+                        (LEGAL_NUMBER_ABBREVIATIONS, AbbreviationKind::Number),
+                        (&["cf"], AbbreviationKind::Prepositive),
+                        (LEGAL_ABBREVIATIONS, AbbreviationKind::Plain),
+                    ],
+                )),
+            Preset::Biomedical => builder
+                .numbered_references(
+                    NumberedReferences::default()
+                        .superscripts(true)
+                        .parentheses(true)
+                        .bare_numbers(false),
+                )
+                .numeric_expressions(NumericExpressions::default().degrees(true))
+                .abbreviations(AbbreviationPack::biomedical())
+                .rule(dosing_boundary_rule()),
+            Preset::Chat => builder
+                .lowercase_sentence_starts(true)
+                .uppercase_letters(UppercaseLetters::Unicode)
+                .collapse_whitespace(true),
+            Preset::Ocr => builder
+                .uppercase_letters(UppercaseLetters::Unicode)
+                .collapse_whitespace(true)
+                .merge_unbalanced_quotes(true),
+            Preset::Financial => builder
+                .numbered_references(
+                    NumberedReferences::default()
+                        .parentheses(true)
+                        .bare_numbers(false),
+                )
+                .protected_pattern(FINANCIAL_HEADING)
+                .abbreviations(AbbreviationPack::from_groups(
+                    "financial",
+                    &[
+                        (&["approx"], AbbreviationKind::Prepositive),
+                        (FINANCIAL_ABBREVIATIONS, AbbreviationKind::Plain),
+                    ],
+                ))
+                .rule(company_suffix_rule())
+                .rule(footnote_marker_rule()),
+            Preset::Patent => builder
+                .merge_unbalanced_quotes(true)
+                .abbreviations(AbbreviationPack::from_groups(
+                    "patent",
+                    &[(PATENT_NUMBER_ABBREVIATIONS, AbbreviationKind::Number)],
+                ))
+                .rule(patent_number_rule()),
+        }
+    }
+}
+
+/// Protected periods are written as `∯` when user rules run, and the multi-period abbreviation
+/// rule protects every period of "b.i.d.", so the last one is restored here.
+fn dosing_boundary_rule() -> Rule {
+    let lookbehind = abbreviation_pack::DOSING
+        .iter()
+        .map(|abbr| format!(r"\b{}", abbr.replace('.', "∯")))
+        .collect::<Vec<_>>()
+        .join("|");
+    Rule::new(
+        "DosingBoundaryRule",
+        &format!(r"(?<={})∯(?=\s[A-Z])", lookbehind),
+        ".",
+    )
+    .unwrap() // NOTE: DOSING는 상수이므로 실패하지 않는다.
+    .with_example("5 mg t∯i∯d∯ p∯o∯ Results", "5 mg t∯i∯d∯ p∯o. Results")
+    .with_example("5 mg t∯i∯d∯ for a week", "5 mg t∯i∯d∯ for a week")
+}
+
+/// "Co." is an abbreviation which may end a sentence, but not before "Ltd." or "LLC".
+fn company_suffix_rule() -> Rule {
+    Rule::builtin(
+        "CompanySuffixRule",
+        r"(?<=\bCo)\.(?=\s(?:Ltd|LLC|Inc|Limited|KG|AG)\b)",
+        "∯",
+    )
+    .with_example("Samsung Co. Ltd. rose", "Samsung Co∯ Ltd. rose")
+    .with_example("the Co. The", "the Co. The")
+}
+
+/// Footnote markers like "*" or "†" after a period belong to the sentence before them, like
+/// [`NumberedReferences`] do.
+fn footnote_marker_rule() -> Rule {
+    Rule::builtin(
+        "FootnoteMarkerRule",
+        r"(?<=[^\s])\.(\*{1,3}|[†‡])(\s)(?=[A-Z])",
+        "∯\\1\r\\2",
+    )
+    .with_example("per share.* Next", "per share∯*\r Next")
+}
+
+/// "Pat. No." or "Appl. No." before a serial number. The abbreviations are followed by "No."
+/// instead of a number, so [`AbbreviationKind::Number`] doesn't cover them.
+fn patent_number_rule() -> Rule {
+    Rule::builtin(
+        "PatentNumberRule",
+        r"(?<=\bPat|\bPub|\bAppl|\bSer)\.(?=\sNos?\b)",
+        "∯",
+    )
+    .with_example("U.S. Pat. No. 5", "U.S. Pat∯ No. 5")
+    .with_example("a Pat. Nothing", "a Pat. Nothing")
+}
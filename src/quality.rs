@@ -0,0 +1,106 @@
+use crate::lite::{CLOSINGS, TERMINALS};
+use crate::{Kind, Segment};
+
+/// Capitalization and punctuation statistics of the segments of a document, returned by
+/// [`Segmenter::quality()`](crate::Segmenter::quality). Well-edited text has almost every
+/// sentence capitalized and punctuated, while boilerplate, menus and spam scraped from the web
+/// mostly don't, so ingestion pipelines can filter documents by [`score()`](Quality::score).
+///
+/// When the segments are needed anyway, compute it from them with
+/// [`from_segments()`](Quality::from_segments) instead of segmenting twice.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Quality {
+    /// Number of segments which are not blank.
+    pub segments: usize,
+    /// Number of segments whose first letter or digit is not a lowercase letter. List item
+    /// markers are skipped.
+    pub capitalized: usize,
+    /// Number of segments which end with a terminal punctuation like ".", "?" or "。", possibly
+    /// followed by closing quotation marks or brackets.
+    pub terminated: usize,
+}
+
+impl Quality {
+    /// Statistics of `segments`.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::{Quality, Segmenter};
+    ///
+    /// let segmenter = Segmenter::new()?;
+    /// let segments: Vec<_> = segmenter.segments("Hi Mr. Kim.\nclick here\n").collect();
+    /// let quality = Quality::from_segments(&segments);
+    /// assert_eq!((quality.segments, quality.capitalized, quality.terminated), (2, 1, 1));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_segments<'a, 'b: 'a>(segments: impl IntoIterator<Item = &'a Segment<'b>>) -> Self {
+        let mut quality = Quality::default();
+        for segment in segments {
+            let mut text = segment.text.trim();
+            if text.is_empty() {
+                continue;
+            }
+            if let Kind::ListItem { marker } = segment.kind {
+                text = segment.text[marker.len()..].trim();
+            }
+            quality.segments += 1;
+            if !text
+                .chars()
+                .find(|c| c.is_alphanumeric())
+                .is_some_and(char::is_lowercase)
+            {
+                quality.capitalized += 1;
+            }
+            if text.trim_end_matches(CLOSINGS).ends_with(TERMINALS) {
+                quality.terminated += 1;
+            }
+        }
+        quality
+    }
+
+    /// Fraction of the segments which are capitalized, or 0 if there's no segment.
+    pub fn capitalized_ratio(&self) -> f64 {
+        ratio(self.capitalized, self.segments)
+    }
+
+    /// Fraction of the segments which end with a terminal punctuation, or 0 if there's no
+    /// segment.
+    pub fn terminated_ratio(&self) -> f64 {
+        ratio(self.terminated, self.segments)
+    }
+
+    /// Average of [`capitalized_ratio()`](Quality::capitalized_ratio) and
+    /// [`terminated_ratio()`](Quality::terminated_ratio), from 0 to 1.
+    pub fn score(&self) -> f64 {
+        (self.capitalized_ratio() + self.terminated_ratio()) / 2.0
+    }
+}
+
+fn ratio(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    count as f64 / total as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Segmenter;
+
+    #[test]
+    fn test_quality() -> Result<(), Box<dyn std::error::Error>> {
+        let segmenter = Segmenter::new()?;
+        let quality =
+            segmenter.quality("\"Hi,\" he said. 3 cats (ok.) \n1. red one\n\nhome | login");
+        assert_eq!(quality.segments, 4);
+        assert_eq!(quality.capitalized, 2);
+        assert_eq!(quality.terminated, 2);
+        assert_eq!(quality.score(), 0.5);
+
+        let empty = segmenter.quality(" \n");
+        assert_eq!(empty, Quality::default());
+        assert_eq!(empty.score(), 0.0);
+        Ok(())
+    }
+}
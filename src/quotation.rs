@@ -0,0 +1,72 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// Pairs of quotation marks for [`Segmenter::quotations()`](crate::Segmenter::quotations).
+pub(crate) const QUOTATION_MARKS: &[(&str, &str)] = &[
+    ("\"", "\""),
+    ("“", "”"),
+    ("„", "“"),
+    ("'", "'"),
+    ("‘", "’"),
+    ("«", "»"),
+    ("‹", "›"),
+];
+
+/// A quoted span of the input, returned by
+/// [`Segmenter::quotations()`](crate::Segmenter::quotations).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Quotation<'a> {
+    /// Text of the quotation including the quotation marks. This is a slice of the input.
+    pub text: &'a str,
+    /// Byte offset of [`text`](Quotation::text) in the input.
+    pub start: usize,
+    /// Indices of the segments which the quotation spans, in the order of
+    /// [`Segmenter::segments()`](crate::Segmenter::segments).
+    pub sentences: Range<usize>,
+}
+
+impl<'a> Quotation<'a> {
+    /// Text of the quotation without the quotation marks.
+    pub fn content(&self) -> &'a str {
+        let mut chars = self.text.chars();
+        chars.next();
+        chars.next_back();
+        chars.as_str()
+    }
+}
+
+/// Hide apostrophes between two letters like "don't" or "l’homme" with spaces of the same
+/// length, so that they are not taken for quotation marks. Byte offsets are kept.
+pub(crate) fn mask_apostrophes(text: &str) -> Cow<'_, str> {
+    let mut result = Cow::Borrowed(text);
+    let mut prev = None;
+    let mut chars = text.char_indices().peekable();
+    while let Some((idx, c)) = chars.next() {
+        let next = chars.peek().map(|&(_, c)| c);
+        if matches!(c, '\'' | '’')
+            && prev.is_some_and(char::is_alphanumeric)
+            && next.is_some_and(char::is_alphanumeric)
+        {
+            result
+                .to_mut()
+                .replace_range(idx..idx + c.len_utf8(), &" ".repeat(c.len_utf8()));
+        }
+        prev = Some(c);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mask_apostrophes() {
+        assert_eq!(
+            mask_apostrophes("'Don't,' l’homme's"),
+            "'Don t,' l   homme s"
+        );
+        assert!(matches!(mask_apostrophes("'hi'"), Cow::Borrowed(_)));
+    }
+}
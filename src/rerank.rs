@@ -0,0 +1,285 @@
+//! Re-decision of sentence boundaries after a period which the rules can't tell well, like the
+//! period of an abbreviation followed by a capital letter. The segments are split or merged at
+//! such periods while their spans stay slices of the input.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::{Kind, Segment, Segmenter};
+
+/// Decides whether a period which the rules can't tell well ends a sentence, for example with a
+/// classifier trained on the domain of the input. Set it with
+/// [`boundary_scorer()`](crate::SegmenterBuilder::boundary_scorer).
+///
+/// ```rust
+/// use pragmatic_segmenter::{BoundaryCandidate, BoundaryScorer, Segmenter};
+///
+/// /// Never split after "Inc.", whatever follows.
+/// #[derive(Debug)]
+/// struct KeepInc;
+///
+/// impl BoundaryScorer for KeepInc {
+///     fn is_boundary(&self, text: &str, candidate: &BoundaryCandidate) -> bool {
+///         candidate.rules && !text[..candidate.period].ends_with("Inc")
+///     }
+/// }
+///
+/// let segmenter = Segmenter::builder().boundary_scorer(KeepInc).build()?;
+/// let result: Vec<_> = segmenter.segment("He works at Foo Inc. The firm is big.").collect();
+/// assert_eq!(result, vec!["He works at Foo Inc. The firm is big."]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub trait BoundaryScorer: fmt::Debug + Send + Sync {
+    /// Whether `candidate` of `text` ends a sentence. `text` is the whole input of
+    /// [`Segmenter::segments()`].
+    fn is_boundary(&self, text: &str, candidate: &BoundaryCandidate) -> bool;
+}
+
+/// Period of a known abbreviation, an initial or an acronym followed by spaces and an
+/// uppercase letter on the same line, like "Inc." in "Foo Inc. The" or "Foo Inc. Board". Passed
+/// to a [`BoundaryScorer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct BoundaryCandidate {
+    /// Byte offset of the period in the input.
+    pub period: usize,
+    /// Byte offset of the uppercase letter, where the next sentence starts if the period ends
+    /// a sentence.
+    pub next: usize,
+    /// Whether the rules ended a sentence at the period.
+    pub rules: bool,
+}
+
+/// Score of the boundary between two adjacent segments. See
+/// [`merge_scorer()`](crate::SegmenterBuilder::merge_scorer).
+type ScoreFn = dyn Fn(&str, &str) -> f32 + Send + Sync;
+
+/// Closure of [`merge_scorer()`](crate::SegmenterBuilder::merge_scorer) and its threshold.
+#[derive(Clone)]
+pub(crate) struct MergeScorer {
+    pub(crate) threshold: f32,
+    pub(crate) score: Arc<ScoreFn>,
+}
+
+impl MergeScorer {
+    /// Whether the score of the boundary between `before` and `after` is at or above the
+    /// threshold.
+    pub(crate) fn merges(&self, before: &str, after: &str) -> bool {
+        (self.score)(before, after) >= self.threshold
+    }
+}
+
+/// Only the threshold, since a closure can't be printed.
+impl fmt::Debug for MergeScorer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergeScorer")
+            .field("threshold", &self.threshold)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Every [`BoundaryCandidate`] of `text`, in order. `segments` are the segments of `text`
+/// which the rules made.
+pub(crate) fn candidates(
+    segmenter: &Segmenter,
+    text: &str,
+    segments: &[Segment],
+) -> Vec<BoundaryCandidate> {
+    let mut periods: Vec<_> = segmenter
+        .abbreviation_periods(text)
+        .into_iter()
+        .map(|(period, _)| period)
+        .chain(
+            segmenter
+                .abbreviation_occurrences(text)
+                .into_iter()
+                .map(|occurrence| occurrence.start + occurrence.text.len() - 1),
+        )
+        .collect();
+    periods.sort_unstable();
+    periods.dedup();
+
+    periods
+        .into_iter()
+        .filter_map(|period| {
+            let rest = &text[period + 1..];
+            let next = rest.trim_start_matches(&[' ', '\t'][..]);
+            let starts_upper = next.chars().next().is_some_and(char::is_uppercase);
+            if next.len() == rest.len() || !starts_upper {
+                return None;
+            }
+            let next = text.len() - next.len();
+            Some(BoundaryCandidate {
+                period,
+                next,
+                rules: segments
+                    .binary_search_by_key(&next, |segment| segment.start)
+                    .is_ok(),
+            })
+        })
+        .collect()
+}
+
+/// Split `segments` at every `next` offset whose decision is `true`, and merge segments which
+/// start at a `next` offset whose decision is `false` into the previous one. `decisions` must
+/// be sorted by the offsets. A piece split off a segment is a [`Kind::Sentence`], and a merged
+/// segment keeps the kind of the first one. Segments of different speakers are never merged.
+pub(crate) fn apply<'a>(
+    text: &'a str,
+    segments: Vec<Segment<'a>>,
+    decisions: impl IntoIterator<Item = (usize, bool)>,
+) -> Vec<Segment<'a>> {
+    let mut decisions = decisions.into_iter().peekable();
+    let mut result: Vec<Segment<'a>> = Vec::with_capacity(segments.len());
+    for segment in segments {
+        while decisions
+            .next_if(|&(next, _)| next < segment.start)
+            .is_some()
+        {}
+        let mut current = segment;
+        if let Some((_, false)) = decisions.next_if(|&(next, _)| next == segment.start) {
+            match result.last() {
+                Some(last)
+                    if last.start + last.text.len() == segment.start
+                        && last.speaker == segment.speaker =>
+                {
+                    let last = result.pop().unwrap(); // Must exist
+                    current = Segment {
+                        text: &text[last.start..segment.start + segment.text.len()],
+                        ..last
+                    };
+                }
+                _ => {}
+            }
+        }
+
+        let end = current.start + current.text.len();
+        while let Some((next, split)) = decisions.next_if(|&(next, _)| next < end) {
+            if !split || next <= current.start {
+                continue;
+            }
+            result.push(Segment {
+                text: &text[current.start..next],
+                ..current
+            });
+            current = Segment {
+                text: &text[next..end],
+                start: next,
+                kind: Kind::Sentence,
+                ..current
+            };
+        }
+        result.push(current);
+    }
+    result
+}
+
+/// Merge every segment into the previous one if `merges` tells so for the texts of the two. A
+/// merged segment is checked again with the segment after it, so a run of fragments becomes a
+/// single segment. Segments of different speakers, or with a gap between them, are never
+/// merged.
+pub(crate) fn merge<'a>(
+    text: &'a str,
+    segments: Vec<Segment<'a>>,
+    merges: impl Fn(&str, &str) -> bool,
+) -> Vec<Segment<'a>> {
+    let mut result: Vec<Segment<'a>> = Vec::with_capacity(segments.len());
+    for segment in segments {
+        match result.last_mut() {
+            Some(last)
+                if last.start + last.text.len() == segment.start
+                    && last.speaker == segment.speaker
+                    && merges(last.text, segment.text) =>
+            {
+                last.text = &text[last.start..segment.start + segment.text.len()];
+            }
+            _ => result.push(segment),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_candidates() -> Result<(), Box<dyn std::error::Error>> {
+        let segmenter = Segmenter::new()?;
+        let text = "Apple Inc. The firm is in the U.S. Mr. Kim left. Dr. smith and etc.\nThe end.";
+        let segments: Vec<_> = segmenter.segments(text).collect();
+        let found: Vec<_> = candidates(&segmenter, text, &segments)
+            .into_iter()
+            .map(|candidate| (&text[candidate.next..candidate.next + 3], candidate.rules))
+            .collect();
+        assert_eq!(found, vec![("The", true), ("Mr.", false), ("Kim", false)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply() -> Result<(), Box<dyn std::error::Error>> {
+        let segmenter = Segmenter::new()?;
+        let text = "A b. C d. E f. G h.";
+        let segments = || segmenter.segments(text).collect::<Vec<_>>();
+        fn texts<'a>(segments: Vec<Segment<'a>>) -> Vec<&'a str> {
+            segments.into_iter().map(|segment| segment.text).collect()
+        }
+        assert_eq!(texts(segments()), vec!["A b. ", "C d. ", "E f. ", "G h."]);
+        assert_eq!(
+            texts(apply(text, segments(), vec![(5, false), (10, false)])),
+            vec!["A b. C d. E f. ", "G h."]
+        );
+        assert_eq!(
+            texts(apply(text, segments(), vec![(0, false), (15, true)])),
+            vec!["A b. ", "C d. ", "E f. ", "G h."]
+        );
+
+        let merged = vec![Segment {
+            text,
+            start: 0,
+            kind: Kind::ListItem { marker: "A" },
+            speaker: None,
+            overlong: false,
+        }];
+        let result = apply(text, merged, vec![(5, true), (10, false), (15, true)]);
+        assert_eq!(
+            result
+                .iter()
+                .map(|segment| (segment.text, segment.kind))
+                .collect::<Vec<_>>(),
+            vec![
+                ("A b. ", Kind::ListItem { marker: "A" }),
+                ("C d. E f. ", Kind::Sentence),
+                ("G h.", Kind::Sentence),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge() -> Result<(), Box<dyn std::error::Error>> {
+        let segmenter = Segmenter::new()?;
+        let text = "We met. then left. and slept. Next day came. so long.";
+        let segments: Vec<_> = segmenter.segments(text).collect();
+        assert_eq!(segments.len(), 5);
+        let scorer = MergeScorer {
+            threshold: 0.5,
+            score: Arc::new(|_, after: &str| {
+                let lower = after.starts_with(char::is_lowercase);
+                f32::from(u8::from(lower))
+            }),
+        };
+        let result: Vec<_> = merge(text, segments, |before, after| scorer.merges(before, after))
+            .into_iter()
+            .map(|segment| (segment.start, segment.text))
+            .collect();
+        assert_eq!(
+            result,
+            vec![
+                (0, "We met. then left. and slept. "),
+                (30, "Next day came. so long."),
+            ]
+        );
+        Ok(())
+    }
+}
@@ -1,17 +1,435 @@
-use onig::{Error, Regex, RegexOptions, Syntax};
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::iter::FromIterator;
 
-pub struct Rule(Regex, &'static str);
+use onig::{Captures, Regex};
+
+use crate::util::re;
+
+/// A named regular expression replacement, which is the building block of pragmatic-segmenter.
+/// Every match of the regex, written in Oniguruma (Ruby) syntax, is replaced with the replacement
+/// string. `\1` ~ `\99` in the replacement refer to the capture groups like Python's `re.sub()`.
+///
+/// ```rust
+/// use pragmatic_segmenter::Rule;
+///
+/// let rule = Rule::new("SwapRule", r"(\w+) (\w+)", r"\2 \1")?
+///     .with_description("Swap two words")
+///     .with_example("hello world", "world hello");
+/// assert_eq!(rule.name(), "SwapRule");
+/// assert_eq!(rule.replace_all("hello world"), "world hello");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct Rule {
+    name: Cow<'static, str>,
+    description: Cow<'static, str>,
+    examples: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+    pattern: String,
+    regex: Regex,
+    replacement: Cow<'static, str>,
+    enabled: bool,
+}
 
 impl Rule {
-    pub fn new(regex: &str, replace: &'static str) -> Result<Self, Error> {
-        Ok(Rule(
-            Regex::with_options(regex, RegexOptions::REGEX_OPTION_NONE, Syntax::ruby())?,
-            replace,
-        ))
+    /// Compile a new rule.
+    pub fn new(
+        name: impl Into<Cow<'static, str>>,
+        regex: &str,
+        replacement: impl Into<Cow<'static, str>>,
+    ) -> Result<Self, Box<dyn Error>> {
+        Ok(Rule {
+            name: name.into(),
+            description: Cow::Borrowed(""),
+            examples: Vec::new(),
+            pattern: regex.to_string(),
+            regex: re(regex)?,
+            replacement: replacement.into(),
+            enabled: true,
+        })
+    }
+
+    /// Compile a rule of this crate, whose pattern is a constant.
+    pub(crate) fn builtin(name: &'static str, regex: &str, replacement: &'static str) -> Self {
+        // NOTE: 상수 패턴이므로 실패하지 않는다.
+        Self::new(name, regex, replacement).unwrap()
+    }
+
+    /// Set the description of the rule.
+    pub fn with_description(mut self, description: impl Into<Cow<'static, str>>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Add an example input and the expected result of [`replace_all()`](Rule::replace_all).
+    pub fn with_example(
+        mut self,
+        input: impl Into<Cow<'static, str>>,
+        output: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.examples.push((input.into(), output.into()));
+        self
+    }
+
+    /// Name of the rule, like `"PeriodBeforeNumberRule"`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Description of the rule. Empty if not given.
+    pub fn description(&self) -> &str {
+        &self.description
     }
 
+    /// Example inputs and their expected results.
+    pub fn examples(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.examples
+            .iter()
+            .map(|(input, output)| (input.as_ref(), output.as_ref()))
+    }
+
+    /// Source of the regex.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Replacement string.
+    pub fn replacement(&self) -> &str {
+        &self.replacement
+    }
+
+    /// Whether the rule is applied. A rule is disabled by
+    /// [`SegmenterBuilder::disable_rule()`](crate::SegmenterBuilder::disable_rule).
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub(crate) fn disable(&mut self) {
+        self.enabled = false;
+    }
+
+    /// Replace every match of the regex in `text`. A disabled rule returns `text` as it is.
     #[must_use]
     pub fn replace_all(&self, text: &str) -> String {
-        self.0.replace_all(text, self.1)
+        if !self.enabled {
+            return text.to_string();
+        }
+        // NOTE: onig의 replace_all()은 치환 문자열을 그대로 삽입하고 backreference를 처리해주지
+        // 않는다. pySBD는 파이썬 re.sub()을 쓰므로, 직접 expand 해줘야한다.
+        if self.replacement.contains('\\') {
+            self.regex
+                .replace_all(text, |c: &Captures| expand(&self.replacement, c))
+        } else {
+            self.regex.replace_all(text, self.replacement.as_ref())
+        }
+    }
+
+    /// Check that [`replace_all()`](Rule::replace_all) turns `input` into `expected`.
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Rule;
+    ///
+    /// let rule = Rule::new("PeriodBeforeNumberRule", r"\.(?=\d)", "∯")?;
+    /// assert!(rule.check("It is 1.5 km.", "It is 1∯5 km.").is_ok());
+    /// assert!(rule.check("It is 1.5 km.", "It is 1.5 km.").is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn check(&self, input: &str, expected: &str) -> Result<(), RuleMismatch> {
+        let actual = self.replace_all(input);
+        if actual == expected {
+            return Ok(());
+        }
+        Err(RuleMismatch {
+            rule: self.name.to_string(),
+            input: input.to_string(),
+            expected: expected.to_string(),
+            actual,
+        })
+    }
+
+    /// [`check()`](Rule::check) every example given with [`with_example()`](Rule::with_example).
+    pub fn check_examples(&self) -> Result<(), RuleMismatch> {
+        self.examples()
+            .try_for_each(|(input, expected)| self.check(input, expected))
+    }
+}
+
+impl Clone for Rule {
+    fn clone(&self) -> Self {
+        Rule {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            examples: self.examples.clone(),
+            pattern: self.pattern.clone(),
+            regex: re(&self.pattern).unwrap(), // Must succeed, since it was compiled once
+            replacement: self.replacement.clone(),
+            enabled: self.enabled,
+        }
+    }
+}
+
+impl fmt::Debug for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rule")
+            .field("name", &self.name)
+            .field("description", &self.description)
+            .field("examples", &self.examples)
+            .field("pattern", &self.pattern)
+            .field("replacement", &self.replacement)
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
+/// Ordered list of [`Rule`]s, which are applied one after another.
+///
+/// ```rust
+/// use pragmatic_segmenter::{Rule, RuleSet};
+///
+/// let rules = RuleSet::new()
+///     .with(Rule::new("A", "a", "b")?)
+///     .with(Rule::new("B", "b", "c")?);
+/// assert_eq!(rules.apply("ab"), "cc");
+/// assert_eq!(rules.get("B").map(Rule::pattern), Some("b"));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RuleSet {
+    rules: Vec<Rule>,
+}
+
+impl RuleSet {
+    /// Create an empty rule set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a rule, builder style.
+    pub fn with(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Append a rule.
+    pub fn push(&mut self, rule: Rule) {
+        self.rules.push(rule);
+    }
+
+    /// Apply every rule in order.
+    #[must_use]
+    pub fn apply(&self, text: &str) -> String {
+        let mut text = text.to_string();
+        for rule in &self.rules {
+            text = rule.replace_all(&text);
+        }
+        text
+    }
+
+    /// Find a rule by its name.
+    pub fn get(&self, name: &str) -> Option<&Rule> {
+        self.rules.iter().find(|rule| rule.name() == name)
+    }
+
+    /// Iterate over the rules in order.
+    pub fn iter(&self) -> std::slice::Iter<'_, Rule> {
+        self.rules.iter()
+    }
+
+    pub(crate) fn iter_mut(&mut self) -> std::slice::IterMut<'_, Rule> {
+        self.rules.iter_mut()
+    }
+
+    /// Number of rules.
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// [`check_examples()`](Rule::check_examples) of every rule.
+    pub fn check_examples(&self) -> Result<(), RuleMismatch> {
+        self.rules.iter().try_for_each(Rule::check_examples)
+    }
+
+    /// Whether there's no rule.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+impl From<Vec<Rule>> for RuleSet {
+    fn from(rules: Vec<Rule>) -> Self {
+        RuleSet { rules }
+    }
+}
+
+impl FromIterator<Rule> for RuleSet {
+    fn from_iter<I: IntoIterator<Item = Rule>>(iter: I) -> Self {
+        RuleSet {
+            rules: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<Rule> for RuleSet {
+    fn extend<I: IntoIterator<Item = Rule>>(&mut self, iter: I) {
+        self.rules.extend(iter);
+    }
+}
+
+impl<'a> IntoIterator for &'a RuleSet {
+    type Item = &'a Rule;
+    type IntoIter = std::slice::Iter<'a, Rule>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rules.iter()
+    }
+}
+
+/// Error returned by [`Rule::check()`] when the result differs from the expected one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleMismatch {
+    pub rule: String,
+    pub input: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl fmt::Display for RuleMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "rule {} failed\n   input: {:?}\nexpected: {:?}\n  actual: {:?}",
+            self.rule, self.input, self.expected, self.actual
+        )
+    }
+}
+
+impl Error for RuleMismatch {}
+
+/// Error returned when a rule given to
+/// [`SegmenterBuilder::disable_rule()`](crate::SegmenterBuilder::disable_rule) is not a rule of
+/// the segmenter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownRule(pub String);
+
+impl fmt::Display for UnknownRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown rule: {:?}", self.0)
+    }
+}
+
+impl Error for UnknownRule {}
+
+/// Define a `#[test]` function for each rule, which [checks](Rule::check) every pair of an input
+/// and its expected result.
+///
+/// ```rust
+/// use pragmatic_segmenter::{rule_tests, Rule};
+///
+/// rule_tests! {
+///     period_before_number: Rule::new("PeriodBeforeNumberRule", r"\.(?=\d)", "∯").unwrap() => {
+///         "It is 1.5 km." => "It is 1∯5 km.",
+///         "No number." => "No number.",
+///     },
+/// }
+/// ```
+#[macro_export]
+macro_rules! rule_tests {
+    ($($name:ident: $rule:expr => { $($input:expr => $expected:expr),* $(,)? }),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                let rule: $crate::Rule = $rule;
+                $(
+                    if let Err(err) = rule.check($input, $expected) {
+                        panic!("{}", err);
+                    }
+                )*
+            }
+        )*
+    };
+}
+
+/// Expand a replacement template the way Python's `re.sub()` does. `\1` ~ `\99` are replaced with
+/// the corresponding capture group (an empty string if the group did not participate in the
+/// match), and `\n`, `\r`, `\t`, `\\` are unescaped.
+fn expand(template: &str, captures: &Captures) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some(d @ '0'..='9') => {
+                let mut group = d.to_digit(10).unwrap() as usize; // Must be a digit
+                if let Some(e) = chars.peek().and_then(|e| e.to_digit(10)) {
+                    group = group * 10 + e as usize;
+                    chars.next();
+                }
+                result += captures.at(group).unwrap_or("");
+            }
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            Some('t') => result.push('\t'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type TestResult = Result<(), Box<dyn Error>>;
+
+    #[test]
+    fn test_backreference() -> TestResult {
+        let rule = Rule::new("Test", r"(a)(b)?(\s)", r"<\2\1>\r\3")?;
+        assert_eq!(rule.replace_all("ab a c"), "<ba>\r <a>\r c");
+        Ok(())
+    }
+
+    rule_tests! {
+        test_rule_tests_macro: Rule::new("Test", r"(?<=\d)\.(?=\d)", "∯").unwrap() => {
+            "1.5" => "1∯5",
+            "a.b" => "a.b",
+        },
+    }
+
+    #[test]
+    fn test_check() -> TestResult {
+        let rule = Rule::new("Test", r"a", "b")?.with_example("aa", "bb");
+        assert!(rule.check_examples().is_ok());
+
+        let err = rule.check("a", "a").unwrap_err();
+        assert_eq!(err.rule, "Test");
+        assert_eq!(err.actual, "b");
+
+        let rule = rule.with_example("a", "c");
+        assert!(rule.check_examples().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_disable() -> TestResult {
+        let mut rule = Rule::new("Test", r"\.(?=\d)", "∯")?;
+        rule.disable();
+        assert!(!rule.is_enabled());
+        assert_eq!(rule.replace_all("1.5"), "1.5");
+        assert!(!rule.clone().is_enabled());
+        Ok(())
+    }
+
+    #[test]
+    fn test_clone() -> TestResult {
+        let rule = Rule::new("Test", r"\.(?=\d)", "∯")?.with_example("1.5", "1∯5");
+        let cloned = rule.clone();
+        assert_eq!(cloned.name(), "Test");
+        assert_eq!(cloned.replace_all("1.5."), "1∯5.");
+        assert_eq!(cloned.examples().collect::<Vec<_>>(), vec![("1.5", "1∯5")]);
+        Ok(())
     }
 }
@@ -0,0 +1,431 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+use crate::util;
+
+/// A segment of the input, returned by [`Segmenter::segments()`](crate::Segmenter::segments).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Segment<'a> {
+    /// Text of the segment, including the trailing whitespaces. This is a slice of the input.
+    pub text: &'a str,
+    /// Byte offset of [`text`](Segment::text) in the input.
+    pub start: usize,
+    /// How the segment was separated from the previous one.
+    pub kind: Kind<'a>,
+    /// Speaker of the turn which the segment belongs to, like "SPEAKER 1" or "John", when
+    /// [`transcript()`](crate::SegmenterBuilder::transcript) is enabled. `None` otherwise, or if
+    /// the turn starts with a timestamp only.
+    pub speaker: Option<&'a str>,
+    /// Whether the segment is longer than
+    /// [`max_length()`](crate::SegmenterBuilder::max_length) with [`Overlong::Flag`](
+    /// crate::Overlong::Flag).
+    pub overlong: bool,
+}
+
+/// Kind of a [`Segment`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Kind<'a> {
+    /// Ordinary sentence of prose.
+    Sentence,
+    /// Item of an enumeration like "1. ", "b) " or "(iv) ", which was separated by the list item
+    /// rules. `marker` is the slice of [`Segment::text`] which holds the marker.
+    ListItem { marker: &'a str },
+    /// First segment of a speaker turn in a transcript, which starts with a prefix like
+    /// "SPEAKER 1: " or "[00:01:23] ". `prefix` is the slice of [`Segment::text`] which holds the
+    /// prefix, including the whitespaces after it.
+    Turn { prefix: &'a str },
+    /// Dateline which starts a news article, like "WASHINGTON (Reuters) - ". See
+    /// [`datelines()`](crate::SegmenterBuilder::datelines).
+    Dateline,
+    /// Piece of a sentence which was longer than
+    /// [`max_length()`](crate::SegmenterBuilder::max_length), split off at the end of a clause.
+    /// The first piece keeps the kind of the sentence, unless it is cut inside the marker of a
+    /// list item or the prefix of a turn.
+    Clause,
+}
+
+impl<'a> Segment<'a> {
+    /// Inline citation markers in the segment, like "[12]", "[3, 5–7]" or a superscript "¹²".
+    /// Superscript numbers count only right after a punctuation, like "proved.¹", since "m²" is
+    /// a unit. Numbers in parentheses are not included, since "(12)" is usually not a citation.
+    ///
+    /// A marker right after the end of a sentence stays in the sentence only if its style is
+    /// enabled in [`numbered_references()`](crate::SegmenterBuilder::numbered_references).
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::{NumberedReferences, Segmenter};
+    ///
+    /// let segmenter = Segmenter::builder()
+    ///     .numbered_references(NumberedReferences::default().superscripts(true))
+    ///     .build()?;
+    /// let text = "It was proved.[3, 5-7] Then it was used.² It is 5 m² big.";
+    /// let citations: Vec<_> = segmenter
+    ///     .segments(text)
+    ///     .flat_map(|segment| segment.citations())
+    ///     .map(|citation| (citation.text, citation.start, citation.numbers()))
+    ///     .collect();
+    /// assert_eq!(
+    ///     citations,
+    ///     vec![("[3, 5-7]", 14, vec![3, 5, 6, 7]), ("²", 40, vec![2])]
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn citations(&self) -> impl Iterator<Item = Citation<'a>> {
+        let Segment { text, start, .. } = *self;
+        let mut idx = 0;
+        let mut prev = None;
+        std::iter::from_fn(move || {
+            while let Some(c) = text[idx..].chars().next() {
+                let len = match c {
+                    '[' => bracket_len(&text[idx..]),
+                    c if SUPERSCRIPTS.contains(&c) && prev.is_some_and(is_punctuation) => {
+                        Some(superscript_len(&text[idx..]))
+                    }
+                    _ => None,
+                };
+                let begin = idx;
+                match len {
+                    Some(len) => {
+                        idx += len;
+                        prev = text[..idx].chars().next_back();
+                        return Some(Citation {
+                            text: &text[begin..idx],
+                            start: start + begin,
+                        });
+                    }
+                    None => {
+                        idx += c.len_utf8();
+                        prev = Some(c);
+                    }
+                }
+            }
+            None
+        })
+    }
+
+    /// Normalized form of the segment for near-duplicate detection: lowercased, without
+    /// punctuations, and with every run of whitespaces collapsed into a single space. Leading and
+    /// trailing whitespaces are removed. Punctuations inside a word are removed as well, so
+    /// "well-known" becomes "wellknown".
+    ///
+    /// ```rust
+    /// use pragmatic_segmenter::Segmenter;
+    ///
+    /// let segmenter = Segmenter::new()?;
+    /// let normalized: Vec<_> = segmenter
+    ///     .segments("“Hello,  World!” he said.\nHELLO WORLD, he said…")
+    ///     .map(|segment| segment.normalized())
+    ///     .collect();
+    /// assert_eq!(normalized, vec!["hello world he said", "hello world he said"]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn normalized(&self) -> String {
+        let mut normalized = String::with_capacity(self.text.len());
+        let mut space = false;
+        for c in self.text.chars() {
+            if c.is_whitespace() {
+                space = !normalized.is_empty();
+            } else if !is_any_punctuation(c) {
+                if space {
+                    normalized.push(' ');
+                    space = false;
+                }
+                normalized.extend(c.to_lowercase());
+            }
+        }
+        normalized
+    }
+}
+
+/// Inline citation marker in a [`Segment`], returned by [`Segment::citations()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Citation<'a> {
+    /// Text of the marker, including the brackets. This is a slice of the input.
+    pub text: &'a str,
+    /// Byte offset of [`text`](Citation::text) in the input.
+    pub start: usize,
+}
+
+impl Citation<'_> {
+    /// Numbers which the marker refers to, in order. Ranges like "5–7" are expanded.
+    pub fn numbers(&self) -> Vec<u32> {
+        let digits: String = self
+            .text
+            .chars()
+            .filter_map(|c| match SUPERSCRIPTS.iter().position(|&s| s == c) {
+                Some(10) => Some('-'),
+                Some(digit) => std::char::from_digit(digit as u32, 10),
+                None => Some(c),
+            })
+            .collect();
+        let mut numbers = Vec::new();
+        for part in digits.trim_matches(&['[', ']'][..]).split(&[',', ';'][..]) {
+            let mut bounds = part.split(&['-', '–'][..]).map(|n| n.trim().parse::<u32>());
+            match (bounds.next(), bounds.next(), bounds.next()) {
+                (Some(Ok(n)), None, _) => numbers.push(n),
+                // "[1–1000000]" 같은 오타로 메모리를 다 쓰지 않도록 범위의 길이를 제한한다.
+                (Some(Ok(from)), Some(Ok(to)), None) if from <= to && to - from < 1000 => {
+                    numbers.extend(from..=to)
+                }
+                _ => {}
+            }
+        }
+        numbers
+    }
+}
+
+/// Error returned by [`Segmenter::checked_segments()`](crate::Segmenter::checked_segments) when
+/// the segments don't cover the input exactly once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CoverageError {
+    /// Byte ranges of the input which contain non-whitespace characters, but are not in any
+    /// segment.
+    pub lost: Vec<Range<usize>>,
+    /// Byte ranges of the input which are in more than one segment.
+    pub overlapping: Vec<Range<usize>>,
+    /// Byte offset of the first problem: the first non-whitespace character which is lost, or
+    /// the start of the first overlapping range, whichever comes first.
+    pub offset: usize,
+    /// Up to [`EXCERPT`](CoverageError::EXCERPT) characters of the input from
+    /// [`offset`](CoverageError::offset), followed by "…" if the input goes on, so that a bug
+    /// report can show the offending text without the whole input.
+    pub excerpt: String,
+}
+
+impl CoverageError {
+    /// Maximum number of characters of [`excerpt`](CoverageError::excerpt).
+    pub const EXCERPT: usize = 40;
+
+    /// Check that `segments` of `text` cover every non-whitespace character of `text` exactly
+    /// once.
+    pub(crate) fn check(text: &str, segments: &[Segment]) -> Result<(), Self> {
+        let mut lost = Vec::new();
+        let mut overlapping = Vec::new();
+        let mut covered = 0;
+        for segment in segments {
+            let end = segment.start + segment.text.len();
+            if segment.start < covered {
+                overlapping.push(segment.start..end.min(covered));
+            } else if !text[covered..segment.start].trim().is_empty() {
+                lost.push(covered..segment.start);
+            }
+            covered = covered.max(end);
+        }
+        if !text[covered..].trim().is_empty() {
+            lost.push(covered..text.len());
+        }
+
+        // 잃어버린 범위는 공백으로 시작할 수 있으므로, 처음 나오는 글자를 가리킨다.
+        let lost_offset = lost.first().map(|range| {
+            let region = &text[range.clone()];
+            range.start + region.len() - region.trim_start().len()
+        });
+        let overlapping_offset = overlapping.first().map(|range| range.start);
+        let offset = match (lost_offset, overlapping_offset) {
+            (Some(lost), Some(overlapping)) => lost.min(overlapping),
+            (Some(offset), None) | (None, Some(offset)) => offset,
+            (None, None) => return Ok(()),
+        };
+        Err(CoverageError {
+            lost,
+            overlapping,
+            offset,
+            excerpt: util::excerpt(text, offset, Self::EXCERPT),
+        })
+    }
+}
+
+impl fmt::Display for CoverageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "segments do not cover the input at offset {} near {:?}
+       lost: {:?}
+overlapping: {:?}",
+            self.offset, self.excerpt, self.lost, self.overlapping
+        )
+    }
+}
+
+impl Error for CoverageError {}
+
+/// Superscript digits from zero to nine, and a superscript minus for ranges.
+const SUPERSCRIPTS: &[char] = &['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹', '⁻'];
+
+fn is_punctuation(c: char) -> bool {
+    matches!(
+        c,
+        '.' | ',' | ';' | ':' | '!' | '?' | ')' | ']' | '"' | '”' | '’'
+    )
+}
+
+/// Whether `c` is removed by [`Segment::normalized()`]: ASCII punctuations and symbols, and the
+/// punctuations of common scripts.
+fn is_any_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation()
+        || matches!(
+            c,
+            '¡' | '§' | '«' | '¶' | '·' | '»' | '¿'
+                | '\u{2010}'..='\u{2027}'
+                | '\u{2030}'..='\u{205E}'
+                | '\u{3001}'..='\u{3003}'
+                | '\u{3008}'..='\u{3011}'
+                | '\u{3014}'..='\u{301F}'
+                | '・'
+                | '\u{FF01}'..='\u{FF0F}'
+                | '\u{FF1A}'..='\u{FF20}'
+                | '\u{FF3B}'..='\u{FF40}'
+                | '\u{FF5B}'..='\u{FF65}'
+                | '،' | '؛' | '؟' | '٪'..='٭' | '۔'
+                | '।' | '॥' | '։' | '።' | '፧'
+        )
+}
+
+/// Length of a citation in brackets at the start of `text`, like "[1, 3–5]".
+fn bracket_len(text: &str) -> Option<usize> {
+    let close = text.get(1..)?.find(']')? + 1;
+    let content = &text[1..close];
+    let valid = content.starts_with(|c: char| c.is_ascii_digit())
+        && content.chars().count() <= 30
+        && content
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, ',' | ';' | ' ' | '-' | '–'));
+    if valid {
+        Some(close + 1)
+    } else {
+        None
+    }
+}
+
+/// Length of the run of superscript digits at the start of `text`. A comma is part of the run
+/// only between two superscripts, like "⁴,⁵", so that "⁴, and" keeps its comma.
+fn superscript_len(text: &str) -> usize {
+    let mut len = 0;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let inside = c == ','
+            && len > 0
+            && chars.peek().is_some_and(|next| SUPERSCRIPTS.contains(next));
+        if !SUPERSCRIPTS.contains(&c) && !inside {
+            break;
+        }
+        len += c.len_utf8();
+    }
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn citations(text: &str) -> Vec<(&str, Vec<u32>)> {
+        let segment = Segment {
+            text,
+            start: 0,
+            kind: Kind::Sentence,
+            speaker: None,
+            overlong: false,
+        };
+        segment
+            .citations()
+            .map(|citation| (citation.text, citation.numbers()))
+            .collect()
+    }
+
+    #[test]
+    fn test_citations() {
+        assert_eq!(
+            citations("Known.[1][2] See [4; 9-11] and [a] or [ 1]."),
+            vec![
+                ("[1]", vec![1]),
+                ("[2]", vec![2]),
+                ("[4; 9-11]", vec![4, 9, 10, 11])
+            ]
+        );
+        assert_eq!(
+            citations("Proved,¹⁻³ 10² times.⁴,⁵"),
+            vec![("¹⁻³", vec![1, 2, 3]), ("⁴,⁵", vec![4, 5])]
+        );
+        assert_eq!(
+            citations("Known.⁴, and also.⁶,"),
+            vec![("⁴", vec![4]), ("⁶", vec![6])]
+        );
+        assert_eq!(citations("Open [1 and [2"), vec![]);
+    }
+
+    #[test]
+    fn test_normalized() {
+        let normalized = |text| {
+            Segment {
+                text,
+                start: 0,
+                kind: Kind::Sentence,
+                speaker: None,
+                overlong: false,
+            }
+            .normalized()
+        };
+        assert_eq!(normalized("  Ça\tVA?  "), "ça va");
+        assert_eq!(normalized("「東京」に行く。"), "東京に行く");
+        assert_eq!(normalized("क्या यह ठीक है।"), "क्या यह ठीक है");
+        assert_eq!(normalized("İ — (x)"), "i̇ x");
+        assert_eq!(normalized("..."), "");
+    }
+
+    #[test]
+    fn test_coverage() {
+        let text = "  One. Two. Three.\n";
+        let segment = |start: usize, end: usize| Segment {
+            text: &text[start..end],
+            start,
+            kind: Kind::Sentence,
+            speaker: None,
+            overlong: false,
+        };
+        let check = |segments: &[Segment]| CoverageError::check(text, segments);
+        assert_eq!(
+            check(&[segment(2, 7), segment(7, 12), segment(12, 19)]),
+            Ok(())
+        );
+        assert_eq!(
+            check(&[segment(7, 12)]),
+            Err(CoverageError {
+                lost: vec![0..7, 12..19],
+                overlapping: vec![],
+                offset: 2,
+                excerpt: "One. Two. Three.\n".into(),
+            })
+        );
+        assert_eq!(
+            check(&[segment(2, 9), segment(7, 14), segment(12, 19)]),
+            Err(CoverageError {
+                lost: vec![],
+                overlapping: vec![7..9, 12..14],
+                offset: 7,
+                excerpt: "Two. Three.\n".into(),
+            })
+        );
+
+        let text = format!("{} Lost∯ text", "Ok. ".repeat(20));
+        let error = CoverageError::check(
+            &text,
+            &[Segment {
+                text: &text[..80],
+                ..segment(0, 0)
+            }],
+        )
+        .unwrap_err();
+        assert_eq!(error.offset, 81);
+        assert_eq!(
+            error.to_string(),
+            "segments do not cover the input at offset 81 near \"Lost∯ text\"
+       lost: [80..93]
+overlapping: []"
+        );
+    }
+}
@@ -0,0 +1,87 @@
+//! Truncation of a document at a sentence boundary, for previews and snippets.
+//!
+//! ```rust
+//! use pragmatic_segmenter::snippet::{truncate, Limit};
+//! use pragmatic_segmenter::Segmenter;
+//!
+//! let segmenter = Segmenter::new()?;
+//! let text = "Hi Mr. Kim. Let's meet at 3 P.M. See you there.";
+//! assert_eq!(
+//!     truncate(&segmenter, text, Limit::Chars(40)),
+//!     "Hi Mr. Kim. Let's meet at 3 P.M."
+//! );
+//! assert_eq!(truncate(&segmenter, text, Limit::Bytes(10)), "");
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::Segmenter;
+
+/// Maximum length of a snippet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+    /// Length in bytes of UTF-8.
+    Bytes(usize),
+    /// Length in characters, i.e. Unicode scalar values.
+    Chars(usize),
+}
+
+impl Limit {
+    fn len(self, text: &str) -> usize {
+        match self {
+            Limit::Bytes(_) => text.len(),
+            Limit::Chars(_) => text.chars().count(),
+        }
+    }
+
+    fn max(self) -> usize {
+        match self {
+            Limit::Bytes(max) | Limit::Chars(max) => max,
+        }
+    }
+}
+
+/// The longest prefix of `text` which ends at a sentence boundary and fits in `limit`. The
+/// whitespaces after the last sentence are not included. Returns `text` as is if it fits, and
+/// an empty string if even the first sentence doesn't fit.
+pub fn truncate<'a>(segmenter: &Segmenter, text: &'a str, limit: Limit) -> &'a str {
+    if limit.len(text) <= limit.max() {
+        return text;
+    }
+    // 문장 뒤의 공백은 세지 않으므로, 공백까지 센 길이와 공백을 뺀 길이를 따로 관리한다.
+    let mut end = 0;
+    let mut len = 0;
+    for segment in segmenter.segments(text) {
+        let trimmed = segment.text.trim_end();
+        let trimmed_len = len + limit.len(&text[end..segment.start]) + limit.len(trimmed);
+        if trimmed_len > limit.max() {
+            break;
+        }
+        len += limit.len(&text[end..segment.start]) + limit.len(segment.text);
+        end = segment.start + segment.text.len();
+    }
+    text[..end].trim_end()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate() -> Result<(), Box<dyn std::error::Error>> {
+        let segmenter = Segmenter::new()?;
+        let text = "Ça va. Très bien.  Merci!";
+        assert_eq!(truncate(&segmenter, text, Limit::Chars(25)), text);
+        assert_eq!(
+            truncate(&segmenter, text, Limit::Bytes(25)),
+            "Ça va. Très bien."
+        );
+        assert_eq!(
+            truncate(&segmenter, text, Limit::Chars(17)),
+            "Ça va. Très bien."
+        );
+        assert_eq!(truncate(&segmenter, text, Limit::Chars(16)), "Ça va.");
+        assert_eq!(truncate(&segmenter, text, Limit::Chars(5)), "");
+        assert_eq!(truncate(&segmenter, "", Limit::Bytes(0)), "");
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use onig::Regex;
+
+use crate::builder::SpeechRecognition;
+use crate::util::re;
+use crate::PUNCTUATIONS;
+
+/// Capitalized words which usually start a sentence in transcribed speech. "I" is not here,
+/// since speech recognizers capitalize it everywhere.
+#[rustfmt::skip]
+const SENTENCE_STARTERS: &[&str] = &[
+    "So", "And", "But", "Then", "Now", "Well", "Okay", "OK", "Yeah", "Yes", "No", "The", "This",
+    "That", "It", "We", "You", "He", "She", "They", "What", "When", "Where", "Why", "How",
+    "There", "Because", "Anyway", "Actually",
+];
+
+/// Rules for the output of speech recognition, enabled by
+/// [`SegmenterBuilder::speech_recognition()`](crate::SegmenterBuilder::speech_recognition).
+pub(crate) struct SpeechRules {
+    pause_regex: Option<Regex>,
+    starter_regex: Option<Regex>,
+    pause_markers: Vec<String>,
+    fillers: Vec<String>,
+}
+
+impl SpeechRules {
+    pub fn new(config: &SpeechRecognition) -> Result<Self, onig::Error> {
+        let pause_markers: Vec<_> = config
+            .pause_markers
+            .iter()
+            .filter(|marker| !marker.is_empty())
+            .cloned()
+            .collect();
+        let pause_regex = if pause_markers.is_empty() {
+            None
+        } else {
+            let markers: Vec<_> = pause_markers.iter().map(|m| regex::escape(m)).collect();
+            Some(re(&format!(r"(?:{})+[ \t]*", markers.join("|")))?)
+        };
+        let starter_regex = if config.capitalization {
+            Some(re(&format!(
+                r"(?<=\p{{Ll}}[ \t])(?=(?:{})\b)",
+                SENTENCE_STARTERS.join("|")
+            ))?)
+        } else {
+            None
+        };
+        Ok(SpeechRules {
+            pause_regex,
+            starter_regex,
+            pause_markers,
+            fillers: config.fillers.iter().map(|f| f.to_lowercase()).collect(),
+        })
+    }
+
+    /// Insert line breaks after pause markers, and before capitalized sentence starters in lines
+    /// without any punctuation. Line breaks must be already replaced with `\r`.
+    pub fn add_line_breaks(&self, text: &str) -> String {
+        let text = match &self.pause_regex {
+            Some(regex) => regex.replace_all(text, |c: &onig::Captures| {
+                format!("{}\r", c.at(0).unwrap()) // Must exist
+            }),
+            None => text.to_string(),
+        };
+        let starter_regex = match &self.starter_regex {
+            Some(regex) => regex,
+            None => return text,
+        };
+        text.split('\r')
+            .map(|line| {
+                if line.contains(&PUNCTUATIONS[..]) {
+                    line.to_string()
+                } else {
+                    starter_regex.replace_all(line, "\r")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\r")
+    }
+
+    /// Whether `segment` has only fillers like "uh" and "um", besides pause markers and
+    /// punctuations.
+    pub fn is_filler(&self, segment: &str) -> bool {
+        let mut segment = segment.to_lowercase();
+        for marker in &self.pause_markers {
+            segment = segment.replace(&marker.to_lowercase(), " ");
+        }
+        let mut words = segment
+            .split(|c: char| !c.is_alphanumeric() && c != '-' && c != '\'')
+            .filter(|word| !word.is_empty())
+            .peekable();
+        words.peek().is_some() && words.all(|word| self.fillers.iter().any(|f| f == word))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_line_breaks() -> Result<(), Box<dyn std::error::Error>> {
+        let rules = SpeechRules::new(&SpeechRecognition::default())?;
+        assert_eq!(
+            rules.add_line_breaks("we went <pause> to Seoul So I left\rNo. Then go"),
+            "we went <pause> \rto Seoul \rSo I left\rNo. Then go"
+        );
+        assert_eq!(rules.add_line_breaks("wait… ok"), "wait… \rok");
+
+        assert!(rules.is_filler("Um, uh <pause> "));
+        assert!(!rules.is_filler("Um, so "));
+        assert!(!rules.is_filler("<pause> "));
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+use crate::list_item_replacer::ListItemReplacer;
+use crate::{Kind, Segment};
+
+/// Summary of the structure of a document, returned by
+/// [`Segmenter::analyze()`](crate::Segmenter::analyze).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DocStructure {
+    /// Number of paragraphs, which are separated by blank lines.
+    pub paragraphs: usize,
+    /// Number of segments which are not headings, list items included.
+    pub sentences: usize,
+    /// Number of headings, which are short lines without a sentence-ending punctuation like
+    /// "Introduction" or "# Usage", followed by more text.
+    pub headings: usize,
+    /// Enumerations in the order they appear.
+    pub lists: Vec<List>,
+}
+
+/// An enumeration in a document. Consecutive items with the same style of markers form a list,
+/// until a blank line or a line which is not a list item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct List {
+    /// Style of the markers.
+    pub kind: ListKind,
+    /// Byte offset of the first item in the input.
+    pub start: usize,
+    /// Number of items.
+    pub items: usize,
+}
+
+/// Style of the markers of a [`List`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ListKind {
+    /// "1.", "2)"
+    Numbered,
+    /// "a.", "(b)"
+    Alphabetical,
+    /// "i.", "(iv)"
+    Roman,
+}
+
+/// A list which is not finished yet.
+struct OpenList {
+    list: List,
+    /// Marker without the letters or digits, like "." or "()"
+    style: String,
+    numbered: bool,
+    /// Whether every marker so far is a roman numeral
+    roman: bool,
+}
+
+impl DocStructure {
+    pub(crate) fn new<'a>(
+        text: &'a str,
+        segments: impl Iterator<Item = Segment<'a>>,
+        list_item_replacer: &ListItemReplacer,
+    ) -> Self {
+        let mut structure = DocStructure::default();
+        let mut open: Option<OpenList> = None;
+        let mut segments = segments
+            .filter(|segment| !segment.text.trim().is_empty())
+            .peekable();
+        while let Some(segment) = segments.next() {
+            let before = text[..segment.start].trim_end();
+            let newlines = text[before.len()..segment.start].matches('\n').count();
+            let line_start = before.is_empty() || newlines > 0;
+            if before.is_empty() || newlines > 1 {
+                structure.paragraphs += 1;
+                structure.close(open.take());
+            }
+
+            if let Kind::ListItem { marker } = segment.kind {
+                let letters = marker.trim_matches(|c: char| !c.is_alphanumeric());
+                let style: String = marker.chars().filter(|c| !c.is_alphanumeric()).collect();
+                let numbered = letters.starts_with(|c: char| c.is_ascii_digit());
+                let roman =
+                    !numbered && list_item_replacer.is_roman_numeral(&letters.to_lowercase());
+                match &mut open {
+                    Some(current) if current.style == style && current.numbered == numbered => {
+                        current.list.items += 1;
+                        current.roman &= roman;
+                    }
+                    _ => {
+                        structure.close(open.take());
+                        open = Some(OpenList {
+                            list: List {
+                                kind: ListKind::Numbered,
+                                start: segment.start,
+                                items: 1,
+                            },
+                            style,
+                            numbered,
+                            roman,
+                        });
+                    }
+                }
+                structure.sentences += 1;
+                continue;
+            }
+
+            if line_start {
+                structure.close(open.take());
+            }
+            if line_start && segments.peek().is_some() && is_heading(segment.text) {
+                structure.headings += 1;
+            } else {
+                structure.sentences += 1;
+            }
+        }
+        structure.close(open);
+        structure
+    }
+
+    fn close(&mut self, open: Option<OpenList>) {
+        if let Some(OpenList {
+            mut list,
+            numbered,
+            roman,
+            ..
+        }) = open
+        {
+            list.kind = if numbered {
+                ListKind::Numbered
+            } else if roman {
+                ListKind::Roman
+            } else {
+                ListKind::Alphabetical
+            };
+            self.lists.push(list);
+        }
+    }
+}
+
+/// Whether a segment which starts a line is a heading. It must be the whole line, have at most
+/// ten words, start with a capital letter, a digit or "#", and not end with a punctuation.
+fn is_heading(segment: &str) -> bool {
+    let line = segment.trim_end();
+    segment[line.len()..].contains('\n')
+        && !line.contains('\n')
+        && line.split_whitespace().count() <= 10
+        && line.starts_with(|c: char| c.is_uppercase() || c.is_ascii_digit() || c == '#')
+        && !line.ends_with(|c: char| {
+            matches!(
+                c,
+                '.' | '!' | '?' | ',' | ';' | ':' | '…' | '。' | '！' | '？' | '"' | '”'
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_heading() {
+        assert!(is_heading("Introduction\n"));
+        assert!(is_heading("# Getting started\r\n\r\n"));
+        assert!(is_heading("2 Related work\n"));
+        assert!(!is_heading("Introduction"));
+        assert!(!is_heading("Steps:\n"));
+        assert!(!is_heading("it was late\n"));
+        assert!(!is_heading("He left.\n"));
+    }
+}
@@ -0,0 +1,75 @@
+//! Trace of the rules which changed the text while segmenting it, returned by
+//! [`Segmenter::explain()`](crate::Segmenter::explain). It tells which rule hid or moved a
+//! punctuation, which makes a wrong segmentation much easier to report and to fix.
+//!
+//! ```rust
+//! use pragmatic_segmenter::trace::Stage;
+//! use pragmatic_segmenter::Segmenter;
+//!
+//! let segmenter = Segmenter::new()?;
+//! let steps = segmenter.explain("It costs 3.5 dollars. Bye.");
+//! assert_eq!(steps.len(), 1);
+//! assert_eq!(steps[0].stage, Stage::Document);
+//! assert_eq!(steps[0].rule, "PeriodBeforeNumberRule");
+//! assert_eq!(steps[0].text, "It costs 3∯5 dollars. Bye.");
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::cell::RefCell;
+
+use crate::Rule;
+
+/// Stage of the pipeline where a rule is applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Stage {
+    /// Line breaks between the items of a list, on the whole text.
+    ListItems,
+    /// Periods of abbreviations, on the whole text.
+    Abbreviations,
+    /// Every other rule on the whole text, like salutations, numbers and the rules of a
+    /// language.
+    Document,
+    /// Rules on each line of the text, right before it is split at the sentence boundaries.
+    Line,
+}
+
+/// A rule which changed the text, and the text after the change. The text has the placeholders
+/// which the segmenter uses internally, like "∯" for a period which doesn't end a sentence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Step {
+    /// Stage of the pipeline.
+    pub stage: Stage,
+    /// Name of the rule, as returned by [`Rule::name()`].
+    pub rule: String,
+    /// Text which the rule was applied to, after the change: a line for [`Stage::Line`], and
+    /// usually the whole text otherwise. Rules which only apply inside quotations see only the
+    /// quotation.
+    pub text: String,
+}
+
+/// Where the steps are recorded, or nowhere when segmenting as usual.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct Tracer<'t>(Option<&'t RefCell<Vec<Step>>>);
+
+impl<'t> Tracer<'t> {
+    pub fn new(steps: &'t RefCell<Vec<Step>>) -> Self {
+        Tracer(Some(steps))
+    }
+
+    /// Apply `rule` to `text`, and record it if it changed the text.
+    pub fn apply(self, stage: Stage, rule: &Rule, text: &str) -> String {
+        let after = rule.replace_all(text);
+        if let Some(steps) = self.0 {
+            if after != text {
+                steps.borrow_mut().push(Step {
+                    stage,
+                    rule: rule.name().to_string(),
+                    text: after.clone(),
+                });
+            }
+        }
+        after
+    }
+}
@@ -0,0 +1,140 @@
+//! Training data for boundary classifiers, calibrated against the behavior of this crate. Every
+//! run of terminal punctuations in a text is a decision of the rules, labeled with whether a
+//! sentence ends there, along with the text around it.
+//!
+//! ```rust
+//! use pragmatic_segmenter::{training, Segmenter};
+//!
+//! let segmenter = Segmenter::new()?;
+//! let decisions: Vec<_> = training::decisions(&segmenter, "Hi Mr. Kim. Is it 3.5 km?!", 5)
+//!     .into_iter()
+//!     .map(|decision| (decision.left(), decision.punct(), decision.right(), decision.boundary))
+//!     .collect();
+//! assert_eq!(
+//!     decisions,
+//!     vec![
+//!         ("Hi Mr", ".", " Kim.", false),
+//!         (". Kim", ".", " Is i", true),
+//!         (" it 3", ".", "5 km?", false),
+//!         (".5 km", "?!", "", true),
+//!     ]
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::HashSet;
+use std::ops::Range;
+
+use crate::lite::{CLOSINGS, TERMINALS};
+use crate::Segmenter;
+
+/// A run of terminal punctuations in a text, returned by [`decisions()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Decision<'a> {
+    /// The punctuations and the text around them. This is a slice of the input.
+    pub context: &'a str,
+    /// Byte offset of [`context`](Decision::context) in the input.
+    pub start: usize,
+    /// Byte range of the punctuations in [`context`](Decision::context).
+    pub punct: Range<usize>,
+    /// Whether a sentence ends with the punctuations. Closing quotation marks and brackets
+    /// right after them are considered a part of the sentence.
+    pub boundary: bool,
+}
+
+impl<'a> Decision<'a> {
+    /// The text before the punctuations.
+    pub fn left(&self) -> &'a str {
+        &self.context[..self.punct.start]
+    }
+
+    /// The punctuations, like "." or "?!".
+    pub fn punct(&self) -> &'a str {
+        &self.context[self.punct.clone()]
+    }
+
+    /// The text after the punctuations.
+    pub fn right(&self) -> &'a str {
+        &self.context[self.punct.end..]
+    }
+}
+
+/// Every run of terminal punctuations in `text` and whether `segmenter` ends a sentence with it,
+/// in order. The context has at most `window` characters on each side, without crossing line
+/// breaks.
+///
+/// Periods of numbers and URLs are included as well, since they are decisions of the rules
+/// too.
+pub fn decisions<'a>(segmenter: &Segmenter, text: &'a str, window: usize) -> Vec<Decision<'a>> {
+    let ends: HashSet<usize> = segmenter
+        .segments(text)
+        .map(|segment| segment.start + segment.text.trim_end().trim_end_matches(CLOSINGS).len())
+        .collect();
+
+    let mut decisions = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some((begin, c)) = chars.next() {
+        if !TERMINALS.contains(&c) {
+            continue;
+        }
+        let mut end = begin + c.len_utf8();
+        while let Some((idx, c)) = chars.next_if(|(_, c)| TERMINALS.contains(c)) {
+            end = idx + c.len_utf8();
+        }
+
+        let left = text[..begin]
+            .char_indices()
+            .rev()
+            .take(window)
+            .take_while(|&(_, c)| c != '\n' && c != '\r')
+            .last()
+            .map_or(begin, |(idx, _)| idx);
+        let right = text[end..]
+            .char_indices()
+            .take(window)
+            .take_while(|&(_, c)| c != '\n' && c != '\r')
+            .last()
+            .map_or(end, |(idx, c)| end + idx + c.len_utf8());
+        decisions.push(Decision {
+            context: &text[left..right],
+            start: left,
+            punct: begin - left..end - left,
+            boundary: ends.contains(&end),
+        });
+    }
+    decisions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decisions() -> Result<(), Box<dyn std::error::Error>> {
+        let segmenter = Segmenter::new()?;
+        let text = "He said \"Hi.\" Then...\nsee www.example.com. 終わり。";
+        let decisions: Vec<_> = decisions(&segmenter, text, 4)
+            .into_iter()
+            .map(|decision| {
+                assert_eq!(
+                    &text[decision.start..decision.start + decision.context.len()],
+                    decision.context
+                );
+                (decision.context, decision.boundary)
+            })
+            .collect();
+        assert_eq!(
+            decisions,
+            vec![
+                (" \"Hi.\" Th", true),
+                ("Then...", true),
+                (" www.exam", false),
+                ("mple.com.", false),
+                (".com. 終わり", true),
+                (" 終わり。", true),
+            ]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use std::ops::Range;
+
+use onig::Regex;
+
+use crate::util::re;
+
+/// A speaker turn of a transcript, in byte ranges of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Turn {
+    /// Prefix of the turn including the whitespaces after it, like "[00:01:23] SPEAKER 1: ".
+    /// Empty for the text before the first turn.
+    pub prefix: Range<usize>,
+    /// Speaker label in the prefix, like "SPEAKER 1".
+    pub speaker: Option<Range<usize>>,
+    /// Rest of the turn, up to the next prefix.
+    pub body: Range<usize>,
+}
+
+impl Turn {
+    /// Whole `text` as a single turn without a prefix.
+    pub fn whole(text: &str) -> Self {
+        Turn {
+            prefix: 0..0,
+            speaker: None,
+            body: 0..text.len(),
+        }
+    }
+}
+
+/// Finds speaker turns of a transcript, which start at the beginning of a line with a timestamp
+/// like "00:01:23" or "[01:23.5]", a speaker label like "SPEAKER 1:", "Dr. Smith:" or "[John]",
+/// or both.
+///
+/// Any capitalized word followed by a colon looks like a speaker label, like "Note: this is
+/// fine.", so a label followed by a colon starts a turn only if it is uppercase like "BOB:",
+/// follows a timestamp, or starts another line of the text as well. A speaker who speaks only
+/// once in mixed case, like "Mary: Hello.", stays in the turn before.
+pub(crate) struct TurnParser {
+    regex: Regex,
+}
+
+impl TurnParser {
+    pub fn new() -> Result<Self, onig::Error> {
+        Ok(TurnParser {
+            regex: re(concat!(
+                r"^[ \t]*",
+                // 1: timestamp
+                r"([\[(]?\d{1,2}(?::\d{2}){1,2}(?:[.,]\d{1,3})?[\])]?(?:[ \t]+|$))?",
+                // 2: speaker label in brackets
+                r"(?:\[(\p{L}[^\]\n]{0,39})\]:?(?=\s|$)",
+                // 3: speaker label followed by a colon
+                r"|(\p{Lu}[\p{L}\d.'-]*(?:[ \t][\p{Lu}\d][\p{L}\d.'-]*){0,3})[ \t]*:(?=\s|$))?",
+                r"[ \t]*",
+            ))?,
+        })
+    }
+
+    /// Turns of `text`, which cover the whole text in order.
+    pub fn turns(&self, text: &str) -> Vec<Turn> {
+        let prefixes: Vec<_> = self
+            .regex
+            .captures_iter(text)
+            .map(|captures| {
+                (
+                    captures.pos(0).unwrap(), // Must exist
+                    captures.pos(1),
+                    captures.pos(2),
+                    captures.pos(3),
+                )
+            })
+            .collect();
+        let labels: Vec<_> = prefixes
+            .iter()
+            .filter_map(|&(_, _, _, label)| label.map(|(start, end)| &text[start..end]))
+            .collect();
+        // NOTE: "Note: ..."처럼 콜론 앞의 대문자 단어는 흔하므로, 대문자로만 되어있거나
+        // 타임스탬프 뒤에 있거나 두 줄 이상에 나오는 이름만 화자로 본다.
+        let is_speaker = |label: &str| {
+            !label.chars().any(char::is_lowercase)
+                || labels.iter().filter(|&&other| other == label).count() > 1
+        };
+
+        let mut turns: Vec<Turn> = Vec::new();
+        for ((start, end), timestamp, bracket, label) in prefixes {
+            let label = label.filter(|&(start, end)| {
+                timestamp.is_some() || is_speaker(&text[start..end])
+            });
+            let speaker = bracket.or(label);
+            if speaker.is_none() && timestamp.is_none() {
+                continue;
+            }
+            match turns.last_mut() {
+                Some(last) => last.body.end = start,
+                None if start > 0 => turns.push(Turn {
+                    prefix: 0..0,
+                    speaker: None,
+                    body: 0..start,
+                }),
+                None => {}
+            }
+            turns.push(Turn {
+                prefix: start..end,
+                speaker: speaker.map(|(start, end)| start..end),
+                body: end..text.len(),
+            });
+        }
+        if turns.is_empty() {
+            turns.push(Turn::whole(text));
+        }
+        turns
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() -> Result<(), Box<dyn std::error::Error>> {
+        let parser = TurnParser::new()?;
+        let text = "Intro.\nSPEAKER 1: Hi.\n[John] Yes.\n[00:01:23] Dr. Smith: Ok.\n00:02 So.\nNote that: 5\nAnn: Me.\nNote: fine.\nAnn: Bye.";
+        let turns: Vec<_> = parser
+            .turns(text)
+            .into_iter()
+            .map(|turn| {
+                (
+                    &text[turn.prefix],
+                    turn.speaker.map(|range| &text[range]),
+                    &text[turn.body],
+                )
+            })
+            .collect();
+        assert_eq!(
+            turns,
+            vec![
+                ("", None, "Intro.\n"),
+                ("SPEAKER 1: ", Some("SPEAKER 1"), "Hi.\n"),
+                ("[John] ", Some("John"), "Yes.\n"),
+                ("[00:01:23] Dr. Smith: ", Some("Dr. Smith"), "Ok.\n"),
+                ("00:02 ", None, "So.\nNote that: 5\n"),
+                ("Ann: ", Some("Ann"), "Me.\nNote: fine.\n"),
+                ("Ann: ", Some("Ann"), "Bye."),
+            ]
+        );
+
+        assert_eq!(parser.turns("No turns."), vec![Turn::whole("No turns.")]);
+        Ok(())
+    }
+}
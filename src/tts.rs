@@ -0,0 +1,191 @@
+//! Re-chunking of segments into utterances for text-to-speech engines, which usually limit the
+//! length of a single request.
+//!
+//! Segments which fit in the budget are passed through as is. Longer ones are split, preferably
+//! after a comma or a semicolon, then before a conjunction like "and", then between any two words.
+//! A single word longer than the budget is cut in the middle as a last resort.
+//!
+//! ```rust
+//! use pragmatic_segmenter::{tts::Rechunker, Segmenter};
+//!
+//! let segmenter = Segmenter::new()?;
+//! let text = "Hi. We walked along the river, crossed the old bridge and came back home.";
+//! let rechunker = Rechunker::new(40);
+//! let utterances: Vec<_> = rechunker.chunks(segmenter.segment(text)).collect();
+//! assert_eq!(
+//!     utterances,
+//!     vec![
+//!         "Hi. ",
+//!         "We walked along the river, ",
+//!         "crossed the old bridge ",
+//!         "and came back home."
+//!     ]
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::borrow::Cow;
+
+use crate::clause;
+
+#[rustfmt::skip]
+const CONJUNCTIONS: &[&str] = &[
+    "and", "but", "or", "nor", "so", "yet", "because", "although", "though", "while", "whereas",
+    "which", "who", "that", "when", "where", "unless", "until", "since", "if",
+];
+
+const CLAUSE_PUNCTUATIONS: &[char] = &[',', ';', ':', '—', '–', '，', '、', '；', '：'];
+
+/// Splits segments into utterances of at most `budget` characters, trailing whitespace included.
+#[derive(Debug, Clone)]
+pub struct Rechunker {
+    budget: usize,
+    conjunctions: Vec<Cow<'static, str>>,
+}
+
+impl Rechunker {
+    /// Create a re-chunker with a budget in characters, and English conjunctions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `budget` is zero.
+    pub fn new(budget: usize) -> Self {
+        assert!(budget > 0, "budget must be positive");
+        Rechunker {
+            budget,
+            conjunctions: CONJUNCTIONS
+                .iter()
+                .map(|&word| Cow::Borrowed(word))
+                .collect(),
+        }
+    }
+
+    /// Replace the conjunctions which an utterance may be split before. Matching is case
+    /// insensitive.
+    pub fn conjunctions<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'static, str>>,
+    {
+        self.conjunctions = words
+            .into_iter()
+            .map(|word| Cow::Owned(word.into().to_lowercase()))
+            .collect();
+        self
+    }
+
+    /// Re-chunk a stream of segments, like the result of
+    /// [`Segmenter::segment()`](crate::Segmenter::segment). Segments are consumed lazily, one at a
+    /// time.
+    pub fn chunks<'a, I>(&self, segments: I) -> Chunks<'_, 'a, I::IntoIter>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        Chunks {
+            rechunker: self,
+            segments: segments.into_iter(),
+            rest: "",
+        }
+    }
+
+    /// Byte offset where `text`, which is longer than the budget, should be split.
+    fn split_point(&self, text: &str) -> usize {
+        // 예산 안에 들어가는 가장 긴 접두사의 끝
+        let limit = text
+            .char_indices()
+            .nth(self.budget)
+            .map_or(text.len(), |(idx, _)| idx);
+        // 너무 앞에서 자르면 짧은 발화가 생기므로, 쉼표나 접속사는 예산의 1/3 이후만 본다.
+        let min = text
+            .char_indices()
+            .nth(self.budget / 3)
+            .map_or(text.len(), |(idx, _)| idx);
+
+        clause::best_cut(text, limit, |idx| {
+            if idx < min {
+                1
+            } else if text[..idx].trim_end().ends_with(CLAUSE_PUNCTUATIONS) {
+                3
+            } else if self.starts_with_conjunction(&text[idx..]) {
+                2
+            } else {
+                1
+            }
+        })
+        .unwrap_or(limit)
+    }
+
+    fn starts_with_conjunction(&self, text: &str) -> bool {
+        let end = text.find(char::is_whitespace).unwrap_or(text.len());
+        let first = text[..end].to_lowercase();
+        self.conjunctions.iter().any(|word| *word == first)
+    }
+}
+
+/// Iterator returned by [`Rechunker::chunks()`].
+#[derive(Debug)]
+pub struct Chunks<'r, 'a, I> {
+    rechunker: &'r Rechunker,
+    segments: I,
+    rest: &'a str,
+}
+
+impl<'a, I: Iterator<Item = &'a str>> Iterator for Chunks<'_, 'a, I> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.rest.is_empty() {
+            self.rest = self.segments.next()?;
+        }
+        let fits = self.rest.chars().nth(self.rechunker.budget).is_none();
+        let end = if fits {
+            self.rest.len()
+        } else {
+            self.rechunker.split_point(self.rest)
+        };
+        let (utterance, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(utterance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks(budget: usize, text: &str) -> Vec<&str> {
+        let rechunker = Rechunker::new(budget);
+        rechunker.chunks(Some(text)).collect()
+    }
+
+    #[test]
+    fn test_chunks() {
+        assert_eq!(chunks(10, "Short."), vec!["Short."]);
+        assert_eq!(
+            chunks(20, "One, two, three, four, five, six."),
+            vec!["One, two, three, ", "four, five, six."]
+        );
+        assert_eq!(
+            chunks(20, "It rained and we all stayed at home."),
+            vec!["It rained ", "and we all stayed ", "at home."]
+        );
+        assert_eq!(chunks(4, "abcdefghij"), vec!["abcd", "efgh", "ij"]);
+        assert_eq!(chunks(3, "가나다라마"), vec!["가나다", "라마"]);
+
+        let text = "Uno dos y tres.";
+        let rechunker = Rechunker::new(12).conjunctions(vec!["y"]);
+        let actual: Vec<_> = rechunker.chunks(Some(text)).collect();
+        assert_eq!(actual, vec!["Uno dos ", "y tres."]);
+    }
+
+    #[test]
+    fn test_budget() {
+        let text = "We walked along the river, crossed the old bridge and came back home. \
+                    Supercalifragilisticexpialidocious!";
+        for budget in 1..30 {
+            let actual: Vec<_> = chunks(budget, text);
+            assert_eq!(actual.concat(), text);
+            assert!(actual.iter().all(|u| u.chars().count() <= budget));
+        }
+    }
+}
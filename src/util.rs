@@ -7,3 +7,81 @@ pub fn re(regex: &str) -> Result<Regex, Error> {
 pub fn re_i(regex: &str) -> Result<Regex, Error> {
     Regex::with_options(regex, RegexOptions::REGEX_OPTION_IGNORECASE, Syntax::ruby())
 }
+
+/// Byte offset in an original text which corresponds to `offset` of a text made shorter than it.
+/// `runs` holds (offset of a shortened place in the shorter text, number of bytes removed up to
+/// that place), in order. An offset right after a shortened place is moved past the whole
+/// original run.
+pub fn original_offset(runs: &[(usize, usize)], offset: usize) -> usize {
+    let idx = runs.partition_point(|&(pos, _)| pos < offset);
+    match idx {
+        0 => offset,
+        _ => offset + runs[idx - 1].1,
+    }
+}
+
+/// 64-bit FNV-1a hash. Unlike `std::collections::hash_map::DefaultHasher`, the result never
+/// changes between Rust versions.
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// At most `chars` characters of `text` from the byte offset `offset`, followed by "…" if there
+/// are more, to point at a region of a long input in an error message.
+pub fn excerpt(text: &str, offset: usize, chars: usize) -> String {
+    let rest = &text[offset..];
+    match rest.char_indices().nth(chars) {
+        Some((end, _)) => format!("{}…", &rest[..end]),
+        None => rest.to_string(),
+    }
+}
+
+/// Insert `insertion` at every byte offset of `offsets`, which must be sorted, in place. The
+/// buffer grows once, and every byte is moved at most once.
+pub fn insert_all(buffer: &mut Vec<u8>, offsets: &[usize], insertion: &[u8]) {
+    let old_len = buffer.len();
+    buffer.resize(old_len + offsets.len() * insertion.len(), 0);
+    // 뒤에서부터 옮기면 아직 옮기지 않은 바이트를 덮어쓰지 않는다.
+    let mut end = old_len;
+    for (idx, &offset) in offsets.iter().enumerate().rev() {
+        let shift = (idx + 1) * insertion.len();
+        buffer.copy_within(offset..end, offset + shift);
+        buffer[offset + shift - insertion.len()..offset + shift].copy_from_slice(insertion);
+        end = offset;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_excerpt() {
+        assert_eq!(excerpt("Hi. Bye.", 4, 10), "Bye.");
+        assert_eq!(excerpt("Hi. Bye.", 0, 3), "Hi.…");
+        assert_eq!(excerpt("가나다라", 3, 2), "나다…");
+        assert_eq!(excerpt("Hi.", 3, 2), "");
+    }
+
+    #[test]
+    fn test_insert_all() {
+        let mut buffer = b"Hi. Bye. Ok.".to_vec();
+        insert_all(&mut buffer, &[4, 9], b"|\n");
+        assert_eq!(buffer, b"Hi. |\nBye. |\nOk.");
+
+        let mut buffer = b"ab".to_vec();
+        insert_all(&mut buffer, &[0, 2, 2], b"-");
+        assert_eq!(buffer, b"-ab--");
+        insert_all(&mut buffer, &[], b"-");
+        assert_eq!(buffer, b"-ab--");
+    }
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(fnv1a(b"foobar"), 0x8594_4171_f739_67e8);
+    }
+}
@@ -0,0 +1,62 @@
+use crate::util;
+
+/// Text whose runs of spaces and tabs are collapsed into a single space, along with the mapping
+/// of byte offsets back to the original text.
+pub(crate) struct CollapsedWhitespace {
+    pub text: String,
+    /// (offset of a collapsed space in `text`, number of bytes removed up to that space)
+    runs: Vec<(usize, usize)>,
+}
+
+impl CollapsedWhitespace {
+    /// `None` if there's nothing to collapse.
+    pub fn new(original: &str) -> Option<Self> {
+        let mut text = String::with_capacity(original.len());
+        let mut runs = Vec::new();
+        let mut removed = 0;
+        let mut rest = original;
+        while let Some(idx) = rest.find([' ', '\t']) {
+            text += &rest[..idx];
+            let len = rest[idx..]
+                .find(|c| c != ' ' && c != '\t')
+                .unwrap_or(rest.len() - idx);
+            if len > 1 {
+                removed += len - 1;
+                runs.push((text.len(), removed));
+            }
+            text.push(' ');
+            rest = &rest[idx + len..];
+        }
+        text += rest;
+
+        if text == original {
+            None
+        } else {
+            Some(CollapsedWhitespace { text, runs })
+        }
+    }
+
+    /// Byte offset in the original text which corresponds to `offset` of the collapsed text. A
+    /// range which includes a collapsed space includes the whole run of the original text.
+    pub fn original_offset(&self, offset: usize) -> usize {
+        util::original_offset(&self.runs, offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collapsed_whitespace() {
+        assert!(CollapsedWhitespace::new("a b\nc").is_none());
+
+        let original = "a  b\t\tc\td";
+        let collapsed = CollapsedWhitespace::new(original).unwrap();
+        assert_eq!(collapsed.text, "a b c d");
+        let offsets: Vec<_> = (0..=collapsed.text.len())
+            .map(|offset| collapsed.original_offset(offset))
+            .collect();
+        assert_eq!(offsets, vec![0, 1, 3, 4, 6, 7, 8, 9]);
+    }
+}
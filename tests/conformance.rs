@@ -0,0 +1,35 @@
+use std::error::Error;
+
+use pragmatic_segmenter::{conformance, Segmenter};
+
+type TestResult = Result<(), Box<dyn Error>>;
+
+/// A test which checks the segmenter of a language against its fixtures in tests/fixtures.
+macro_rules! conformance_test {
+    ($name:ident, $code:literal) => {
+        #[test]
+        fn $name() -> TestResult {
+            let cases = conformance::load("tests/fixtures", $code)?;
+            let segmenter = Segmenter::builder().language($code)?.build()?;
+            let report = conformance::run_parallel(&segmenter, &cases);
+
+            assert_eq!(report.failures, vec![]);
+            Ok(())
+        }
+    };
+}
+
+conformance_test!(test_ar, "ar");
+conformance_test!(test_de, "de");
+conformance_test!(test_en, "en");
+conformance_test!(test_es, "es");
+conformance_test!(test_fa, "fa");
+conformance_test!(test_fr, "fr");
+conformance_test!(test_hi, "hi");
+conformance_test!(test_ja, "ja");
+conformance_test!(test_kk, "kk");
+conformance_test!(test_mr, "mr");
+conformance_test!(test_pl, "pl");
+conformance_test!(test_pt, "pt");
+conformance_test!(test_ru, "ru");
+conformance_test!(test_zh, "zh");
@@ -53,3 +53,65 @@ fn test_quotes() -> TestResult {
     assert_eq!(actual, expected);
     Ok(())
 }
+
+#[test]
+fn test_backreference_in_replacement() -> TestResult {
+    let segmenter = Segmenter::new()?;
+
+    let input = "See (iv) Roman numerals.";
+    let actual: Vec<_> = segmenter.segment(input).collect();
+    assert_eq!(actual, vec![input]);
+
+    let input = "It was cited.[12] The end.";
+    let actual: Vec<_> = segmenter.segment(input).collect();
+    assert_eq!(actual, vec!["It was cited.[12] ", "The end."]);
+
+    Ok(())
+}
+
+#[test]
+fn test_quotation_at_end_of_sentence_spans() -> TestResult {
+    let segmenter = Segmenter::new()?;
+
+    let input = r#"She said "Stop." Then she left. She said "Stop." Then she left."#;
+    let actual: Vec<_> = segmenter.segment(input).collect();
+    let expected = vec![
+        r#"She said "Stop." "#,
+        "Then she left. ",
+        r#"She said "Stop." "#,
+        "Then she left.",
+    ];
+
+    assert_eq!(actual, expected);
+    assert_eq!(actual.concat(), input);
+    Ok(())
+}
+
+#[test]
+fn test_numerals_in_parens() -> TestResult {
+    let input = "See the rule. (12) The court may act. (iv), as noted, applies.";
+
+    let segmenter = Segmenter::new()?;
+    let actual: Vec<_> = segmenter.segment(input).collect();
+    let expected = vec![
+        "See the rule. ",
+        "(12) ",
+        "The court may act. ",
+        "(iv), as noted, applies.",
+    ];
+    assert_eq!(actual, expected);
+
+    let segmenter = Segmenter::builder()
+        .numerals_in_parens_in_any_context(true)
+        .arabic_numerals_in_parens(true)
+        .build()?;
+    let actual: Vec<_> = segmenter.segment(input).collect();
+    let expected = vec![
+        "See the rule. ",
+        "(12) The court may act. ",
+        "(iv), as noted, applies.",
+    ];
+    assert_eq!(actual, expected);
+
+    Ok(())
+}